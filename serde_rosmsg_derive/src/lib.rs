@@ -0,0 +1,365 @@
+//! # Serde ROSMSG Derive
+//!
+//! Implementation of `#[derive(RosMessage)]`, which implements the
+//! `RosMessage` trait of [`serde_rosmsg`](https://crates.io/crates/serde_rosmsg)
+//! for structs with named fields.
+//!
+//! This crate is not meant to be used directly. Enable the `derive` feature of
+//! `serde_rosmsg` instead, which re-exports the macro. Details about the
+//! supported field types and attributes can be found in the documentation of
+//! the `serde_rosmsg::message` module.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Expr, Fields, GenericArgument, Lit, LitStr, Path,
+          PathArguments, Result, Type};
+
+/// Names of built-in ROS types, which can be used for fields and constants.
+static PRIMITIVE_TYPES: &[&str] = &["bool", "int8", "uint8", "int16", "uint16", "int32", "uint32",
+                                    "int64", "uint64", "float32", "float64", "string", "time",
+                                    "duration", "byte", "char"];
+
+/// Derive the `RosMessage` trait, given the ROS type in a `rosmsg` attribute.
+#[proc_macro_derive(RosMessage, attributes(rosmsg))]
+pub fn derive_ros_message(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Type of a single field item, without the array annotation.
+enum Base {
+    /// A built-in type, given by its name.
+    Primitive(String),
+    /// A nested message, given by its Rust type.
+    Message(Type),
+}
+
+/// A field of the message.
+struct Field {
+    name: String,
+    base: Base,
+    /// Array annotation, like `[]` or `[3]`, or empty if it's not an array.
+    array: String,
+}
+
+/// Attributes given to the struct.
+struct Container {
+    ros_type: Option<String>,
+    constants: Vec<String>,
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(input.generics.span(),
+                              "RosMessage can't be derived for generic types"));
+    }
+    let container = container(input)?;
+    let ros_type = match container.ros_type {
+        Some(v) => v,
+        None => {
+            return Err(Error::new(input.ident.span(),
+                                  "missing #[rosmsg(type = \"package/Name\")] attribute"))
+        }
+    };
+    let fields = match input.data {
+        Data::Struct(ref data) => {
+            match data.fields {
+                Fields::Named(ref fields) => &fields.named,
+                _ => {
+                    return Err(Error::new(input.ident.span(),
+                                          "RosMessage can only be derived for structs with \
+                                           named fields"))
+                }
+            }
+        }
+        _ => {
+            return Err(Error::new(input.ident.span(),
+                                  "RosMessage can only be derived for structs"))
+        }
+    };
+    let fields = fields.iter().map(field).collect::<Result<Vec<_>>>()?;
+
+    let mut text = Vec::<TokenStream2>::new();
+    let mut md5_lines = Vec::<TokenStream2>::new();
+    let mut nested = Vec::<TokenStream2>::new();
+    for constant in &container.constants {
+        text.push(quote!(#constant));
+        text.push(quote!("\n"));
+        md5_lines.push(quote!(#constant));
+    }
+    for field in &fields {
+        match field.base {
+            Base::Primitive(ref name) => {
+                let line = format!("{}{} {}", name, field.array, field.name);
+                text.push(quote!(#line));
+                md5_lines.push(quote!(#line));
+            }
+            Base::Message(ref ty) => {
+                let suffix = format!("{} {}", field.array, field.name);
+                let name = format!(" {}", field.name);
+                text.push(quote!(<#ty as _serde_rosmsg::RosMessage>::ROS_TYPE));
+                text.push(quote!(#suffix));
+                md5_lines.push(quote!(<#ty as _serde_rosmsg::RosMessage>::MD5SUM, #name));
+                nested.push(quote!((<#ty as _serde_rosmsg::RosMessage>::ROS_TYPE,
+                                    <#ty as _serde_rosmsg::RosMessage>::DEFINITION)));
+            }
+        }
+        text.push(quote!("\n"));
+    }
+
+    // Lines of the MD5 text are separated by new lines, without a trailing one
+    let mut md5 = Vec::<TokenStream2>::new();
+    for line in md5_lines {
+        if !md5.is_empty() {
+            md5.push(quote!("\n"));
+        }
+        md5.push(line);
+    }
+
+    let ident = &input.ident;
+    Ok(quote! {
+        const _: () = {
+            #[allow(unused_extern_crates)]
+            extern crate serde_rosmsg as _serde_rosmsg;
+
+            const TEXT: &[&str] = &[#(#text),*];
+            const NESTED: &[(&str, &str)] = &[#(#nested),*];
+            const DEFINITION: [u8; _serde_rosmsg::derive::definition_len(TEXT, NESTED)] =
+                _serde_rosmsg::derive::definition(TEXT, NESTED);
+            const MD5SUM: [u8; 32] = _serde_rosmsg::derive::md5(&[#(#md5),*]);
+
+            impl _serde_rosmsg::RosMessage for #ident {
+                const ROS_TYPE: &'static str = #ros_type;
+                const MD5SUM: &'static str = _serde_rosmsg::derive::as_str(&MD5SUM);
+                const DEFINITION: &'static str = _serde_rosmsg::derive::as_str(&DEFINITION);
+            }
+        };
+    })
+}
+
+fn container(input: &DeriveInput) -> Result<Container> {
+    let mut container = Container {
+        ros_type: None,
+        constants: Vec::new(),
+    };
+    for attr in &input.attrs {
+        if !attr.path().is_ident("rosmsg") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("type") {
+                let value = meta.value()?.parse::<LitStr>()?;
+                container.ros_type = Some(message_name(&value)?);
+                Ok(())
+            } else if meta.path.is_ident("constant") {
+                let value = meta.value()?.parse::<LitStr>()?;
+                container.constants.push(constant(&value)?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported rosmsg attribute"))
+            }
+        })?;
+    }
+    Ok(container)
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic()) &&
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Validate a full message name, in the `package/Name` format.
+fn message_name(value: &LitStr) -> Result<String> {
+    let name = value.value();
+    let mut parts = name.split('/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(package), Some(message), None) if is_identifier(package) &&
+                                                is_identifier(message) => Ok(name),
+        _ => Err(Error::new(value.span(), "message type needs to be in the package/Name format")),
+    }
+}
+
+/// Normalize a constant definition, like `uint8 VALUE=3`.
+fn constant(value: &LitStr) -> Result<String> {
+    let definition = value.value();
+    let definition = definition.trim();
+    let error = |message: &str| Error::new(value.span(), message);
+    let (constant_type, rest) = match definition.find(char::is_whitespace) {
+        Some(index) => (&definition[..index], &definition[index..]),
+        None => return Err(error("constants need to be in the \"type NAME=value\" format")),
+    };
+    if !PRIMITIVE_TYPES.contains(&constant_type) || constant_type == "time" ||
+       constant_type == "duration" {
+        return Err(error("constants need to be of a number, bool or string type"));
+    }
+    let (name, constant_value) = match rest.find('=') {
+        Some(index) => (rest[..index].trim(), rest[index + 1..].trim()),
+        None => return Err(error("constants need to be in the \"type NAME=value\" format")),
+    };
+    if !is_identifier(name) {
+        return Err(error("constant names need to be valid identifiers"));
+    }
+    Ok(format!("{} {}={}", constant_type, name, constant_value))
+}
+
+fn field(field: &syn::Field) -> Result<Field> {
+    let name = match field.ident {
+        Some(ref ident) => ident.unraw().to_string(),
+        None => return Err(Error::new(field.span(), "fields need to be named")),
+    };
+    let mut ros_type = None;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("rosmsg") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("type") {
+                ros_type = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported rosmsg attribute"))
+            }
+        })?;
+    }
+    let (base, array) = match ros_type {
+        Some(ref value) => primitive_field_type(value)?,
+        None => field_type(&field.ty)?,
+    };
+    Ok(Field {
+        name,
+        base,
+        array,
+    })
+}
+
+/// Parse a built-in field type given in an attribute, like `time` or `int8[4]`.
+fn primitive_field_type(value: &LitStr) -> Result<(Base, String)> {
+    let ros_type = value.value();
+    let (base, array) = match ros_type.find('[') {
+        Some(index) => (&ros_type[..index], &ros_type[index..]),
+        None => (&ros_type[..], ""),
+    };
+    if !PRIMITIVE_TYPES.contains(&base) {
+        return Err(Error::new(value.span(), "field type needs to be a built-in ROS type"));
+    }
+    let length = array.trim_start_matches('[').trim_end_matches(']');
+    if !array.is_empty() &&
+       (!array.ends_with(']') || !length.is_empty() && length.parse::<usize>().is_err()) {
+        return Err(Error::new(value.span(), "array annotation needs to be `[]` or `[N]`"));
+    }
+    Ok((Base::Primitive(base.into()), array.into()))
+}
+
+/// Map the Rust type of a field to a ROS type.
+fn field_type(ty: &Type) -> Result<(Base, String)> {
+    match *ty {
+        Type::Array(ref array) => {
+            let length = match array.len {
+                Expr::Lit(ref expr) => {
+                    match expr.lit {
+                        Lit::Int(ref value) => value.base10_parse::<usize>()?,
+                        _ => return Err(Error::new(array.len.span(), "expected array length")),
+                    }
+                }
+                _ => {
+                    return Err(Error::new(array.len.span(),
+                                          "array lengths need to be integer literals"))
+                }
+            };
+            Ok((base_type(&array.elem)?, format!("[{}]", length)))
+        }
+        _ => {
+            match vec_item(ty) {
+                Some(item) => Ok((base_type(item)?, "[]".into())),
+                None => Ok((base_type(ty)?, String::new())),
+            }
+        }
+    }
+}
+
+/// Get the item type if the type is a `Vec`.
+fn vec_item(ty: &Type) -> Option<&Type> {
+    let path = match *ty {
+        Type::Path(ref path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref arguments) if arguments.args.len() == 1 => {
+            match arguments.args[0] {
+                GenericArgument::Type(ref item) => Some(item),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Get the ROS type of `time::Time` and `time::Duration` paths.
+///
+/// The `time` module has to be part of the path, so `std_msgs/Time` messages
+/// and `std::time::Duration` are not mistaken for the built-in types.
+fn time_type(path: &Path) -> Option<&'static str> {
+    let segments = path.segments.iter().collect::<Vec<_>>();
+    let (module, name) = match segments[..] {
+        [first, ..] if first.ident == "std" || first.ident == "core" => return None,
+        [.., module, name] => (module, name),
+        _ => return None,
+    };
+    if module.ident != "time" || !module.arguments.is_empty() || !name.arguments.is_empty() {
+        return None;
+    }
+    if name.ident == "Time" {
+        Some("time")
+    } else if name.ident == "Duration" {
+        Some("duration")
+    } else {
+        None
+    }
+}
+
+/// Map the Rust type of a field item to a ROS type.
+fn base_type(ty: &Type) -> Result<Base> {
+    if let Type::Array(_) = *ty {
+        return Err(Error::new(ty.span(), "nested arrays are not supported in ROS messages"));
+    }
+    if vec_item(ty).is_some() {
+        return Err(Error::new(ty.span(), "nested arrays are not supported in ROS messages"));
+    }
+    if let Type::Path(ref path) = *ty {
+        if let Some(name) = time_type(&path.path) {
+            return Ok(Base::Primitive(name.into()));
+        }
+        if let Some(ident) = path.path.get_ident() {
+            let name = match &ident.to_string()[..] {
+                "bool" => "bool",
+                "i8" => "int8",
+                "u8" => "uint8",
+                "i16" => "int16",
+                "u16" => "uint16",
+                "i32" => "int32",
+                "u32" => "uint32",
+                "i64" => "int64",
+                "u64" => "uint64",
+                "f32" => "float32",
+                "f64" => "float64",
+                "String" => "string",
+                _ => return Ok(Base::Message(ty.clone())),
+            };
+            return Ok(Base::Primitive(name.into()));
+        }
+    }
+    Ok(Base::Message(ty.clone()))
+}
@@ -0,0 +1,198 @@
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_rosmsg;
+
+use serde_rosmsg::msg::{definition, MessageName, MessageSpec};
+use serde_rosmsg::time::{self, Duration, Time};
+use serde_rosmsg::{from_slice, header_from_slice, header_to_vec, to_vec, RosMessage};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "std_msgs/Header")]
+#[allow(dead_code)]
+struct Header {
+    seq: u32,
+    #[rosmsg(type = "time")]
+    stamp: (u32, u32),
+    frame_id: String,
+}
+
+#[derive(Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "geometry_msgs/Point")]
+#[allow(dead_code)]
+struct Point {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[derive(Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "geometry_msgs/Quaternion")]
+#[allow(dead_code)]
+struct Quaternion {
+    x: f64,
+    y: f64,
+    z: f64,
+    w: f64,
+}
+
+#[derive(Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "geometry_msgs/Pose")]
+#[allow(dead_code)]
+struct Pose {
+    position: Point,
+    orientation: Quaternion,
+}
+
+#[derive(Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "geometry_msgs/PoseArray")]
+#[allow(dead_code)]
+struct PoseArray {
+    header: Header,
+    poses: Vec<Pose>,
+}
+
+#[derive(Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "geometry_msgs/PoseWithCovariance")]
+#[allow(dead_code)]
+struct PoseWithCovariance {
+    pose: Pose,
+    #[rosmsg(type = "float64[36]")]
+    covariance: [[f64; 6]; 6],
+}
+
+#[derive(Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "my_msgs/Everything")]
+#[rosmsg(constant = "uint8 OK = 0", constant = "string NAME=everything # really")]
+#[allow(dead_code)]
+struct Everything {
+    a: bool,
+    b: i8,
+    c: u8,
+    d: i16,
+    e: u16,
+    f: i32,
+    g: u32,
+    h: i64,
+    i: u64,
+    j: f32,
+    k: f64,
+    l: String,
+    #[rosmsg(type = "duration")]
+    m: (i32, i32),
+    #[rosmsg(type = "byte[4]")]
+    n: [i8; 4],
+    r#type: Vec<u8>,
+    points: [Point; 2],
+    poses: Vec<PoseWithCovariance>,
+    first: PoseArray,
+    second: Pose,
+}
+
+#[derive(Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "std_msgs/Empty")]
+struct Empty {}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, RosMessage)]
+#[rosmsg(type = "my_msgs/Timing")]
+struct Timing {
+    stamp: time::Time,
+    timeout: serde_rosmsg::time::Duration,
+    stamps: Vec<::serde_rosmsg::time::Time>,
+    timeouts: [time::Duration; 2],
+}
+
+fn parse_definition<T: RosMessage>() -> HashMap<MessageName, MessageSpec> {
+    definition::parse(T::ROS_TYPE, T::DEFINITION).unwrap()
+}
+
+fn spec<T: RosMessage>(registry: &HashMap<MessageName, MessageSpec>) -> &MessageSpec {
+    &registry[&MessageName::parse(T::ROS_TYPE).unwrap()]
+}
+
+#[test]
+fn matches_golden_md5_sums() {
+    assert_eq!("2176decaecbce78abc3b96ef049fabed", Header::MD5SUM);
+    assert_eq!("4a842b65f413084dc2b10fb484ea7f17", Point::MD5SUM);
+    assert_eq!("e45d45a5a1ce597b249e23fb30fc871f", Pose::MD5SUM);
+    assert_eq!("916c28c5764443f268b296bb671b9d97", PoseArray::MD5SUM);
+    assert_eq!("c23e848cf1b7533a8d7c259073a97e6f", PoseWithCovariance::MD5SUM);
+    assert_eq!("d41d8cd98f00b204e9800998ecf8427e", Empty::MD5SUM);
+}
+
+#[test]
+fn writes_field_definitions() {
+    assert_eq!("geometry_msgs/Point", Point::ROS_TYPE);
+    assert_eq!("float64 x\nfloat64 y\nfloat64 z\n", Point::DEFINITION);
+    assert_eq!("uint32 seq\ntime stamp\nstring frame_id\n", Header::DEFINITION);
+    assert_eq!("", Empty::DEFINITION);
+    let registry = parse_definition::<Everything>();
+    assert_eq!("uint8 OK=0\n\
+                string NAME=everything # really\n\
+                bool a\nint8 b\nuint8 c\nint16 d\nuint16 e\nint32 f\nuint32 g\nint64 h\n\
+                uint64 i\nfloat32 j\nfloat64 k\nstring l\nduration m\nbyte[4] n\n\
+                uint8[] type\n\
+                geometry_msgs/Point[2] points\n\
+                geometry_msgs/PoseWithCovariance[] poses\n\
+                geometry_msgs/PoseArray first\n\
+                geometry_msgs/Pose second\n",
+               spec::<Everything>(&registry).text);
+}
+
+#[test]
+fn matches_runtime_full_definitions() {
+    let registry = parse_definition::<Everything>();
+    let names = spec::<Everything>(&registry)
+        .all_dependencies(&registry)
+        .unwrap()
+        .into_iter()
+        .map(|spec| spec.name.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["geometry_msgs/Point",
+                    "geometry_msgs/PoseWithCovariance",
+                    "geometry_msgs/Pose",
+                    "geometry_msgs/Quaternion",
+                    "geometry_msgs/PoseArray",
+                    "std_msgs/Header"],
+               names);
+    assert_eq!(Everything::DEFINITION,
+               spec::<Everything>(&registry).full_definition(&registry).unwrap());
+    let registry = parse_definition::<PoseArray>();
+    assert_eq!(PoseArray::DEFINITION,
+               spec::<PoseArray>(&registry).full_definition(&registry).unwrap());
+}
+
+#[test]
+fn matches_runtime_md5_sums() {
+    let registry = parse_definition::<Everything>();
+    assert_eq!(Everything::MD5SUM,
+               spec::<Everything>(&registry).md5sum(&registry).unwrap());
+}
+
+#[test]
+fn works_with_connection_headers() {
+    let point = Point {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let point = from_slice::<Point>(&to_vec(&point).unwrap()).unwrap();
+    assert_eq!(2.0, point.y);
+    let header = header_to_vec::<PoseArray>(&HashMap::new()).unwrap();
+    let header = header_from_slice::<PoseArray>(&header).unwrap();
+    assert_eq!(PoseArray::DEFINITION, header["message_definition"]);
+    header_from_slice::<Pose>(&to_vec(&header).unwrap()).unwrap_err();
+}
+
+#[test]
+fn maps_time_types() {
+    assert_eq!("time stamp\nduration timeout\ntime[] stamps\nduration[2] timeouts\n",
+               Timing::DEFINITION);
+    let timing = Timing {
+        stamp: Time::new(1, 2),
+        timeout: Duration::new(0, -1),
+        stamps: vec![Time::new(3, 4)],
+        timeouts: [Duration::new(5, 6), Duration::new(-7, 8)],
+    };
+    assert_eq!(timing, from_slice(&to_vec(&timing).unwrap()).unwrap());
+}
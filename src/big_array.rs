@@ -0,0 +1,102 @@
+//! Encoding of fixed size arrays with more than 32 items.
+//!
+//! Serde only implements its traits for arrays of up to 32 items, while ROS
+//! messages commonly contain larger fixed size arrays, like the 36 items of a
+//! covariance matrix. Fields with such arrays can be encoded with this module
+//! by using the `with` attribute of `serde_derive`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! extern crate serde_rosmsg;
+//! #[macro_use]
+//! extern crate serde_derive;
+//! use serde_rosmsg::{from_slice, to_vec};
+//!
+//! fn main() {
+//! #[derive(Serialize, Deserialize)]
+//! struct Covariance {
+//!     #[serde(with = "serde_rosmsg::big_array")]
+//!     values: [f64; 36],
+//! }
+//!
+//! let data = to_vec(&Covariance { values: [0.5; 36] }).unwrap();
+//! assert_eq!(data.len(), 4 + 36 * 8);
+//! assert_eq!(from_slice::<Covariance>(&data).unwrap().values[35], 0.5);
+//! }
+//! ```
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Serialize an array of any size as a tuple.
+pub fn serialize<S, T, const N: usize>(value: &[T; N], serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+          T: Serialize
+{
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for item in value {
+        tuple.serialize_element(item)?;
+    }
+    tuple.end()
+}
+
+/// Deserialize an array of any size from a tuple.
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where D: Deserializer<'de>,
+          T: Deserialize<'de>
+{
+    struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+        type Value = [T; N];
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an array of {} items", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[T; N], A::Error> {
+            let mut items = Vec::with_capacity(N);
+            while let Some(item) = seq.next_element()? {
+                items.push(item);
+            }
+            let len = items.len();
+            <[T; N]>::try_from(items).map_err(|_| de::Error::invalid_length(len, &self))
+        }
+    }
+
+    deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use {from_slice, to_vec};
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Data {
+        prefix: u8,
+        #[serde(with = "super")]
+        values: [u16; 40],
+        suffix: u8,
+    }
+
+    #[test]
+    fn encodes_items_without_length() {
+        let mut data = Data {
+            prefix: 1,
+            values: [0; 40],
+            suffix: 2,
+        };
+        for (index, value) in data.values.iter_mut().enumerate() {
+            *value = index as u16;
+        }
+        let bytes = to_vec(&data).unwrap();
+        assert_eq!(86, bytes.len());
+        assert_eq!(&[82, 0, 0, 0, 1, 0, 0, 1, 0], &bytes[..9]);
+        assert_eq!(&[39, 0, 2], &bytes[83..]);
+        assert_eq!(data, from_slice(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,234 @@
+//! Tokio codec for streams of ROSMSG data.
+//!
+//! `RosMsgCodec` splits a byte stream into length-prefixed frames and
+//! converts them from and into values of a single type, so wrapping a socket
+//! in `Framed` gives a stream and a sink of messages. It is available with
+//! the `async` feature enabled.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! extern crate futures;
+//! extern crate serde_rosmsg;
+//! extern crate tokio;
+//! extern crate tokio_util;
+//!
+//! use futures::{SinkExt, StreamExt};
+//! use serde_rosmsg::codec::RosMsgCodec;
+//! use tokio::net::TcpStream;
+//! use tokio_util::codec::Framed;
+//!
+//! async fn echo(stream: TcpStream) -> serde_rosmsg::error::Result<()> {
+//!     let mut framed = Framed::new(stream, RosMsgCodec::<String>::new());
+//!     while let Some(message) = framed.next().await {
+//!         framed.send(message?).await?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use super::de::from_slice;
+use super::error::{Error, ErrorKind, Result};
+use super::ser::to_writer;
+use byteorder::{ByteOrder, LittleEndian};
+use bytes::{BufMut, BytesMut};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::{cmp, fmt};
+use std::marker::PhantomData;
+use tokio_util::codec::{Decoder, Encoder};
+
+/// Largest amount of memory reserved at once for an incomplete frame.
+const MAX_RESERVED_LENGTH: usize = 64 * 1024;
+
+/// Codec decoding frames into values of type `T`, and encoding values of
+/// type `T` into frames.
+///
+/// Frames of any length are accepted unless `max_frame_length` is set.
+pub struct RosMsgCodec<T> {
+    max_length: Option<u32>,
+    phantom: PhantomData<fn(T) -> T>,
+}
+
+impl<T> RosMsgCodec<T> {
+    /// Create a codec for values of type `T`.
+    pub fn new() -> Self {
+        RosMsgCodec {
+            max_length: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Set the largest length of a received frame, excluding its prefix.
+    ///
+    /// Frames announcing a larger length are rejected as soon as their
+    /// prefix is received, instead of buffering data for them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate bytes;
+    /// # extern crate serde_rosmsg;
+    /// # extern crate tokio_util;
+    /// # use bytes::BytesMut;
+    /// # use serde_rosmsg::codec::RosMsgCodec;
+    /// # use tokio_util::codec::Decoder;
+    /// # fn main() {
+    /// let mut codec = RosMsgCodec::<String>::new().max_frame_length(4);
+    /// let mut buffer = BytesMut::from(&b"\x05\0\0\0"[..]);
+    /// assert!(codec.decode(&mut buffer).is_err());
+    /// # }
+    /// ```
+    pub fn max_frame_length(mut self, max_length: u32) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+}
+
+impl<T> Clone for RosMsgCodec<T> {
+    fn clone(&self) -> Self {
+        RosMsgCodec {
+            max_length: self.max_length,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for RosMsgCodec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for RosMsgCodec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RosMsgCodec").field("max_length", &self.max_length).finish()
+    }
+}
+
+impl<T> Decoder for RosMsgCodec<T>
+    where T: DeserializeOwned
+{
+    type Item = T;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<T>> {
+        if src.len() < 4 {
+            return Ok(None);
+        }
+        let length = LittleEndian::read_u32(src);
+        if let Some(max_length) = self.max_length {
+            if length > max_length {
+                bail!(ErrorKind::FrameTooLarge(length, max_length));
+            }
+        }
+        let end = 4 + length as usize;
+        if src.len() < end {
+            // The length comes from the data, so memory is reserved as the
+            // data arrives, instead of all at once.
+            src.reserve(cmp::min(end - src.len(), MAX_RESERVED_LENGTH));
+            return Ok(None);
+        }
+        let frame = src.split_to(end);
+        from_slice(&frame).map(Some)
+    }
+}
+
+impl<T> Encoder<T> for RosMsgCodec<T>
+    where T: Serialize
+{
+    type Error = Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<()> {
+        to_writer(&mut dst.writer(), &item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::StreamExt;
+    use header::ConnectionHeader;
+    use tokio_util::codec::FramedRead;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Pose {
+        position: (f64, f64, f64),
+        orientation: (f64, f64, f64, f64),
+    }
+
+    fn pose() -> Pose {
+        Pose {
+            position: (1.0, 2.0, 3.0),
+            orientation: (4.0, 5.0, 6.0, 7.0),
+        }
+    }
+
+    #[test]
+    fn decodes_partial_data() {
+        let data = include_bytes!("datatests/pose_msg.bin");
+        let mut codec = RosMsgCodec::<Pose>::new();
+        let mut buffer = BytesMut::new();
+        for chunk in data.chunks(5) {
+            assert_eq!(None, codec.decode(&mut buffer).unwrap());
+            buffer.extend_from_slice(chunk);
+        }
+        buffer.extend_from_slice(&data[..2]);
+        assert_eq!(Some(pose()), codec.decode(&mut buffer).unwrap());
+        assert_eq!(None, codec.decode(&mut buffer).unwrap());
+        assert_eq!(2, buffer.len());
+    }
+
+    #[test]
+    fn reserves_memory_as_data_arrives() {
+        let mut codec = RosMsgCodec::<String>::new();
+        let mut buffer = BytesMut::from(&b"\xfb\xff\xff\xff"[..]);
+        assert_eq!(None, codec.decode(&mut buffer).unwrap());
+        assert!(buffer.capacity() <= 4 + MAX_RESERVED_LENGTH, "{}", buffer.capacity());
+        let mut buffer = BytesMut::from(&b"\x05\0\0\0\x01\0\0\0"[..]);
+        assert_eq!(None, codec.decode(&mut buffer).unwrap());
+        buffer.extend_from_slice(b"a");
+        assert_eq!(Some("a".to_string()), codec.decode(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn encodes_frames() {
+        let mut codec = RosMsgCodec::<Pose>::new();
+        let mut buffer = BytesMut::new();
+        codec.encode(pose(), &mut buffer).unwrap();
+        codec.encode(pose(), &mut buffer).unwrap();
+        let mut expected = include_bytes!("datatests/pose_msg.bin").to_vec();
+        expected.extend_from_slice(include_bytes!("datatests/pose_msg.bin"));
+        assert_eq!(expected, &buffer[..]);
+    }
+
+    #[test]
+    fn rejects_large_frames() {
+        let mut codec = RosMsgCodec::<Pose>::new().max_frame_length(55);
+        let mut buffer = BytesMut::from(&include_bytes!("datatests/pose_msg.bin")[..]);
+        match *codec.decode(&mut buffer).unwrap_err().kind() {
+            ErrorKind::FrameTooLarge(56, 55) => {}
+            ref error => panic!("Frame too large error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn streams_messages() {
+        let mut data = include_bytes!("datatests/pose_res.bin").to_vec();
+        for _ in 0..3 {
+            data.extend_from_slice(include_bytes!("datatests/pose_msg.bin"));
+        }
+        let mut headers = FramedRead::new(&data[..], RosMsgCodec::<ConnectionHeader>::new());
+        let header = block_on(headers.next()).unwrap().unwrap();
+        assert_eq!(Some("/meow"), header.topic());
+        let parts = headers.into_parts();
+        let mut poses = FramedRead::new(parts.io, RosMsgCodec::<Pose>::new());
+        *poses.read_buffer_mut() = parts.read_buf;
+        let poses = block_on(poses.collect::<Vec<_>>());
+        assert_eq!(3, poses.len());
+        for received in poses {
+            assert_eq!(pose(), received.unwrap());
+        }
+    }
+}
@@ -0,0 +1,727 @@
+//! Generate Rust types from ROS message and service definitions.
+//!
+//! The generator is meant to be used from build scripts. It looks for ROS
+//! packages in the given search paths, the same way ROS does with the
+//! `ROS_PACKAGE_PATH` environment variable, so no ROS installation is needed.
+//! A package is any directory containing a `package.xml` file, or a `msg` or
+//! `srv` directory, and its name is the name of the directory.
+//!
+//! Every package becomes a module, which contains a struct for each message
+//! and for the request and response of each service. The structs implement
+//! `Serialize`, `Deserialize` and `RosMessage`, and their constants become
+//! associated constants. Each service also gets a unit struct holding the
+//! name and MD5 sum of the service type.
+//!
+//! # Examples
+//!
+//! In `build.rs`:
+//!
+//! ```rust,no_run
+//! extern crate serde_rosmsg;
+//!
+//! use serde_rosmsg::codegen::Generator;
+//! use std::env;
+//! use std::path::Path;
+//!
+//! fn main() {
+//!     let output = Path::new(&env::var("OUT_DIR").unwrap()).join("msgs.rs");
+//!     Generator::new()
+//!         .search_path("msg_packages")
+//!         .search_paths_from_env("ROS_PACKAGE_PATH")
+//!         .package("my_msgs")
+//!         .write_to_file(output)
+//!         .unwrap();
+//!     println!("cargo:rerun-if-changed=msg_packages");
+//! }
+//! ```
+//!
+//! In the crate, with `serde_derive`'s macros imported at the crate root:
+//!
+//! ```rust,ignore
+//! #[macro_use]
+//! extern crate serde_derive;
+//! extern crate serde_rosmsg;
+//!
+//! mod msgs {
+//!     include!(concat!(env!("OUT_DIR"), "/msgs.rs"));
+//! }
+//!
+//! use msgs::geometry_msgs::Point;
+//! ```
+//!
+//! Message types used by the requested packages are generated as well, so
+//! their packages need to be available in the search paths.
+
+use super::error::{ErrorKind, Result, ResultExt};
+use super::msg::{ArrayLength, BaseType, ConstantValue, FieldType, MessageName, MessageSpec,
+                 PrimitiveType, ServiceSpec};
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Serde only implements its traits for arrays up to this length.
+const MAX_SERDE_ARRAY: usize = 32;
+
+static KEYWORDS: &[&str] = &["abstract", "as", "async", "await", "become", "box", "break",
+                             "const", "continue", "do", "dyn", "else", "enum", "extern", "false",
+                             "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop",
+                             "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+                             "ref", "return", "static", "struct", "trait", "true", "try", "type",
+                             "typeof", "unsafe", "unsized", "use", "virtual", "where", "while",
+                             "yield"];
+
+/// Generator of Rust modules from ROS packages.
+///
+/// By default, all packages found in the search paths are generated. If
+/// packages are listed explicitly, only those packages and the packages
+/// they depend on are generated.
+#[derive(Clone, Debug, Default)]
+pub struct Generator {
+    search_paths: Vec<PathBuf>,
+    packages: Vec<String>,
+}
+
+impl Generator {
+    /// Create a generator without any search paths.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a directory to search for packages in.
+    ///
+    /// Directories are searched recursively, in the order they were added.
+    /// If a package is found multiple times, the first one is used.
+    pub fn search_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.search_paths.push(path.into());
+        self
+    }
+
+    /// Add the directories listed in an environment variable, like
+    /// `ROS_PACKAGE_PATH`, to search for packages in.
+    ///
+    /// Missing variables are ignored.
+    pub fn search_paths_from_env(mut self, variable: &str) -> Self {
+        if let Some(paths) = env::var_os(variable) {
+            self.search_paths.extend(env::split_paths(&paths));
+        }
+        self
+    }
+
+    /// Add a package to generate.
+    pub fn package<S: Into<String>>(mut self, name: S) -> Self {
+        self.packages.push(name.into());
+        self
+    }
+
+    /// Generate the Rust code of all the requested packages.
+    pub fn generate(&self) -> Result<String> {
+        let found = self.find_packages()?;
+        let mut packages = BTreeMap::new();
+        let mut pending = if self.packages.is_empty() {
+            found.keys().cloned().collect()
+        } else {
+            self.packages.clone()
+        };
+        while let Some(name) = pending.pop() {
+            if packages.contains_key(&name) {
+                continue;
+            }
+            let path = match found.get(&name) {
+                Some(path) => path,
+                None => bail!(ErrorKind::MissingPackage(name)),
+            };
+            let package = Package::load(&name, path)?;
+            for dependency in package.dependencies() {
+                if !packages.contains_key(dependency) {
+                    pending.push(dependency.clone());
+                }
+            }
+            packages.insert(name, package);
+        }
+
+        let mut registry = HashMap::new();
+        for package in packages.values() {
+            for spec in package.specs() {
+                registry.insert(spec.name.clone(), spec.clone());
+            }
+        }
+
+        let mut code = String::from("// Generated by serde_rosmsg::codegen, do not edit.\n");
+        for (name, package) in &packages {
+            code.push('\n');
+            package.generate(name, &registry, &mut code)?;
+        }
+        Ok(code)
+    }
+
+    /// Generate the Rust code of all the requested packages into a file.
+    ///
+    /// The file is left untouched if it already contains the same code, to
+    /// avoid needless recompilation.
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let code = self.generate()?;
+        if fs::read_to_string(path).ok().as_ref() != Some(&code) {
+            fs::write(path, code).chain_err(|| format!("Failed to write {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    fn find_packages(&self) -> Result<BTreeMap<String, PathBuf>> {
+        let mut packages = BTreeMap::new();
+        for path in &self.search_paths {
+            if path.is_dir() {
+                find_packages(path, &mut packages)?;
+            }
+        }
+        Ok(packages)
+    }
+}
+
+fn find_packages(path: &Path, packages: &mut BTreeMap<String, PathBuf>) -> Result<()> {
+    if path.join("package.xml").is_file() || path.join("msg").is_dir() ||
+       path.join("srv").is_dir() {
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+            packages.entry(name.into()).or_insert_with(|| path.into());
+        }
+        return Ok(());
+    }
+    for entry in sorted_entries(path)? {
+        let hidden = entry.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if entry.is_dir() && !hidden {
+            find_packages(&entry, packages)?;
+        }
+    }
+    Ok(())
+}
+
+fn sorted_entries(path: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(path)
+        .chain_err(|| format!("Failed to read {}", path.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<::std::io::Result<Vec<_>>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Read all the definitions with the given extension in a directory.
+fn read_definitions(path: &Path, extension: &str) -> Result<Vec<(String, String)>> {
+    if !path.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut definitions = Vec::new();
+    for entry in sorted_entries(path)? {
+        if entry.extension().and_then(|v| v.to_str()) != Some(extension) {
+            continue;
+        }
+        let name = match entry.file_stem().and_then(|v| v.to_str()) {
+            Some(name) => name.to_owned(),
+            None => continue,
+        };
+        let text = fs::read_to_string(&entry)
+            .chain_err(|| format!("Failed to read {}", entry.display()))?;
+        definitions.push((name, text));
+    }
+    Ok(definitions)
+}
+
+/// Message and service definitions of a package.
+struct Package {
+    messages: Vec<MessageSpec>,
+    services: Vec<ServiceSpec>,
+}
+
+impl Package {
+    fn load(name: &str, path: &Path) -> Result<Self> {
+        let mut package = Package {
+            messages: Vec::new(),
+            services: Vec::new(),
+        };
+        for (message, text) in read_definitions(&path.join("msg"), "msg")? {
+            let full_name = format!("{}/{}", name, message);
+            package.messages.push(MessageSpec::parse(&full_name, &text)
+                .chain_err(|| format!("Failed to parse message {}", full_name))?);
+        }
+        for (service, text) in read_definitions(&path.join("srv"), "srv")? {
+            let full_name = format!("{}/{}", name, service);
+            package.services.push(ServiceSpec::parse(&full_name, &text)
+                .chain_err(|| format!("Failed to parse service {}", full_name))?);
+        }
+        Ok(package)
+    }
+
+    fn specs(&self) -> Vec<&MessageSpec> {
+        let mut specs = self.messages.iter().collect::<Vec<_>>();
+        for service in &self.services {
+            specs.push(&service.request);
+            specs.push(&service.response);
+        }
+        specs
+    }
+
+    fn dependencies(&self) -> Vec<&String> {
+        let mut packages = Vec::new();
+        for spec in self.specs() {
+            for name in spec.dependencies() {
+                if !packages.contains(&&name.package) {
+                    packages.push(&name.package);
+                }
+            }
+        }
+        packages
+    }
+
+    fn generate(&self,
+                name: &str,
+                registry: &HashMap<MessageName, MessageSpec>,
+                code: &mut String)
+                -> Result<()> {
+        let _ = writeln!(code, "pub mod {} {{", identifier(name));
+        let _ = writeln!(code,
+                         "    #![allow(dead_code, non_camel_case_types, non_snake_case, \
+                          non_upper_case_globals)]");
+        for spec in &self.messages {
+            code.push('\n');
+            generate_message(spec, registry, code)?;
+        }
+        for service in &self.services {
+            code.push('\n');
+            generate_message(&service.request, registry, code)?;
+            code.push('\n');
+            generate_message(&service.response, registry, code)?;
+            code.push('\n');
+            generate_service(service, registry, code)?;
+        }
+        code.push_str("}\n");
+        Ok(())
+    }
+}
+
+/// Turn a ROS name into a valid Rust identifier.
+fn identifier(name: &str) -> String {
+    match name {
+        "self" | "Self" | "super" | "crate" => format!("{}_", name),
+        _ if KEYWORDS.contains(&name) => format!("r#{}", name),
+        _ => name.into(),
+    }
+}
+
+fn primitive_type(primitive: PrimitiveType) -> &'static str {
+    match primitive {
+        PrimitiveType::Bool => "bool",
+        PrimitiveType::Int8 | PrimitiveType::Byte => "i8",
+        PrimitiveType::UInt8 | PrimitiveType::Char => "u8",
+        PrimitiveType::Int16 => "i16",
+        PrimitiveType::UInt16 => "u16",
+        PrimitiveType::Int32 => "i32",
+        PrimitiveType::UInt32 => "u32",
+        PrimitiveType::Int64 => "i64",
+        PrimitiveType::UInt64 => "u64",
+        PrimitiveType::Float32 => "f32",
+        PrimitiveType::Float64 => "f64",
+        PrimitiveType::String => "::std::string::String",
+        PrimitiveType::Time => "::serde_rosmsg::time::Time",
+        PrimitiveType::Duration => "::serde_rosmsg::time::Duration",
+    }
+}
+
+fn field_type(field_type: &FieldType) -> String {
+    let base = match field_type.base {
+        BaseType::Primitive(primitive) => primitive_type(primitive).into(),
+        BaseType::Message(ref name) => {
+            format!("super::{}::{}", identifier(&name.package), identifier(&name.name))
+        }
+    };
+    match field_type.array {
+        None => base,
+        Some(ArrayLength::Variable) => format!("::std::vec::Vec<{}>", base),
+        Some(ArrayLength::Fixed(len)) => format!("[{}; {}]", base, len),
+    }
+}
+
+fn is_big_array(field_type: &FieldType) -> bool {
+    match field_type.array {
+        Some(ArrayLength::Fixed(len)) => len > MAX_SERDE_ARRAY,
+        _ => false,
+    }
+}
+
+fn constant_value(primitive: PrimitiveType, value: &ConstantValue) -> String {
+    match *value {
+        ConstantValue::Bool(v) => v.to_string(),
+        ConstantValue::Int(v) => v.to_string(),
+        ConstantValue::UInt(v) => v.to_string(),
+        ConstantValue::String(ref v) => format!("{:?}", v),
+        ConstantValue::Float(v) => {
+            let float = primitive_type(primitive);
+            if v.is_nan() {
+                format!("::std::{}::NAN", float)
+            } else if v.is_infinite() {
+                format!("::std::{}::{}INFINITY", float, if v < 0.0 { "NEG_" } else { "" })
+            } else {
+                format!("{:?}", v)
+            }
+        }
+    }
+}
+
+fn write_comment(code: &mut String, indent: &str, comment: &Option<String>) {
+    if let Some(ref comment) = *comment {
+        let _ = writeln!(code, "{}/// {}", indent, comment);
+    }
+}
+
+fn write_metadata(code: &mut String, ros_type: &str, md5sum: &str) {
+    let _ = writeln!(code, "        const ROS_TYPE: &'static str = {:?};", ros_type);
+    let _ = writeln!(code, "        const MD5SUM: &'static str = {:?};", md5sum);
+}
+
+fn generate_message(spec: &MessageSpec,
+                    registry: &HashMap<MessageName, MessageSpec>,
+                    code: &mut String)
+                    -> Result<()> {
+    let name = identifier(&spec.name.name);
+    let big_arrays = spec.fields.iter().any(|field| is_big_array(&field.field_type));
+    let _ = writeln!(code, "    /// Message type `{}`.", spec.name);
+    let _ = writeln!(code,
+                     "    #[derive(Clone, Debug, {}PartialEq, Serialize, Deserialize)]",
+                     if big_arrays { "" } else { "Default, " });
+    let _ = writeln!(code, "    pub struct {} {{", name);
+    for field in &spec.fields {
+        write_comment(code, "        ", &field.comment);
+        if is_big_array(&field.field_type) {
+            let _ = writeln!(code, "        #[serde(with = \"::serde_rosmsg::big_array\")]");
+        }
+        let _ = writeln!(code,
+                         "        pub {}: {},",
+                         identifier(&field.name),
+                         field_type(&field.field_type));
+    }
+    code.push_str("    }\n");
+
+    // Arrays longer than 32 items do not implement `Default` either.
+    if big_arrays {
+        let _ = writeln!(code, "\n    impl ::std::default::Default for {} {{", name);
+        code.push_str("        fn default() -> Self {\n");
+        code.push_str("            Self {\n");
+        for field in &spec.fields {
+            let value = if is_big_array(&field.field_type) {
+                "::std::array::from_fn(|_| ::std::default::Default::default())"
+            } else {
+                "::std::default::Default::default()"
+            };
+            let _ = writeln!(code, "                {}: {},", identifier(&field.name), value);
+        }
+        code.push_str("            }\n        }\n    }\n");
+    }
+
+    if !spec.constants.is_empty() {
+        let _ = writeln!(code, "\n    impl {} {{", name);
+        for constant in &spec.constants {
+            write_comment(code, "        ", &constant.comment);
+            let constant_type = match constant.constant_type {
+                PrimitiveType::String => "&'static str",
+                primitive => primitive_type(primitive),
+            };
+            let _ = writeln!(code,
+                             "        pub const {}: {} = {};",
+                             identifier(&constant.name),
+                             constant_type,
+                             constant_value(constant.constant_type, &constant.value));
+        }
+        code.push_str("    }\n");
+    }
+
+    let _ = writeln!(code, "\n    impl ::serde_rosmsg::RosMessage for {} {{", name);
+    write_metadata(code, &spec.name.to_string(), &spec.md5sum(registry)?);
+    let _ = writeln!(code,
+                     "        const DEFINITION: &'static str = {:?};",
+                     spec.full_definition(registry)?);
+    code.push_str("    }\n");
+    Ok(())
+}
+
+fn generate_service(spec: &ServiceSpec,
+                    registry: &HashMap<MessageName, MessageSpec>,
+                    code: &mut String)
+                    -> Result<()> {
+    let name = identifier(&spec.name.name);
+    let _ = writeln!(code,
+                     "    /// Service type `{}`, made of `{}` and `{}`.",
+                     spec.name,
+                     spec.request.name.name,
+                     spec.response.name.name);
+    let _ = writeln!(code, "    #[derive(Clone, Copy, Debug, Default, PartialEq)]");
+    let _ = writeln!(code, "    pub struct {};", name);
+    let _ = writeln!(code, "\n    impl {} {{", name);
+    let _ = writeln!(code, "        /// Full name of the service type.");
+    let _ = writeln!(code, "        pub const ROS_TYPE: &'static str = {:?};", spec.name.to_string());
+    let _ = writeln!(code, "        /// MD5 sum of the service type.");
+    let _ = writeln!(code, "        pub const MD5SUM: &'static str = {:?};", spec.md5sum(registry)?);
+    code.push_str("    }\n");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DIRECTORY_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    /// Temporary directory tree, removed when dropped.
+    struct Tree(PathBuf);
+
+    impl Tree {
+        fn new(files: &[(&str, &str)]) -> Self {
+            let root = env::temp_dir().join(format!("serde_rosmsg_codegen_{}_{}",
+                                                    ::std::process::id(),
+                                                    DIRECTORY_COUNT.fetch_add(1,
+                                                                              Ordering::SeqCst)));
+            for &(path, text) in files {
+                let path = root.join(path);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, text).unwrap();
+            }
+            Tree(root)
+        }
+    }
+
+    impl Drop for Tree {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn geometry() -> Tree {
+        Tree::new(&[("core/std_msgs/package.xml", "<package/>"),
+                    ("core/std_msgs/msg/Header.msg",
+                     "uint32 seq\ntime stamp\nstring frame_id\n"),
+                    ("core/std_msgs/msg/String.msg", "string data\n"),
+                    ("core/geometry_msgs/msg/Point.msg",
+                     "float64 x # Forward\nfloat64 y\nfloat64 z\n"),
+                    ("core/geometry_msgs/msg/PoseArray.msg", "Header header\nPose[] poses\n"),
+                    ("core/geometry_msgs/msg/Pose.msg",
+                     "Point position\nQuaternion orientation\n"),
+                    ("core/geometry_msgs/msg/Quaternion.msg",
+                     "float64 x\nfloat64 y\nfloat64 z\nfloat64 w\n"),
+                    ("core/geometry_msgs/msg/PoseWithCovariance.msg",
+                     "Pose pose\nfloat64[36] covariance\n"),
+                    ("core/geometry_msgs/msg/PoseWithCovarianceStamped.msg",
+                     "Header header\nPoseWithCovariance pose\n"),
+                    ("core/geometry_msgs/msg/notes.txt", "Not a message"),
+                    ("custom/my_msgs/msg/Status.msg",
+                     "uint8 OK=0 # All good\nint8 LOW=-3\nfloat32 GAIN=1.5\n\
+                      bool ON=True\nstring NAME=a \"b\" # c\n\
+                      uint8 level\nbyte[4] type\nduration timeout\n"),
+                    ("custom/my_msgs/srv/AddTwoInts.srv", "int64 a\nint64 b\n---\nint64 sum\n"),
+                    ("custom/other_msgs/msg/Other.msg", "string data\n")])
+    }
+
+    fn generate(generator: Generator) -> String {
+        generator.generate().unwrap()
+    }
+
+    #[test]
+    fn generates_requested_packages_and_dependencies() {
+        let tree = geometry();
+        let code = generate(Generator::new()
+            .search_path(tree.0.join("custom"))
+            .search_path(tree.0.join("core"))
+            .package("geometry_msgs"));
+        assert!(code.contains("pub mod geometry_msgs {"));
+        assert!(code.contains("pub mod std_msgs {"));
+        assert!(!code.contains("pub mod my_msgs {"));
+        assert!(!code.contains("pub mod other_msgs {"));
+        assert!(code.starts_with("// Generated by serde_rosmsg::codegen, do not edit.\n"));
+    }
+
+    #[test]
+    fn generates_all_packages_by_default() {
+        let tree = geometry();
+        let code = generate(Generator::new().search_path(&tree.0));
+        for package in &["geometry_msgs", "my_msgs", "other_msgs", "std_msgs"] {
+            assert!(code.contains(&format!("pub mod {} {{", package)));
+        }
+    }
+
+    #[test]
+    fn generates_message_structs() {
+        let tree = geometry();
+        let code = generate(Generator::new().search_path(&tree.0).package("geometry_msgs"));
+        assert!(code.contains("    /// Message type `geometry_msgs/Point`.\n    \
+                               #[derive(Clone, Debug, Default, PartialEq, Serialize, \
+                               Deserialize)]\n    \
+                               pub struct Point {\n        \
+                               /// Forward\n        \
+                               pub x: f64,\n        \
+                               pub y: f64,\n        \
+                               pub z: f64,\n    \
+                               }\n"));
+        assert!(code.contains("    pub struct PoseArray {\n        \
+                               pub header: super::std_msgs::Header,\n        \
+                               pub poses: ::std::vec::Vec<super::geometry_msgs::Pose>,\n    \
+                               }\n"));
+        assert!(code.contains("    pub struct Header {\n        \
+                               pub seq: u32,\n        \
+                               pub stamp: ::serde_rosmsg::time::Time,\n        \
+                               pub frame_id: ::std::string::String,\n    \
+                               }\n"));
+        assert!(code.contains("    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]\n    \
+                               pub struct PoseWithCovariance {\n        \
+                               pub pose: super::geometry_msgs::Pose,\n        \
+                               #[serde(with = \"::serde_rosmsg::big_array\")]\n        \
+                               pub covariance: [f64; 36],\n    \
+                               }\n\n    \
+                               impl ::std::default::Default for PoseWithCovariance {\n        \
+                               fn default() -> Self {\n            \
+                               Self {\n                \
+                               pose: ::std::default::Default::default(),\n                \
+                               covariance: ::std::array::from_fn(|_| \
+                               ::std::default::Default::default()),\n            \
+                               }\n        \
+                               }\n    \
+                               }\n"));
+    }
+
+    #[test]
+    fn generates_metadata() {
+        let tree = geometry();
+        let code = generate(Generator::new().search_path(&tree.0).package("geometry_msgs"));
+        assert!(code.contains("    impl ::serde_rosmsg::RosMessage for PoseArray {\n        \
+                               const ROS_TYPE: &'static str = \"geometry_msgs/PoseArray\";\n        \
+                               const MD5SUM: &'static str = \
+                               \"916c28c5764443f268b296bb671b9d97\";\n        \
+                               const DEFINITION: &'static str = \"Header header\\nPose[] \
+                               poses\\n\\n====="));
+        assert!(code.contains("\"c23e848cf1b7533a8d7c259073a97e6f\""));
+        assert!(code.contains("const DEFINITION: &'static str = \"string data\\n\";"));
+    }
+
+    #[test]
+    fn generates_constants() {
+        let tree = geometry();
+        let code = generate(Generator::new().search_path(&tree.0).package("my_msgs"));
+        assert!(code.contains("    impl Status {\n        \
+                               /// All good\n        \
+                               pub const OK: u8 = 0;\n        \
+                               pub const LOW: i8 = -3;\n        \
+                               pub const GAIN: f32 = 1.5;\n        \
+                               pub const ON: bool = true;\n        \
+                               pub const NAME: &'static str = \"a \\\"b\\\" # c\";\n    \
+                               }\n"));
+        assert!(code.contains("        pub level: u8,\n        \
+                               pub r#type: [i8; 4],\n        \
+                               pub timeout: ::serde_rosmsg::time::Duration,\n"));
+    }
+
+    #[test]
+    fn generates_services() {
+        let tree = geometry();
+        let code = generate(Generator::new().search_path(&tree.0).package("my_msgs"));
+        assert!(code.contains("    pub struct AddTwoIntsRequest {\n        \
+                               pub a: i64,\n        \
+                               pub b: i64,\n    \
+                               }\n"));
+        assert!(code.contains("    pub struct AddTwoIntsResponse {\n        \
+                               pub sum: i64,\n    \
+                               }\n"));
+        assert!(code.contains("    impl AddTwoInts {\n        \
+                               /// Full name of the service type.\n        \
+                               pub const ROS_TYPE: &'static str = \"my_msgs/AddTwoInts\";\n        \
+                               /// MD5 sum of the service type.\n        \
+                               pub const MD5SUM: &'static str = \
+                               \"6a2e34150c00229791cc89ff309fff21\";\n    \
+                               }\n"));
+    }
+
+    #[test]
+    fn prefers_earlier_search_paths() {
+        let tree = geometry();
+        let other = Tree::new(&[("std_msgs/msg/String.msg", "string value\n")]);
+        let code = generate(Generator::new()
+            .search_path(&other.0)
+            .search_path(&tree.0)
+            .package("std_msgs"));
+        assert!(code.contains("pub value: ::std::string::String"));
+        assert!(!code.contains("pub struct Header"));
+    }
+
+    #[test]
+    fn reports_missing_packages() {
+        let tree = geometry();
+        let error = Generator::new()
+            .search_path(tree.0.join("core/geometry_msgs"))
+            .package("geometry_msgs")
+            .generate()
+            .unwrap_err();
+        match *error.kind() {
+            ErrorKind::MissingPackage(ref name) if name == "std_msgs" => {}
+            _ => panic!("Missing package error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn reports_bad_definitions() {
+        let tree = Tree::new(&[("bad_msgs/msg/Bad.msg", "int32 a b\n")]);
+        let error = Generator::new().search_path(&tree.0).generate().unwrap_err();
+        assert_eq!("Failed to parse message bad_msgs/Bad", error.to_string());
+        let cause = error.iter().nth(1).unwrap().to_string();
+        assert!(cause.starts_with("Bad message definition at line 1"), "{}", cause);
+    }
+
+    /// Checks that the code of the `msgs` module is generated from the
+    /// bundled definitions. Set `SERDE_ROSMSG_UPDATE_MSGS` to regenerate it.
+    #[test]
+    fn bundled_messages_are_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/msgs");
+        let generator = Generator::new().search_path(root.join("definitions"));
+        let path = root.join("generated.rs");
+        if env::var_os("SERDE_ROSMSG_UPDATE_MSGS").is_some() {
+            generator.write_to_file(&path).unwrap();
+        }
+        assert!(generator.generate().unwrap() == fs::read_to_string(&path).unwrap(),
+                "Bundled messages are outdated, run tests with SERDE_ROSMSG_UPDATE_MSGS=1");
+    }
+
+    /// Code generated from the `geometry` tree, compiled along with the tests.
+    mod compiled {
+        include!("datatests/generated.rs");
+    }
+
+    /// Checks that the generated code compiles, including messages that
+    /// nest arrays without `Default` implementations. Set
+    /// `SERDE_ROSMSG_UPDATE_MSGS` to regenerate it.
+    #[test]
+    fn generates_compilable_code() {
+        use self::compiled::geometry_msgs::PoseWithCovarianceStamped;
+        use {from_slice, to_vec};
+
+        let tree = geometry();
+        let generator = Generator::new().search_path(&tree.0);
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/datatests/generated.rs");
+        if env::var_os("SERDE_ROSMSG_UPDATE_MSGS").is_some() {
+            generator.write_to_file(&path).unwrap();
+        }
+        assert!(generate(generator) == fs::read_to_string(&path).unwrap(),
+                "Generated test code is outdated, run tests with SERDE_ROSMSG_UPDATE_MSGS=1");
+
+        let mut message = PoseWithCovarianceStamped::default();
+        assert_eq!([0.0; 36], message.pose.covariance);
+        message.header.frame_id = "map".into();
+        message.pose.covariance[35] = 1.5;
+        let data = to_vec(&message).unwrap();
+        assert_eq!(message, from_slice(&data).unwrap());
+    }
+
+    #[test]
+    fn writes_files() {
+        let tree = geometry();
+        let path = tree.0.join("msgs.rs");
+        let generator = Generator::new().search_path(&tree.0).package("std_msgs");
+        generator.write_to_file(&path).unwrap();
+        assert_eq!(generator.generate().unwrap(), fs::read_to_string(&path).unwrap());
+    }
+}
@@ -0,0 +1,118 @@
+//! Configure how Rust types without a ROSMSG counterpart are encoded.
+//!
+//! By default, only data types supported by ROSMSG can be serialized and
+//! deserialized. A `Config` can enable encodings for additional Rust types,
+//! following idioms commonly used in ROS messages. Both sides of the
+//! communication need to use the same configuration.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde_rosmsg::config::{Config, OptionEncoding};
+//! let config = Config::new().option_encoding(OptionEncoding::Array);
+//!
+//! let data = config.to_vec(&Some(7u8)).unwrap();
+//! assert_eq!(data, b"\x05\0\0\0\x01\0\0\0\x07");
+//! assert_eq!(config.from_slice::<Option<u8>>(&data).unwrap(), Some(7));
+//!
+//! let data = config.to_vec(&None::<u8>).unwrap();
+//! assert_eq!(data, b"\x04\0\0\0\0\0\0\0");
+//! assert_eq!(config.from_slice::<Option<u8>>(&data).unwrap(), None);
+//! ```
+//!
+//! Enumerations with only unit variants can be mapped to integer constants,
+//! which is how ROS messages model enumerations:
+//!
+//! ```rust
+//! extern crate serde_rosmsg;
+//! #[macro_use]
+//! extern crate serde_derive;
+//! use serde_rosmsg::config::{Config, EnumEncoding};
+//!
+//! fn main() {
+//! #[derive(Debug,Serialize,Deserialize,PartialEq)]
+//! enum Status {
+//!     Ok,
+//!     Warn,
+//!     Error,
+//! }
+//!
+//! let config = Config::new().enum_encoding(EnumEncoding::U8);
+//! let data = config.to_vec(&Status::Error).unwrap();
+//! assert_eq!(data, b"\x01\0\0\0\x02");
+//! assert_eq!(config.from_slice::<Status>(&data).unwrap(), Status::Error);
+//! }
+//! ```
+
+/// Encoding used for `Option` values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OptionEncoding {
+    /// Options are rejected, since ROSMSG has no such type.
+    #[default]
+    Unsupported,
+    /// Options are encoded as variable size arrays with zero or one element.
+    ///
+    /// This matches bounded sequences with a maximum size of one, which are
+    /// commonly used for optional fields in ROS messages.
+    Array,
+}
+
+/// Encoding used for enumerations with only unit variants.
+///
+/// Variants are encoded as their index, in order of declaration, using the
+/// selected integer type. Serde does not expose explicit discriminants, so
+/// `enum Status { Ok = 0, Error = 5 }` encodes `Error` as `1`.
+///
+/// Enumerations with variants that contain data are never supported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EnumEncoding {
+    /// Enumerations are rejected, since ROSMSG has no such type.
+    #[default]
+    Unsupported,
+    /// Variants are encoded as a `uint8` constant.
+    U8,
+    /// Variants are encoded as an `int8` constant.
+    I8,
+    /// Variants are encoded as a `uint16` constant.
+    U16,
+    /// Variants are encoded as an `int32` constant.
+    I32,
+}
+
+/// Configuration shared by the ROSMSG serializer and deserializer.
+///
+/// The default configuration only accepts data types supported by ROSMSG.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    option_encoding: OptionEncoding,
+    enum_encoding: EnumEncoding,
+}
+
+impl Config {
+    /// Create the default configuration.
+    pub fn new() -> Self {
+        Config::default()
+    }
+
+    /// Set the encoding used for `Option` values.
+    pub fn option_encoding(mut self, encoding: OptionEncoding) -> Self {
+        self.option_encoding = encoding;
+        self
+    }
+
+    /// Get the encoding used for `Option` values.
+    pub fn get_option_encoding(&self) -> OptionEncoding {
+        self.option_encoding
+    }
+
+    /// Set the encoding used for enumerations with only unit variants.
+    pub fn enum_encoding(mut self, encoding: EnumEncoding) -> Self {
+        self.enum_encoding = encoding;
+        self
+    }
+
+    /// Get the encoding used for enumerations with only unit variants.
+    pub fn get_enum_encoding(&self) -> EnumEncoding {
+        self.enum_encoding
+    }
+}
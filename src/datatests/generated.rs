@@ -0,0 +1,206 @@
+// Generated by serde_rosmsg::codegen, do not edit.
+
+pub mod geometry_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `geometry_msgs/Point`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Point {
+        /// Forward
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Point {
+        const ROS_TYPE: &'static str = "geometry_msgs/Point";
+        const MD5SUM: &'static str = "4a842b65f413084dc2b10fb484ea7f17";
+        const DEFINITION: &'static str = "float64 x # Forward\nfloat64 y\nfloat64 z\n";
+    }
+
+    /// Message type `geometry_msgs/Pose`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Pose {
+        pub position: super::geometry_msgs::Point,
+        pub orientation: super::geometry_msgs::Quaternion,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Pose {
+        const ROS_TYPE: &'static str = "geometry_msgs/Pose";
+        const MD5SUM: &'static str = "e45d45a5a1ce597b249e23fb30fc871f";
+        const DEFINITION: &'static str = "Point position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\nfloat64 x # Forward\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/PoseArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PoseArray {
+        pub header: super::std_msgs::Header,
+        pub poses: ::std::vec::Vec<super::geometry_msgs::Pose>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PoseArray {
+        const ROS_TYPE: &'static str = "geometry_msgs/PoseArray";
+        const MD5SUM: &'static str = "916c28c5764443f268b296bb671b9d97";
+        const DEFINITION: &'static str = "Header header\nPose[] poses\n\n================================================================================\nMSG: std_msgs/Header\nuint32 seq\ntime stamp\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Pose\nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\nfloat64 x # Forward\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/PoseWithCovariance`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct PoseWithCovariance {
+        pub pose: super::geometry_msgs::Pose,
+        #[serde(with = "::serde_rosmsg::big_array")]
+        pub covariance: [f64; 36],
+    }
+
+    impl ::std::default::Default for PoseWithCovariance {
+        fn default() -> Self {
+            Self {
+                pose: ::std::default::Default::default(),
+                covariance: ::std::array::from_fn(|_| ::std::default::Default::default()),
+            }
+        }
+    }
+
+    impl ::serde_rosmsg::RosMessage for PoseWithCovariance {
+        const ROS_TYPE: &'static str = "geometry_msgs/PoseWithCovariance";
+        const MD5SUM: &'static str = "c23e848cf1b7533a8d7c259073a97e6f";
+        const DEFINITION: &'static str = "Pose pose\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Pose\nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\nfloat64 x # Forward\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/PoseWithCovarianceStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PoseWithCovarianceStamped {
+        pub header: super::std_msgs::Header,
+        pub pose: super::geometry_msgs::PoseWithCovariance,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PoseWithCovarianceStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/PoseWithCovarianceStamped";
+        const MD5SUM: &'static str = "953b798c0f514ff060a53a3498ce6246";
+        const DEFINITION: &'static str = "Header header\nPoseWithCovariance pose\n\n================================================================================\nMSG: std_msgs/Header\nuint32 seq\ntime stamp\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/PoseWithCovariance\nPose pose\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Pose\nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\nfloat64 x # Forward\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/Quaternion`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Quaternion {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+        pub w: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Quaternion {
+        const ROS_TYPE: &'static str = "geometry_msgs/Quaternion";
+        const MD5SUM: &'static str = "a779879fadf0160734f906b8c19c7004";
+        const DEFINITION: &'static str = "float64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+}
+
+pub mod my_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `my_msgs/Status`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Status {
+        pub level: u8,
+        pub r#type: [i8; 4],
+        pub timeout: ::serde_rosmsg::time::Duration,
+    }
+
+    impl Status {
+        /// All good
+        pub const OK: u8 = 0;
+        pub const LOW: i8 = -3;
+        pub const GAIN: f32 = 1.5;
+        pub const ON: bool = true;
+        pub const NAME: &'static str = "a \"b\" # c";
+    }
+
+    impl ::serde_rosmsg::RosMessage for Status {
+        const ROS_TYPE: &'static str = "my_msgs/Status";
+        const MD5SUM: &'static str = "4e37e62da2f718f253f514a739c35cf4";
+        const DEFINITION: &'static str = "uint8 OK=0 # All good\nint8 LOW=-3\nfloat32 GAIN=1.5\nbool ON=True\nstring NAME=a \"b\" # c\nuint8 level\nbyte[4] type\nduration timeout\n";
+    }
+
+    /// Message type `my_msgs/AddTwoIntsRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct AddTwoIntsRequest {
+        pub a: i64,
+        pub b: i64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for AddTwoIntsRequest {
+        const ROS_TYPE: &'static str = "my_msgs/AddTwoIntsRequest";
+        const MD5SUM: &'static str = "36d09b846be0b371c5f190354dd3153e";
+        const DEFINITION: &'static str = "int64 a\nint64 b\n";
+    }
+
+    /// Message type `my_msgs/AddTwoIntsResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct AddTwoIntsResponse {
+        pub sum: i64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for AddTwoIntsResponse {
+        const ROS_TYPE: &'static str = "my_msgs/AddTwoIntsResponse";
+        const MD5SUM: &'static str = "b88405221c77b1878a3cbbfff53428d7";
+        const DEFINITION: &'static str = "int64 sum\n";
+    }
+
+    /// Service type `my_msgs/AddTwoInts`, made of `AddTwoIntsRequest` and `AddTwoIntsResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct AddTwoInts;
+
+    impl AddTwoInts {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "my_msgs/AddTwoInts";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "6a2e34150c00229791cc89ff309fff21";
+    }
+}
+
+pub mod other_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `other_msgs/Other`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Other {
+        pub data: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Other {
+        const ROS_TYPE: &'static str = "other_msgs/Other";
+        const MD5SUM: &'static str = "992ce8a1687cec8c8bd883ec73ca41d1";
+        const DEFINITION: &'static str = "string data\n";
+    }
+}
+
+pub mod std_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `std_msgs/Header`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Header {
+        pub seq: u32,
+        pub stamp: ::serde_rosmsg::time::Time,
+        pub frame_id: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Header {
+        const ROS_TYPE: &'static str = "std_msgs/Header";
+        const MD5SUM: &'static str = "2176decaecbce78abc3b96ef049fabed";
+        const DEFINITION: &'static str = "uint32 seq\ntime stamp\nstring frame_id\n";
+    }
+
+    /// Message type `std_msgs/String`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct String {
+        pub data: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for String {
+        const ROS_TYPE: &'static str = "std_msgs/String";
+        const MD5SUM: &'static str = "992ce8a1687cec8c8bd883ec73ca41d1";
+        const DEFINITION: &'static str = "string data\n";
+    }
+}
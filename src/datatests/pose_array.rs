@@ -34,7 +34,7 @@ mod tests {
 
     #[derive(Debug,Deserialize,PartialEq)]
     struct Header {
-        id: u32,
+        seq: u32,
         time: Time,
         frame_id: String,
     }
@@ -62,7 +62,7 @@ mod tests {
         };
         assert_eq!(Structure {
                        header: Header {
-                           id: 1,
+                           seq: 1,
                            time: Time {
                                secs: 0,
                                nsecs: 0,
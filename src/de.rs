@@ -3,17 +3,28 @@
 //! Data types supported by ROSMSG are supported as well. This results in the
 //! lack of support for:
 //!
-//! * Enums of any type, including `Option`
+//! * Enums of any type, including `Option`, unless enabled through `Config`
+//! * Enums with variants that contain data
 //! * `char`, so use one character `String`s instead
 //! * Maps that can't be boiled down to `<String, String>`
 //!
 //! Any methods for blindly identifying structure are not supported, because
-//! the data does not contain any type information.
-
-use super::error::{Error, ErrorKind, Result, ResultExt};
-use byteorder::{LittleEndian, ReadBytesExt};
+//! the data does not contain any type information. Use
+//! `dynamic::SchemaDeserializer` for them, which takes the types from the
+//! message definition.
+
+use super::config::{Config, EnumEncoding, OptionEncoding};
+use super::error::{Error, ErrorKind, Result};
+use super::read::{self, IoRead, Reference, SliceRead};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt};
+#[cfg(feature = "async")]
+use futures_io::AsyncRead;
 use serde::de;
 use std::io;
+#[cfg(feature = "async")]
+use std::{cmp, future::Future, marker::PhantomData, pin::Pin};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
 
 /// A structure for deserializing ROSMSG into Rust values.
 ///
@@ -24,9 +35,10 @@ use std::io;
 pub struct Deserializer<R> {
     reader: R,
     length: u32,
+    config: Config,
 }
 
-impl<R> Deserializer<R>
+impl<R> Deserializer<IoRead<R>>
 where
     R: io::Read,
 {
@@ -54,8 +66,9 @@ where
     /// ```
     pub fn new(reader: R, expected_length: u32) -> Self {
         Deserializer {
-            reader: reader,
+            reader: IoRead::new(reader),
             length: expected_length,
+            config: Config::default(),
         }
     }
 
@@ -80,7 +93,70 @@ where
     /// # }
     /// ```
     pub fn into_inner(self) -> R {
-        self.reader
+        self.reader.into_inner()
+    }
+}
+
+impl<'de> Deserializer<SliceRead<'de>> {
+    /// Create a new ROSMSG deserializer that borrows from a byte slice.
+    ///
+    /// The value of `expected_length` tells the deserializer how long the data
+    /// that we want to read is.
+    ///
+    /// Strings and byte arrays are borrowed directly from `bytes` when the
+    /// deserialized type allows it, avoiding any copies.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate serde_rosmsg;
+    /// # use serde_rosmsg::de::Deserializer;
+    /// # extern crate serde;
+    /// # fn main() {
+    /// use serde::de::Deserialize;
+    ///
+    /// let data = b"\x0d\0\0\0Hello, World!\xAE";
+    /// let mut de = Deserializer::from_slice(data, data.len() as u32);
+    /// let text: &str = Deserialize::deserialize(&mut de).unwrap();
+    /// assert_eq!(text, "Hello, World!");
+    /// assert_eq!(u8::deserialize(&mut de).unwrap(), 0xAE);
+    /// # }
+    /// ```
+    pub fn from_slice(bytes: &'de [u8], expected_length: u32) -> Self {
+        Deserializer {
+            reader: SliceRead::new(bytes),
+            length: expected_length,
+            config: Config::default(),
+        }
+    }
+}
+
+impl<'de, R> Deserializer<R>
+where
+    R: read::Read<'de>,
+{
+    /// Set the configuration used for decoding types that ROSMSG lacks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate serde_rosmsg;
+    /// # use serde_rosmsg::de::Deserializer;
+    /// # use serde_rosmsg::config::{Config, OptionEncoding};
+    /// # extern crate serde;
+    /// # fn main() {
+    /// use serde::de::Deserialize;
+    ///
+    /// let config = Config::new().option_encoding(OptionEncoding::Array);
+    /// let data = [1, 0, 0, 0, 7, 0, 0, 0, 0];
+    /// let mut de = Deserializer::from_slice(&data, 9).with_config(config);
+    /// assert_eq!(Option::<u8>::deserialize(&mut de).unwrap(), Some(7));
+    /// assert_eq!(Option::<u8>::deserialize(&mut de).unwrap(), None);
+    /// # }
+    /// ```
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
     }
 
     /// Check if the deserializer is fully read.
@@ -111,6 +187,32 @@ where
         self.length == 0
     }
 
+    /// Discard the next `length` bytes of data without decoding them.
+    ///
+    /// This allows skipping values that are not needed, as long as their
+    /// encoded length is known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate serde_rosmsg;
+    /// # use serde_rosmsg::de::Deserializer;
+    /// # extern crate serde;
+    /// # fn main() {
+    /// use serde::de::Deserialize;
+    ///
+    /// let data = [2, 4, 8, 16];
+    /// let mut de = Deserializer::from_slice(&data, 4);
+    /// de.skip_bytes(2).unwrap();
+    /// assert_eq!(u16::deserialize(&mut de).unwrap(), 4104);
+    /// de.skip_bytes(1).unwrap_err(); // Failure to skip beyond the end
+    /// # }
+    /// ```
+    pub fn skip_bytes(&mut self, length: u32) -> Result<()> {
+        self.reserve_bytes(length)?;
+        self.reader.skip(length as usize)
+    }
+
     #[inline]
     fn reserve_bytes(&mut self, size: u32) -> Result<()> {
         if size > self.length {
@@ -120,51 +222,56 @@ where
         Ok(())
     }
 
+    #[inline]
+    fn read_fixed(&mut self, buffer: &mut [u8]) -> Result<()> {
+        self.reserve_bytes(buffer.len() as u32)?;
+        self.reader.read_exact(buffer)
+    }
+
     #[inline]
     fn pop_length(&mut self) -> Result<u32> {
-        self.reserve_bytes(4)?;
-        self.reader
-            .read_u32::<LittleEndian>()
-            .chain_err(|| ErrorKind::EndOfBuffer)
+        let mut buffer = [0; 4];
+        self.read_fixed(&mut buffer)?;
+        Ok(LittleEndian::read_u32(&buffer))
     }
 
     #[inline]
-    fn get_string(&mut self) -> Result<String> {
+    fn get_bytes(&mut self) -> Result<Reference<'de>> {
         let length = self.pop_length()?;
         self.reserve_bytes(length)?;
-        let mut buffer = vec![0; length as usize];
-        self.reader
-            .read_exact(&mut buffer)
-            .chain_err(|| ErrorKind::EndOfBuffer)?;
-        String::from_utf8(buffer).chain_err(|| ErrorKind::BadStringData)
+        self.reader.read_bytes(length as usize)
     }
 
-    fn get_bytes(&mut self) -> Result<Vec<u8>> {
-        let length = self.pop_length()?;
-        self.reserve_bytes(length)?;
-        let mut buffer = vec![0; length as usize];
-        self.reader
-            .read_exact(&mut buffer)
-            .chain_err(|| ErrorKind::EndOfBuffer)?;
-        Ok(buffer)
+    #[inline]
+    fn get_string(&mut self) -> Result<String> {
+        match self.get_bytes()? {
+            Reference::Borrowed(data) => into_str(data).map(String::from),
+            Reference::Copied(data) => {
+                String::from_utf8(data).map_err(|_| ErrorKind::BadStringData.into())
+            }
+        }
     }
 }
 
+#[inline]
+fn into_str(data: &[u8]) -> Result<&str> {
+    ::std::str::from_utf8(data).map_err(|_| ErrorKind::BadStringData.into())
+}
+
 macro_rules! impl_nums {
     ($ty:ty, $dser_method:ident, $visitor_method:ident, $reader_method:ident, $bytes:expr) => {
         #[inline]
         fn $dser_method<V>(self, visitor: V) -> Result<V::Value>
             where V: de::Visitor<'de>,
         {
-            self.reserve_bytes($bytes)?;
-            let value = self.reader.$reader_method::<LittleEndian>()
-                .chain_err(|| ErrorKind::EndOfBuffer)?;
-            visitor.$visitor_method(value)
+            let mut buffer = [0; $bytes];
+            self.read_fixed(&mut buffer)?;
+            visitor.$visitor_method(LittleEndian::$reader_method(&buffer))
         }
     }
 }
 
-impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
+impl<'de, R: read::Read<'de>> de::Deserializer<'de> for &mut Deserializer<R> {
     type Error = Error;
 
     #[inline]
@@ -192,13 +299,9 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     where
         V: de::Visitor<'de>,
     {
-        self.reserve_bytes(1)?;
-        let value = self
-            .reader
-            .read_u8()
-            .chain_err(|| ErrorKind::EndOfBuffer)
-            .map(|v| v != 0)?;
-        visitor.visit_bool(value)
+        let mut buffer = [0; 1];
+        self.read_fixed(&mut buffer)?;
+        visitor.visit_bool(buffer[0] != 0)
     }
 
     #[inline]
@@ -206,9 +309,9 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     where
         V: de::Visitor<'de>,
     {
-        self.reserve_bytes(1)?;
-        let value = self.reader.read_u8().chain_err(|| ErrorKind::EndOfBuffer)?;
-        visitor.visit_u8(value)
+        let mut buffer = [0; 1];
+        self.read_fixed(&mut buffer)?;
+        visitor.visit_u8(buffer[0])
     }
 
     #[inline]
@@ -216,9 +319,9 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     where
         V: de::Visitor<'de>,
     {
-        self.reserve_bytes(1)?;
-        let value = self.reader.read_i8().chain_err(|| ErrorKind::EndOfBuffer)?;
-        visitor.visit_i8(value)
+        let mut buffer = [0; 1];
+        self.read_fixed(&mut buffer)?;
+        visitor.visit_i8(buffer[0] as i8)
     }
 
     impl_nums!(u16, deserialize_u16, visit_u16, read_u16, 2);
@@ -243,7 +346,10 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     where
         V: de::Visitor<'de>,
     {
-        visitor.visit_str(&self.get_string()?)
+        match self.get_bytes()? {
+            Reference::Borrowed(data) => visitor.visit_borrowed_str(into_str(data)?),
+            Reference::Copied(data) => visitor.visit_str(into_str(&data)?),
+        }
     }
 
     #[inline]
@@ -259,7 +365,10 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     where
         V: de::Visitor<'de>,
     {
-        visitor.visit_byte_buf(self.get_bytes()?)
+        match self.get_bytes()? {
+            Reference::Borrowed(data) => visitor.visit_borrowed_bytes(data),
+            Reference::Copied(data) => visitor.visit_byte_buf(data),
+        }
     }
 
     #[inline]
@@ -267,15 +376,25 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     where
         V: de::Visitor<'de>,
     {
-        visitor.visit_byte_buf(self.get_bytes()?)
+        match self.get_bytes()? {
+            Reference::Borrowed(data) => visitor.visit_bytes(data),
+            Reference::Copied(data) => visitor.visit_byte_buf(data),
+        }
     }
 
     #[inline]
-    fn deserialize_option<V>(self, _visitor: V) -> Result<V::Value>
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
     where
         V: de::Visitor<'de>,
     {
-        bail!(ErrorKind::UnsupportedEnumType)
+        match self.config.get_option_encoding() {
+            OptionEncoding::Unsupported => bail!(ErrorKind::UnsupportedEnumType),
+            OptionEncoding::Array => match self.pop_length()? {
+                0 => visitor.visit_none(),
+                1 => visitor.visit_some(self),
+                len => bail!(ErrorKind::BadOptionLength(len)),
+            },
+        }
     }
 
     #[inline]
@@ -309,12 +428,12 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     {
         let len = self.pop_length()? as usize;
 
-        struct Access<'a, R: io::Read + 'a> {
+        struct Access<'a, R: 'a> {
             deserializer: &'a mut Deserializer<R>,
             len: usize,
         }
 
-        impl<'de, 'a, 'b: 'a, R: io::Read + 'b> de::SeqAccess<'de> for Access<'a, R> {
+        impl<'de, 'a, R: read::Read<'de> + 'a> de::SeqAccess<'de> for Access<'a, R> {
             type Error = Error;
 
             fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
@@ -336,7 +455,7 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
 
         visitor.visit_seq(Access {
             deserializer: self,
-            len: len,
+            len,
         })
     }
 
@@ -345,12 +464,12 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     where
         V: de::Visitor<'de>,
     {
-        struct Access<'a, R: io::Read + 'a> {
+        struct Access<'a, R: 'a> {
             deserializer: &'a mut Deserializer<R>,
             len: usize,
         }
 
-        impl<'de, 'a, 'b: 'a, R: io::Read + 'b> de::SeqAccess<'de> for Access<'a, R> {
+        impl<'de, 'a, R: read::Read<'de> + 'a> de::SeqAccess<'de> for Access<'a, R> {
             type Error = Error;
 
             fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
@@ -372,7 +491,7 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
 
         visitor.visit_seq(Access {
             deserializer: self,
-            len: len,
+            len,
         })
     }
 
@@ -394,13 +513,13 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     where
         V: de::Visitor<'de>,
     {
-        struct Access<'a, R: io::Read + 'a> {
+        struct Access<'a, R: 'a> {
             deserializer: &'a mut Deserializer<R>,
             key: Vec<u8>,
             value: Vec<u8>,
         }
 
-        impl<'a, R: io::Read + 'a> Access<'a, R> {
+        impl<'de, 'a, R: read::Read<'de> + 'a> Access<'a, R> {
             #[inline]
             fn pop_item(&mut self) -> Result<()> {
                 let data = self.deserializer.get_string()?;
@@ -426,7 +545,7 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
             }
         }
 
-        impl<'de, 'a, 'b: 'a, R: io::Read + 'b> de::MapAccess<'de> for Access<'a, R> {
+        impl<'de, 'a, R: read::Read<'de> + 'a> de::MapAccess<'de> for Access<'a, R> {
             type Error = Error;
 
             #[inline]
@@ -439,7 +558,8 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
                 } else {
                     self.pop_item()?;
                     let mut deserializer =
-                        Deserializer::new(io::Cursor::new(&self.key), self.key.len() as u32);
+                        Deserializer::new(io::Cursor::new(&self.key), self.key.len() as u32)
+                            .with_config(self.deserializer.config);
                     Ok(Some(seed.deserialize(&mut deserializer)?))
                 }
             }
@@ -450,8 +570,9 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
                 V: de::DeserializeSeed<'de>,
             {
                 let mut deserializer =
-                    Deserializer::new(io::Cursor::new(&self.value), self.value.len() as u32);
-                Ok(seed.deserialize(&mut deserializer)?)
+                    Deserializer::new(io::Cursor::new(&self.value), self.value.len() as u32)
+                        .with_config(self.deserializer.config);
+                seed.deserialize(&mut deserializer)
             }
         }
 
@@ -479,13 +600,85 @@ impl<'de, 'a, R: io::Read> de::Deserializer<'de> for &'a mut Deserializer<R> {
     fn deserialize_enum<V>(
         self,
         _name: &'static str,
-        _variants: &'static [&'static str],
-        _visitor: V,
+        variants: &'static [&'static str],
+        visitor: V,
     ) -> Result<V::Value>
     where
         V: de::Visitor<'de>,
     {
-        bail!(ErrorKind::UnsupportedEnumType)
+        use serde::de::Deserialize;
+
+        let value = match self.config.get_enum_encoding() {
+            EnumEncoding::Unsupported => bail!(ErrorKind::UnsupportedEnumType),
+            EnumEncoding::U8 => i64::from(u8::deserialize(&mut *self)?),
+            EnumEncoding::I8 => i64::from(i8::deserialize(&mut *self)?),
+            EnumEncoding::U16 => i64::from(u16::deserialize(&mut *self)?),
+            EnumEncoding::I32 => i64::from(i32::deserialize(&mut *self)?),
+        };
+        if value < 0 || value >= variants.len() as i64 {
+            bail!(ErrorKind::BadEnumValue(value));
+        }
+
+        struct Access {
+            index: u32,
+        }
+
+        impl<'de> de::EnumAccess<'de> for Access {
+            type Error = Error;
+            type Variant = Self;
+
+            #[inline]
+            fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self)>
+            where
+                V: de::DeserializeSeed<'de>,
+            {
+                use serde::de::IntoDeserializer;
+                let deserializer: de::value::U32Deserializer<Error> =
+                    self.index.into_deserializer();
+                Ok((seed.deserialize(deserializer)?, self))
+            }
+        }
+
+        impl<'de> de::VariantAccess<'de> for Access {
+            type Error = Error;
+
+            #[inline]
+            fn unit_variant(self) -> Result<()> {
+                Ok(())
+            }
+
+            #[inline]
+            fn newtype_variant_seed<T>(self, _seed: T) -> Result<T::Value>
+            where
+                T: de::DeserializeSeed<'de>,
+            {
+                bail!(ErrorKind::UnsupportedEnumType)
+            }
+
+            #[inline]
+            fn tuple_variant<V>(self, _len: usize, _visitor: V) -> Result<V::Value>
+            where
+                V: de::Visitor<'de>,
+            {
+                bail!(ErrorKind::UnsupportedEnumType)
+            }
+
+            #[inline]
+            fn struct_variant<V>(
+                self,
+                _fields: &'static [&'static str],
+                _visitor: V,
+            ) -> Result<V::Value>
+            where
+                V: de::Visitor<'de>,
+            {
+                bail!(ErrorKind::UnsupportedEnumType)
+            }
+        }
+
+        visitor.visit_enum(Access {
+            index: value as u32,
+        })
     }
 
     #[inline]
@@ -530,18 +723,12 @@ impl de::Error for Error {
 /// let value: (u16, u16) = from_reader(&mut cursor).unwrap();
 /// assert_eq!(value, (1026, 4104));
 /// ```
-pub fn from_reader<'de, R, T>(mut reader: R) -> Result<T>
+pub fn from_reader<'de, R, T>(reader: R) -> Result<T>
 where
     R: io::Read,
     T: de::Deserialize<'de>,
 {
-    let length = reader.read_u32::<LittleEndian>()?;
-    let mut deserializer = Deserializer::new(reader, length);
-    let value = T::deserialize(&mut deserializer)?;
-    if !deserializer.is_fully_read() {
-        bail!(ErrorKind::Underflow);
-    }
-    Ok(value)
+    Config::default().from_reader(reader)
 }
 
 /// Deserialize an instance of type `T` from bytes of ROSMSG data.
@@ -550,10 +737,17 @@ where
 /// structure expected by `T`. It can also fail if the structure contains
 /// unsupported elements.
 ///
+/// Strings and byte arrays can be borrowed from `bytes`, so `T` may contain
+/// `&str` and `&[u8]` fields.
+///
 /// # Examples
 ///
 /// ```rust
 /// # use serde_rosmsg::de::from_slice;
+/// let data = [17, 0, 0, 0, 13, 0, 0, 0, 72, 101, 108, 108, 111, 44, 32, 87, 111, 114, 108, 100, 33];
+/// let value: &str = from_slice(&data).unwrap();
+/// assert_eq!(value, "Hello, World!");
+///
 /// let value: String = from_slice(&[
 ///     17, 0, 0, 0,
 ///     13, 0, 0, 0,
@@ -563,11 +757,11 @@ where
 /// let value: (u16, u16) = from_slice(&[4, 0, 0, 0, 2, 4, 8, 16]).unwrap();
 /// assert_eq!(value, (1026, 4104));
 /// ```
-pub fn from_slice<'de, T>(bytes: &[u8]) -> Result<T>
+pub fn from_slice<'de, T>(bytes: &'de [u8]) -> Result<T>
 where
     T: de::Deserialize<'de>,
 {
-    from_reader(io::Cursor::new(bytes))
+    Config::default().from_slice(bytes)
 }
 
 /// Deserialize an instance of type `T` from a string of ROSMSG data.
@@ -586,11 +780,163 @@ where
 /// let value: (u16, u16) = from_str("\x04\0\0\0\x02\x04\x08\x10").unwrap();
 /// assert_eq!(value, (1026, 4104));
 /// ```
-pub fn from_str<'de, T>(value: &str) -> Result<T>
+pub fn from_str<'de, T>(value: &'de str) -> Result<T>
 where
     T: de::Deserialize<'de>,
 {
-    from_slice(value.as_bytes())
+    Config::default().from_str(value)
+}
+
+/// Deserialize an instance of type `T` from an asynchronous stream of ROSMSG
+/// data.
+///
+/// The returned future reads the size prefix and the data into a buffer,
+/// yielding whenever the stream has no data ready, and deserializes the value
+/// once all the data is read. It is available with the `async` feature
+/// enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate serde_rosmsg;
+/// # use futures::executor::block_on;
+/// # use futures::io::Cursor;
+/// # use serde_rosmsg::de::from_async_reader;
+/// # fn main() {
+/// let data = b"\x11\0\0\0\x0d\0\0\0Hello, World!\
+///              \x04\0\0\0\x02\x04\x08\x10";
+/// let mut cursor = Cursor::new(&data[..]);
+/// let value: String = block_on(from_async_reader(&mut cursor)).unwrap();
+/// assert_eq!(value, "Hello, World!");
+/// let value: (u16, u16) = block_on(from_async_reader(&mut cursor)).unwrap();
+/// assert_eq!(value, (1026, 4104));
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub fn from_async_reader<R, T>(reader: R) -> ReadMessage<R, T>
+where
+    R: AsyncRead + Unpin,
+    T: de::DeserializeOwned,
+{
+    Config::default().from_async_reader(reader)
+}
+
+/// Future returned by `from_async_reader`.
+#[cfg(feature = "async")]
+#[must_use = "futures do nothing unless polled"]
+pub struct ReadMessage<R, T> {
+    reader: R,
+    config: Config,
+    buffer: Vec<u8>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "async")]
+impl<R, T> Future for ReadMessage<R, T>
+where
+    R: AsyncRead + Unpin,
+    T: de::DeserializeOwned,
+{
+    type Output = Result<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<T>> {
+        let this = &mut *self;
+        let mut chunk = [0u8; 8192];
+        loop {
+            let total = if this.buffer.len() < 4 {
+                4
+            } else {
+                4 + LittleEndian::read_u32(&this.buffer) as usize
+            };
+            let missing = total - this.buffer.len();
+            if missing == 0 {
+                return Poll::Ready(this.config.from_slice(&this.buffer));
+            }
+            let chunk = &mut chunk[..cmp::min(missing, 8192)];
+            match Pin::new(&mut this.reader).poll_read(cx, chunk) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(0)) if this.buffer.len() < 4 => {
+                    let error = io::Error::from(io::ErrorKind::UnexpectedEof);
+                    return Poll::Ready(Err(error.into()));
+                }
+                Poll::Ready(Ok(0)) => return Poll::Ready(Err(ErrorKind::EndOfBuffer.into())),
+                Poll::Ready(Ok(len)) => this.buffer.extend_from_slice(&chunk[..len]),
+                Poll::Ready(Err(ref error)) if error.kind() == io::ErrorKind::Interrupted => {}
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error.into())),
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Deserialize an instance of type `T` from an IO stream of ROSMSG data
+    /// with this configuration.
+    ///
+    /// Behaves like `de::from_reader`.
+    pub fn from_reader<'de, R, T>(&self, mut reader: R) -> Result<T>
+    where
+        R: io::Read,
+        T: de::Deserialize<'de>,
+    {
+        let length = reader.read_u32::<LittleEndian>()?;
+        let mut deserializer = Deserializer::new(reader, length).with_config(*self);
+        let value = T::deserialize(&mut deserializer)?;
+        if !deserializer.is_fully_read() {
+            bail!(ErrorKind::Underflow);
+        }
+        Ok(value)
+    }
+
+    /// Deserialize an instance of type `T` from bytes of ROSMSG data with
+    /// this configuration.
+    ///
+    /// Behaves like `de::from_slice`.
+    pub fn from_slice<'de, T>(&self, bytes: &'de [u8]) -> Result<T>
+    where
+        T: de::Deserialize<'de>,
+    {
+        if bytes.len() < 4 {
+            bail!(ErrorKind::EndOfBuffer);
+        }
+        let (length, bytes) = bytes.split_at(4);
+        let mut deserializer =
+            Deserializer::from_slice(bytes, LittleEndian::read_u32(length)).with_config(*self);
+        let value = T::deserialize(&mut deserializer)?;
+        if !deserializer.is_fully_read() {
+            bail!(ErrorKind::Underflow);
+        }
+        Ok(value)
+    }
+
+    /// Deserialize an instance of type `T` from a string of ROSMSG data with
+    /// this configuration.
+    ///
+    /// Behaves like `de::from_str`.
+    pub fn from_str<'de, T>(&self, value: &'de str) -> Result<T>
+    where
+        T: de::Deserialize<'de>,
+    {
+        self.from_slice(value.as_bytes())
+    }
+
+    /// Deserialize an instance of type `T` from an asynchronous stream of
+    /// ROSMSG data with this configuration.
+    ///
+    /// Behaves like `de::from_async_reader`.
+    #[cfg(feature = "async")]
+    pub fn from_async_reader<R, T>(&self, reader: R) -> ReadMessage<R, T>
+    where
+        R: AsyncRead + Unpin,
+        T: de::DeserializeOwned,
+    {
+        ReadMessage {
+            reader,
+            config: *self,
+            buffer: Vec::new(),
+            phantom: PhantomData,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -691,6 +1037,154 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reads_borrowed_string() {
+        let data = vec![
+            17, 0, 0, 0, 13, 0, 0, 0, 72, 101, 108, 108, 111, 44, 32, 87, 111, 114, 108, 100, 33,
+        ];
+        let value = from_slice::<&str>(&data).unwrap();
+        assert_eq!("Hello, World!", value);
+        assert_eq!(&data[8] as *const u8, value.as_ptr());
+    }
+
+    #[test]
+    fn reads_borrowed_struct() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct TestBorrowed<'a> {
+            a: &'a str,
+            b: u8,
+            #[serde(borrow)]
+            c: &'a [u8],
+        }
+        let data = vec![16, 0, 0, 0, 3, 0, 0, 0, 65, 66, 67, 7, 4, 0, 0, 0, 1, 2, 3, 4];
+        assert_eq!(
+            TestBorrowed {
+                a: "ABC",
+                b: 7,
+                c: &[1, 2, 3, 4],
+            },
+            from_slice(&data).unwrap()
+        );
+    }
+
+    #[test]
+    fn fails_to_borrow_from_reader() {
+        let data = vec![7, 0, 0, 0, 3, 0, 0, 0, 65, 66, 67];
+        from_reader::<_, &str>(std::io::Cursor::new(&data)).unwrap_err();
+        assert_eq!(
+            "ABC",
+            from_reader::<_, String>(std::io::Cursor::new(&data)).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_bad_string_data() {
+        let data = vec![6, 0, 0, 0, 2, 0, 0, 0, 0xC3, 0x28];
+        let error = from_slice::<&str>(&data).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadStringData => {}
+            _ => panic!("Bad string data error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn rejects_option_by_default() {
+        let data = vec![4, 0, 0, 0, 0, 0, 0, 0];
+        let error = from_slice::<Option<u8>>(&data).unwrap_err();
+        match *error.kind() {
+            ErrorKind::UnsupportedEnumType => {}
+            _ => panic!("Unsupported enum type error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn reads_option_from_array() {
+        let config = Config::new().option_encoding(OptionEncoding::Array);
+        let data = vec![4, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(None, config.from_slice::<Option<u16>>(&data).unwrap());
+        let data = vec![6, 0, 0, 0, 1, 0, 0, 0, 0x34, 0xA2];
+        assert_eq!(Some(0xA234), config.from_slice::<Option<u16>>(&data).unwrap());
+        let data = vec![15, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 65, 66, 67, 0, 0, 0, 0];
+        assert_eq!(
+            (Some("ABC"), None),
+            config.from_slice::<(Option<&str>, Option<bool>)>(&data).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_bad_option_length() {
+        let config = Config::new().option_encoding(OptionEncoding::Array);
+        let data = vec![6, 0, 0, 0, 2, 0, 0, 0, 1, 2];
+        let error = config.from_slice::<Option<u8>>(&data).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadOptionLength(2) => {}
+            _ => panic!("Bad option length error expected, got: {:?}", error),
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum TestEnum {
+        A,
+        B,
+        C,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum TestDataEnum {
+        A(u8),
+    }
+
+    #[test]
+    fn rejects_enum_by_default() {
+        let data = vec![1, 0, 0, 0, 0];
+        let error = from_slice::<TestEnum>(&data).unwrap_err();
+        match *error.kind() {
+            ErrorKind::UnsupportedEnumType => {}
+            _ => panic!("Unsupported enum type error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn reads_enum_from_integer() {
+        let config = Config::new().enum_encoding(EnumEncoding::U8);
+        assert_eq!(TestEnum::C, config.from_slice(&[1, 0, 0, 0, 2]).unwrap());
+        let config = Config::new().enum_encoding(EnumEncoding::I8);
+        assert_eq!(TestEnum::B, config.from_slice(&[1, 0, 0, 0, 1]).unwrap());
+        let config = Config::new().enum_encoding(EnumEncoding::U16);
+        assert_eq!(TestEnum::C, config.from_slice(&[2, 0, 0, 0, 2, 0]).unwrap());
+        let config = Config::new().enum_encoding(EnumEncoding::I32);
+        assert_eq!(
+            (TestEnum::A, TestEnum::C),
+            config
+                .from_slice(&[8, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_unknown_enum_value() {
+        let config = Config::new().enum_encoding(EnumEncoding::U8);
+        let error = config.from_slice::<TestEnum>(&[1, 0, 0, 0, 3]).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadEnumValue(3) => {}
+            _ => panic!("Bad enum value error expected, got: {:?}", error),
+        }
+        let config = Config::new().enum_encoding(EnumEncoding::I8);
+        let error = config.from_slice::<TestEnum>(&[1, 0, 0, 0, 0xFF]).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadEnumValue(-1) => {}
+            _ => panic!("Bad enum value error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn rejects_enum_with_data() {
+        let config = Config::new().enum_encoding(EnumEncoding::U8);
+        config
+            .from_slice::<TestDataEnum>(&[2, 0, 0, 0, 0, 5])
+            .unwrap_err();
+    }
+
     #[test]
     fn reads_array() {
         let data = vec![8, 0, 0, 0, 7, 0, 1, 4, 33, 0, 57, 0];
@@ -883,4 +1377,67 @@ mod tests {
         let data = vec![12, 0, 0, 0, 5, 0, 0, 0, 7, 0, 1, 4, 33, 0, 57, 0];
         from_slice::<Vec<i16>>(&data).unwrap_err();
     }
+
+    /// Reader handing out one byte at a time, and nothing every other poll.
+    #[cfg(feature = "async")]
+    struct SlowReader<'a> {
+        data: &'a [u8],
+        ready: bool,
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a> AsyncRead for SlowReader<'a> {
+        fn poll_read(mut self: Pin<&mut Self>,
+                     cx: &mut Context,
+                     buf: &mut [u8])
+                     -> Poll<io::Result<usize>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            let len = cmp::min(cmp::min(buf.len(), self.data.len()), 1);
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Poll::Ready(Ok(len))
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn reads_async_streams() {
+        use futures::executor::block_on;
+        let data = [4, 0, 0, 0, 0x45, 0x23, 1, 0xCD, 3, 0, 0, 0, 1, 2, 3];
+        let mut reader = SlowReader {
+            data: &data,
+            ready: false,
+        };
+        let value: u32 = block_on(from_async_reader(&mut reader)).unwrap();
+        assert_eq!(0xCD012345, value);
+        let value: (u8, u8, u8) = block_on(from_async_reader(&mut reader)).unwrap();
+        assert_eq!((1, 2, 3), value);
+        assert!(reader.data.is_empty());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn reports_truncated_async_streams() {
+        use futures::executor::block_on;
+        let reader = SlowReader {
+            data: &[4, 0, 0],
+            ready: false,
+        };
+        match *block_on(from_async_reader::<_, u32>(reader)).unwrap_err().kind() {
+            ErrorKind::Io(ref error) if error.kind() == io::ErrorKind::UnexpectedEof => {}
+            ref error => panic!("Unexpected EOF error expected, got: {:?}", error),
+        }
+        let reader = SlowReader {
+            data: &[4, 0, 0, 0, 0x45, 0x23, 1],
+            ready: false,
+        };
+        match *block_on(from_async_reader::<_, u32>(reader)).unwrap_err().kind() {
+            ErrorKind::EndOfBuffer => {}
+            ref error => panic!("End of buffer error expected, got: {:?}", error),
+        }
+    }
 }
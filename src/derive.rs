@@ -0,0 +1,370 @@
+//! Support code for `#[derive(RosMessage)]`.
+//!
+//! The derived MD5 sums and full definitions depend on the constants of the
+//! nested message types, so they can't be calculated by the procedural macro
+//! itself. Instead, the macro emits calls to these functions, which are
+//! evaluated by the compiler.
+
+/// Line that separates message texts in full definitions, with the new lines
+/// around it.
+const SEPARATOR: &[u8] = b"\n================================================================================\n";
+
+/// Prefix of the line that introduces a nested message type.
+const MESSAGE_PREFIX: &[u8] = b"MSG: ";
+
+const HEX: &[u8] = b"0123456789abcdef";
+
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22,
+    5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20,
+    4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23,
+    6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+const SINES: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee,
+    0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be,
+    0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa,
+    0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed,
+    0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c,
+    0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05,
+    0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039,
+    0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1,
+    0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// MD5 hasher that can be used in constant expressions.
+struct Md5 {
+    state: [u32; 4],
+    block: [u8; 64],
+    length: u64,
+}
+
+impl Md5 {
+    const fn new() -> Self {
+        Md5 {
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476],
+            block: [0; 64],
+            length: 0,
+        }
+    }
+
+    // `is_multiple_of` would need a newer compiler.
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    const fn push(&mut self, byte: u8) {
+        self.block[(self.length % 64) as usize] = byte;
+        self.length += 1;
+        if self.length % 64 == 0 {
+            self.process();
+        }
+    }
+
+    const fn update(&mut self, data: &[u8]) {
+        let mut i = 0;
+        while i < data.len() {
+            self.push(data[i]);
+            i += 1;
+        }
+    }
+
+    const fn process(&mut self) {
+        let mut words = [0u32; 16];
+        let mut i = 0;
+        while i < 16 {
+            words[i] = u32::from_le_bytes([self.block[i * 4],
+                                           self.block[i * 4 + 1],
+                                           self.block[i * 4 + 2],
+                                           self.block[i * 4 + 3]]);
+            i += 1;
+        }
+        let [mut a, mut b, mut c, mut d] = self.state;
+        let mut i = 0;
+        while i < 64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let f = f.wrapping_add(a).wrapping_add(SINES[i]).wrapping_add(words[g]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(f.rotate_left(SHIFTS[i]));
+            i += 1;
+        }
+        self.state[0] = self.state[0].wrapping_add(a);
+        self.state[1] = self.state[1].wrapping_add(b);
+        self.state[2] = self.state[2].wrapping_add(c);
+        self.state[3] = self.state[3].wrapping_add(d);
+    }
+
+    const fn finish(mut self) -> [u8; 32] {
+        let bits = self.length.wrapping_mul(8).to_le_bytes();
+        self.push(0x80);
+        while self.length % 64 != 56 {
+            self.push(0);
+        }
+        let mut i = 0;
+        while i < 8 {
+            self.push(bits[i]);
+            i += 1;
+        }
+        let mut hex = [0u8; 32];
+        let mut i = 0;
+        while i < 16 {
+            let byte = self.state[i / 4].to_le_bytes()[i % 4];
+            hex[i * 2] = HEX[(byte >> 4) as usize];
+            hex[i * 2 + 1] = HEX[(byte & 0xf) as usize];
+            i += 1;
+        }
+        hex
+    }
+}
+
+/// Calculate the MD5 sum of the concatenated pieces, as hexadecimal digits.
+pub const fn md5(pieces: &[&str]) -> [u8; 32] {
+    let mut hasher = Md5::new();
+    let mut i = 0;
+    while i < pieces.len() {
+        hasher.update(pieces[i].as_bytes());
+        i += 1;
+    }
+    hasher.finish()
+}
+
+/// Interpret bytes produced by the functions of this module as a string.
+pub const fn as_str(bytes: &'static [u8]) -> &'static str {
+    match ::std::str::from_utf8(bytes) {
+        Ok(value) => value,
+        Err(_) => panic!("Generated text is not valid UTF-8"),
+    }
+}
+
+const fn starts_with(data: &[u8], offset: usize, prefix: &[u8]) -> bool {
+    if data.len() < offset + prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if data[offset + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn equal(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len() && starts_with(left, 0, right)
+}
+
+/// Find the first separator at or after the offset, or the end of the data.
+const fn find_separator(data: &[u8], mut offset: usize) -> usize {
+    while offset < data.len() && !starts_with(data, offset, SEPARATOR) {
+        offset += 1;
+    }
+    offset
+}
+
+/// A message text inside of a full definition.
+struct Section<'a> {
+    name: &'a [u8],
+    text: &'a [u8],
+}
+
+/// Cursor over the message texts of a full definition.
+struct Sections<'a> {
+    definition: &'a [u8],
+    name: &'a [u8],
+    offset: usize,
+    finished: bool,
+}
+
+impl<'a> Sections<'a> {
+    const fn new(name: &'a str, definition: &'a str) -> Self {
+        Sections {
+            definition: definition.as_bytes(),
+            name: name.as_bytes(),
+            offset: 0,
+            finished: false,
+        }
+    }
+
+    const fn next(&mut self) -> Option<Section<'a>> {
+        let data = self.definition;
+        if self.finished {
+            return None;
+        }
+        let mut start = self.offset;
+        let name = if start == 0 {
+            self.name
+        } else {
+            start += MESSAGE_PREFIX.len();
+            if start > data.len() {
+                start = data.len();
+            }
+            let (_, rest) = data.split_at(start);
+            let mut length = 0;
+            while length < rest.len() && rest[length] != b'\n' {
+                length += 1;
+            }
+            let (name, _) = rest.split_at(length);
+            start += length;
+            if start < data.len() {
+                start += 1;
+            }
+            name
+        };
+        let end = find_separator(data, start);
+        let (head, _) = data.split_at(end);
+        let (_, text) = head.split_at(start);
+        self.offset = end + SEPARATOR.len();
+        self.finished = end == data.len();
+        Some(Section { name, text })
+    }
+}
+
+/// Call `$body` for every nested message type that goes into the full
+/// definition, skipping types that were already listed.
+macro_rules! for_each_section {
+    ($nested:expr, $section:ident => $body:block) => {
+        let mut i = 0;
+        while i < $nested.len() {
+            let mut sections = Sections::new($nested[i].0, $nested[i].1);
+            while let Some($section) = sections.next() {
+                if !is_listed_before($nested, i, sections.offset, $section.name) {
+                    $body
+                }
+            }
+            i += 1;
+        }
+    }
+}
+
+/// Check if the type was listed before the section that ends at `offset` in
+/// the `index`-th nested definition.
+const fn is_listed_before(nested: &[(&str, &str)], index: usize, offset: usize, name: &[u8]) -> bool {
+    let mut i = 0;
+    while i <= index {
+        let mut sections = Sections::new(nested[i].0, nested[i].1);
+        while let Some(section) = sections.next() {
+            if i == index && sections.offset >= offset {
+                break;
+            }
+            if equal(section.name, name) {
+                return true;
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Calculate the length of the full definition built by `definition`.
+pub const fn definition_len(text: &[&str], nested: &[(&str, &str)]) -> usize {
+    let mut length = 0;
+    let mut i = 0;
+    while i < text.len() {
+        length += text[i].len();
+        i += 1;
+    }
+    for_each_section!(nested, section => {
+        length += SEPARATOR.len() + MESSAGE_PREFIX.len() + section.name.len() + 1 +
+                  section.text.len();
+    });
+    length
+}
+
+/// Build the full definition of a message.
+///
+/// The text of the message is made of the `text` pieces, and `nested`
+/// contains the names and full definitions of the nested message types, in
+/// order of field declaration.
+pub const fn definition<const N: usize>(text: &[&str], nested: &[(&str, &str)]) -> [u8; N] {
+    let mut output = [0u8; N];
+    if write_definition(&mut output, text, nested) != N {
+        panic!("Generated definition has an unexpected length");
+    }
+    output
+}
+
+const fn write_definition(output: &mut [u8], text: &[&str], nested: &[(&str, &str)]) -> usize {
+    let mut length = 0;
+    let mut i = 0;
+    while i < text.len() {
+        length = copy(output, length, text[i].as_bytes());
+        i += 1;
+    }
+    for_each_section!(nested, section => {
+        length = copy(output, length, SEPARATOR);
+        length = copy(output, length, MESSAGE_PREFIX);
+        length = copy(output, length, section.name);
+        length = copy(output, length, b"\n");
+        length = copy(output, length, section.text);
+    });
+    length
+}
+
+const fn copy(output: &mut [u8], offset: usize, data: &[u8]) -> usize {
+    let mut i = 0;
+    while i < data.len() {
+        output[offset + i] = data[i];
+        i += 1;
+    }
+    offset + data.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use msg::MessageSpec;
+    use std::collections::HashMap;
+
+    #[test]
+    fn md5_matches_reference_implementation() {
+        let data = (0..300).map(|v| (b'a' + (v % 26) as u8) as char).collect::<String>();
+        for length in 0..data.len() {
+            let expected = format!("{:x}", ::md5::compute(&data[..length]));
+            assert_eq!(expected.as_bytes(), &md5(&[&data[..length]])[..]);
+        }
+    }
+
+    #[test]
+    fn md5_concatenates_pieces() {
+        const SUM: [u8; 32] = md5(&["string", " ", "data"]);
+        assert_eq!("992ce8a1687cec8c8bd883ec73ca41d1", as_str(&SUM));
+    }
+
+    fn full_definition(registry: &HashMap<::msg::MessageName, MessageSpec>, name: &str) -> String {
+        registry[&::msg::MessageName::parse(name).unwrap()].full_definition(registry).unwrap()
+    }
+
+    #[test]
+    fn definition_combines_nested_definitions() {
+        let mut registry = HashMap::new();
+        for &(name, text) in &[("my_msgs/Point", "float64 x\n"),
+                               ("my_msgs/Color", "uint8 r\n"),
+                               ("my_msgs/Line", "Point a\nPoint b\nColor c\n"),
+                               ("my_msgs/Shape", "Line[] lines\nPoint center\nColor fill\n")] {
+            let spec = MessageSpec::parse(name, text).unwrap();
+            registry.insert(spec.name.clone(), spec);
+        }
+        let line = full_definition(&registry, "my_msgs/Line");
+        let nested = [("my_msgs/Line", line.as_str()),
+                      ("my_msgs/Point", "float64 x\n"),
+                      ("my_msgs/Color", "uint8 r\n")];
+        let text = ["Line[] lines\nPoint center\nColor fill\n"];
+        let mut output = vec![0u8; definition_len(&text, &nested)];
+        assert_eq!(output.len(), write_definition(&mut output, &text, &nested));
+        assert_eq!(full_definition(&registry, "my_msgs/Shape"),
+                   String::from_utf8(output).unwrap());
+    }
+}
@@ -0,0 +1,775 @@
+//! Decoding of dynamically typed messages, driven by a schema.
+
+use super::super::de::Deserializer as RosDeserializer;
+use super::super::error::{Error, ErrorKind, Result};
+use super::super::msg::{ArrayLength, BaseType, Field, FieldType, MessageSpec, PrimitiveType,
+                        Resolver};
+use super::super::read::{IoRead, Read, SliceRead};
+use super::super::time::{Duration, Time};
+use super::value::{DynamicMessage, Value};
+use super::{Expected, Schema};
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess,
+                SeqAccess, Visitor};
+use std::convert::TryFrom;
+use std::{cmp, fmt, io, slice};
+
+/// Seed that decodes a whole message of the given type.
+pub struct MessageSeed<'a> {
+    pub schema: &'a Schema,
+    pub spec: &'a MessageSpec,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for MessageSeed<'a> {
+    type Value = DynamicMessage;
+
+    fn deserialize<D>(self, deserializer: D) -> ::std::result::Result<DynamicMessage, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_tuple(self.spec.fields.len(), self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for MessageSeed<'a> {
+    type Value = DynamicMessage;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a {} message", self.spec.name)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> ::std::result::Result<DynamicMessage, A::Error>
+        where A: SeqAccess<'de>
+    {
+        let mut message = DynamicMessage::new();
+        for (index, field) in self.spec.fields.iter().enumerate() {
+            let seed = FieldSeed {
+                schema: self.schema,
+                field_type: &field.field_type,
+            };
+            match seq.next_element_seed(seed)? {
+                Some(value) => message.insert(field.name.as_str(), value),
+                None => return Err(de::Error::invalid_length(index, &self)),
+            };
+        }
+        Ok(message)
+    }
+}
+
+/// Consume a value of the given type without decoding it.
+pub fn skip<'de, R: Read<'de>>(deserializer: &mut RosDeserializer<R>,
+                               schema: &Schema,
+                               expected: Expected)
+                               -> Result<()> {
+    TypedDeserializer {
+        deserializer,
+        schema,
+        expected,
+    }
+    .skip()
+}
+
+/// Seed that decodes a field, which can be an array.
+pub struct FieldSeed<'a> {
+    pub schema: &'a Schema,
+    pub field_type: &'a FieldType,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for FieldSeed<'a> {
+    type Value = Value;
+
+    fn deserialize<D>(self, deserializer: D) -> ::std::result::Result<Value, D::Error>
+        where D: Deserializer<'de>
+    {
+        let visitor = ArrayVisitor {
+            schema: self.schema,
+            base: &self.field_type.base,
+        };
+        match self.field_type.array {
+            None => ItemSeed {
+                    schema: self.schema,
+                    base: &self.field_type.base,
+                }
+                .deserialize(deserializer),
+            Some(ArrayLength::Variable) => deserializer.deserialize_seq(visitor),
+            Some(ArrayLength::Fixed(len)) => deserializer.deserialize_tuple(len, visitor),
+        }
+    }
+}
+
+struct ArrayVisitor<'a> {
+    schema: &'a Schema,
+    base: &'a BaseType,
+}
+
+impl<'de, 'a> Visitor<'de> for ArrayVisitor<'a> {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of {}", self.base)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> ::std::result::Result<Value, A::Error> {
+        // The length comes from the data, so it is not trusted with allocating memory.
+        let mut items = Vec::with_capacity(cmp::min(seq.size_hint().unwrap_or(0), 4096));
+        let seed = || {
+            ItemSeed {
+                schema: self.schema,
+                base: self.base,
+            }
+        };
+        while let Some(item) = seq.next_element_seed(seed())? {
+            items.push(item);
+        }
+        Ok(Value::Array(items))
+    }
+}
+
+/// Seed that decodes a single item, which is never an array.
+pub struct ItemSeed<'a> {
+    pub schema: &'a Schema,
+    pub base: &'a BaseType,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for ItemSeed<'a> {
+    type Value = Value;
+
+    fn deserialize<D>(self, deserializer: D) -> ::std::result::Result<Value, D::Error>
+        where D: Deserializer<'de>
+    {
+        let primitive = match *self.base {
+            BaseType::Primitive(primitive) => primitive,
+            BaseType::Message(ref name) => {
+                let spec = match self.schema.resolve(name) {
+                    Some(spec) => spec,
+                    None => {
+                        return Err(de::Error::custom(format_args!("missing message type {}",
+                                                                  name)))
+                    }
+                };
+                let seed = MessageSeed {
+                    schema: self.schema,
+                    spec,
+                };
+                return seed.deserialize(deserializer).map(Value::Message);
+            }
+        };
+        Ok(match primitive {
+            PrimitiveType::Bool => Value::Bool(bool::deserialize(deserializer)?),
+            PrimitiveType::Int8 | PrimitiveType::Byte => Value::I8(i8::deserialize(deserializer)?),
+            PrimitiveType::UInt8 | PrimitiveType::Char => Value::U8(u8::deserialize(deserializer)?),
+            PrimitiveType::Int16 => Value::I16(i16::deserialize(deserializer)?),
+            PrimitiveType::UInt16 => Value::U16(u16::deserialize(deserializer)?),
+            PrimitiveType::Int32 => Value::I32(i32::deserialize(deserializer)?),
+            PrimitiveType::UInt32 => Value::U32(u32::deserialize(deserializer)?),
+            PrimitiveType::Int64 => Value::I64(i64::deserialize(deserializer)?),
+            PrimitiveType::UInt64 => Value::U64(u64::deserialize(deserializer)?),
+            PrimitiveType::Float32 => Value::F32(f32::deserialize(deserializer)?),
+            PrimitiveType::Float64 => Value::F64(f64::deserialize(deserializer)?),
+            PrimitiveType::String => Value::String(String::deserialize(deserializer)?),
+            PrimitiveType::Time => Value::Time(Time::deserialize(deserializer)?),
+            PrimitiveType::Duration => Value::Duration(Duration::deserialize(deserializer)?),
+        })
+    }
+}
+
+/// A structure for deserializing ROSMSG data into Rust values, following
+/// the types given by a schema.
+///
+/// Unlike the plain `Deserializer`, it knows the type of every value it
+/// reads, so it supports `deserialize_any`. Messages are presented as maps
+/// from field names to values, arrays as sequences, and `time` and
+/// `duration` values as maps with `sec` and `nsec` entries. The type
+/// expected by the schema always takes precedence over the hints given by
+/// the deserialized type, which allows reading fields into wider types.
+///
+/// Values that are ignored, like fields missing from the deserialized type,
+/// are skipped without decoding them. Values of types with a fixed size,
+/// and arrays of them, are skipped in a single step.
+///
+/// Like the plain `Deserializer`, the structure does not read the length
+/// prefix of the message.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate serde;
+/// # #[macro_use]
+/// # extern crate serde_derive;
+/// # extern crate serde_rosmsg;
+/// # use serde_rosmsg::dynamic::{Schema, SchemaDeserializer};
+/// # fn main() {
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Point {
+///     // Fields are matched by name, and can have wider types
+///     y: f64,
+///     x: f64,
+/// }
+///
+/// let schema = Schema::parse("my_msgs/Point", "float32 x\nfloat32 y\nfloat32 z\n").unwrap();
+/// let data = b"\0\0\x80\x3f\0\0\0\x40\0\0\x40\x40";
+/// let mut de = SchemaDeserializer::from_slice(&schema, data, data.len() as u32);
+/// assert_eq!(Point::deserialize(&mut de).unwrap(), Point { x: 1.0, y: 2.0 });
+/// assert!(de.is_fully_read());
+/// # }
+/// ```
+///
+/// Messages can also be converted into other self-describing formats:
+///
+/// ```rust
+/// # extern crate serde_json;
+/// # extern crate serde_rosmsg;
+/// # extern crate serde_transcode;
+/// # use serde_rosmsg::dynamic::{Schema, SchemaDeserializer};
+/// # fn main() {
+/// let schema = Schema::parse("my_msgs/Sample", "time t\nint8[2] a\nstring s\n").unwrap();
+/// let data = b"\x01\0\0\0\x02\0\0\0\xfe\x07\x02\0\0\0hi";
+/// let mut de = SchemaDeserializer::from_slice(&schema, data, data.len() as u32);
+/// let mut json = Vec::new();
+/// serde_transcode::transcode(&mut de, &mut serde_json::Serializer::new(&mut json)).unwrap();
+/// assert_eq!(String::from_utf8(json).unwrap(),
+///            r#"{"t":{"sec":1,"nsec":2},"a":[-2,7],"s":"hi"}"#);
+///
+/// let mut de = SchemaDeserializer::from_slice(&schema, data, data.len() as u32);
+/// let value: serde_json::Value = serde::Deserialize::deserialize(&mut de).unwrap();
+/// assert_eq!(value["a"][0], -2);
+/// # }
+/// ```
+pub struct SchemaDeserializer<'a, R> {
+    deserializer: RosDeserializer<R>,
+    schema: &'a Schema,
+}
+
+impl<'a, R: io::Read> SchemaDeserializer<'a, IoRead<R>> {
+    /// Create a deserializer of messages described by the schema, reading
+    /// `expected_length` bytes from an IO stream.
+    pub fn new(schema: &'a Schema, reader: R, expected_length: u32) -> Self {
+        SchemaDeserializer {
+            deserializer: RosDeserializer::new(reader, expected_length),
+            schema,
+        }
+    }
+}
+
+impl<'a, 'de> SchemaDeserializer<'a, SliceRead<'de>> {
+    /// Create a deserializer of messages described by the schema, borrowing
+    /// `expected_length` bytes from a byte slice.
+    pub fn from_slice(schema: &'a Schema, bytes: &'de [u8], expected_length: u32) -> Self {
+        SchemaDeserializer {
+            deserializer: RosDeserializer::from_slice(bytes, expected_length),
+            schema,
+        }
+    }
+}
+
+impl<'a, 'de, R: Read<'de>> SchemaDeserializer<'a, R> {
+    /// Check if the deserializer is fully read.
+    pub fn is_fully_read(&self) -> bool {
+        self.deserializer.is_fully_read()
+    }
+
+    fn typed(&mut self) -> TypedDeserializer<'a, '_, R> {
+        TypedDeserializer {
+            deserializer: &mut self.deserializer,
+            schema: self.schema,
+            expected: Expected::Message(self.schema.root()),
+        }
+    }
+}
+
+impl<'de, 'a, 'b, R: Read<'de>> Deserializer<'de> for &'b mut SchemaDeserializer<'a, R> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.typed().deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.typed().deserialize_option(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+        where V: Visitor<'de>
+    {
+        self.typed().deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.typed().deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        self.typed().deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(self,
+                                   name: &'static str,
+                                   len: usize,
+                                   visitor: V)
+                                   -> Result<V::Value>
+        where V: Visitor<'de>
+    {
+        self.typed().deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.typed().deserialize_ignored_any(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct map struct enum identifier
+    }
+}
+
+/// Deserializer of a single value of a known type.
+struct TypedDeserializer<'a, 'b, R: 'b> {
+    deserializer: &'b mut RosDeserializer<R>,
+    schema: &'a Schema,
+    expected: Expected<'a>,
+}
+
+impl<'de, 'a, 'b, R: Read<'de>> TypedDeserializer<'a, 'b, R> {
+    fn nested(&mut self, expected: Expected<'a>) -> TypedDeserializer<'a, '_, R> {
+        TypedDeserializer {
+            deserializer: &mut *self.deserializer,
+            schema: self.schema,
+            expected,
+        }
+    }
+
+    /// Consume the encoded value without decoding it, skipping whole
+    /// blocks of data whenever their size is known up front.
+    fn skip(mut self) -> Result<()> {
+        if let Some(size) = self.expected.fixed_size(self.schema)? {
+            return self.skip_bytes(size);
+        }
+        match self.expected.resolve(self.schema)? {
+            Expected::Message(spec) => {
+                for field in &spec.fields {
+                    self.nested(Expected::Field(&field.field_type)).skip()?;
+                }
+                Ok(())
+            }
+            Expected::Field(field_type) => {
+                let len = match field_type.array {
+                    Some(ArrayLength::Fixed(len)) => len,
+                    _ => u32::deserialize(&mut *self.deserializer)? as usize,
+                };
+                let item = Expected::Item(&field_type.base);
+                if let Some(size) = item.fixed_size(self.schema)? {
+                    return self.skip_bytes(size.checked_mul(len).ok_or(ErrorKind::Overflow)?);
+                }
+                for _ in 0..len {
+                    self.nested(item).skip()?;
+                }
+                Ok(())
+            }
+            Expected::Primitive(_) => {
+                let len = u32::deserialize(&mut *self.deserializer)?;
+                self.deserializer.skip_bytes(len)
+            }
+            Expected::Item(_) => unreachable!("Items are always resolved"),
+        }
+    }
+
+    fn skip_bytes(&mut self, size: usize) -> Result<()> {
+        match u32::try_from(size) {
+            Ok(size) => self.deserializer.skip_bytes(size),
+            Err(_) => bail!(ErrorKind::Overflow),
+        }
+    }
+
+    fn visit_fields<V>(mut self, spec: &'a MessageSpec, visitor: V) -> Result<V::Value>
+        where V: Visitor<'de>
+    {
+        visitor.visit_seq(FieldAccess {
+            deserializer: self.nested(Expected::Message(spec)),
+            fields: spec.fields.iter(),
+        })
+    }
+}
+
+impl<'de, 'a, 'b, R: Read<'de>> Deserializer<'de> for TypedDeserializer<'a, 'b, R> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        let (base, len) = match self.expected.resolve(self.schema)? {
+            Expected::Message(spec) => {
+                return visitor.visit_map(MessageAccess {
+                    deserializer: self.nested(Expected::Message(spec)),
+                    fields: spec.fields.iter(),
+                    value: None,
+                })
+            }
+            Expected::Primitive(primitive) => {
+                let deserializer = self.deserializer;
+                return match primitive {
+                    PrimitiveType::Bool => deserializer.deserialize_bool(visitor),
+                    PrimitiveType::Int8 | PrimitiveType::Byte => {
+                        deserializer.deserialize_i8(visitor)
+                    }
+                    PrimitiveType::UInt8 | PrimitiveType::Char => {
+                        deserializer.deserialize_u8(visitor)
+                    }
+                    PrimitiveType::Int16 => deserializer.deserialize_i16(visitor),
+                    PrimitiveType::UInt16 => deserializer.deserialize_u16(visitor),
+                    PrimitiveType::Int32 => deserializer.deserialize_i32(visitor),
+                    PrimitiveType::UInt32 => deserializer.deserialize_u32(visitor),
+                    PrimitiveType::Int64 => deserializer.deserialize_i64(visitor),
+                    PrimitiveType::UInt64 => deserializer.deserialize_u64(visitor),
+                    PrimitiveType::Float32 => deserializer.deserialize_f32(visitor),
+                    PrimitiveType::Float64 => deserializer.deserialize_f64(visitor),
+                    PrimitiveType::String => deserializer.deserialize_str(visitor),
+                    PrimitiveType::Time => visitor.visit_map(TimeAccess::new(deserializer, false)),
+                    PrimitiveType::Duration => {
+                        visitor.visit_map(TimeAccess::new(deserializer, true))
+                    }
+                };
+            }
+            Expected::Field(field_type) => {
+                match field_type.array {
+                    Some(ArrayLength::Fixed(len)) => (&field_type.base, len),
+                    _ => (&field_type.base, u32::deserialize(&mut *self.deserializer)? as usize),
+                }
+            }
+            Expected::Item(_) => unreachable!("Items are always resolved"),
+        };
+        visitor.visit_seq(ArrayAccess {
+            deserializer: self.nested(Expected::Item(base)),
+            len,
+        })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+        where V: Visitor<'de>
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.expected.resolve(self.schema)? {
+            Expected::Message(spec) => self.visit_fields(spec, visitor),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(self,
+                                   _name: &'static str,
+                                   _len: usize,
+                                   visitor: V)
+                                   -> Result<V::Value>
+        where V: Visitor<'de>
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.skip()?;
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct map struct enum identifier
+    }
+}
+
+/// Access to the fields of a message by name.
+struct MessageAccess<'a, 'b, R: 'b> {
+    deserializer: TypedDeserializer<'a, 'b, R>,
+    fields: slice::Iter<'a, Field>,
+    value: Option<&'a FieldType>,
+}
+
+impl<'de, 'a, 'b, R: Read<'de>> MapAccess<'de> for MessageAccess<'a, 'b, R> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.fields.next() {
+            Some(field) => {
+                self.value = Some(&field.field_type);
+                seed.deserialize(field.name.as_str().into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let field_type = self.value.take().expect("Values are read after their keys");
+        seed.deserialize(self.deserializer.nested(Expected::Field(field_type)))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+/// Access to the fields of a message by position.
+struct FieldAccess<'a, 'b, R: 'b> {
+    deserializer: TypedDeserializer<'a, 'b, R>,
+    fields: slice::Iter<'a, Field>,
+}
+
+impl<'de, 'a, 'b, R: Read<'de>> SeqAccess<'de> for FieldAccess<'a, 'b, R> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        match self.fields.next() {
+            Some(field) => {
+                seed.deserialize(self.deserializer.nested(Expected::Field(&field.field_type)))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+/// Access to the items of an array.
+struct ArrayAccess<'a, 'b, R: 'b> {
+    deserializer: TypedDeserializer<'a, 'b, R>,
+    len: usize,
+}
+
+impl<'de, 'a, 'b, R: Read<'de>> SeqAccess<'de> for ArrayAccess<'a, 'b, R> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        let expected = self.deserializer.expected;
+        seed.deserialize(self.deserializer.nested(expected)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+/// Access to the seconds and nanoseconds of `time` and `duration` values.
+struct TimeAccess<'b, R: 'b> {
+    deserializer: &'b mut RosDeserializer<R>,
+    signed: bool,
+    fields: slice::Iter<'static, &'static str>,
+}
+
+impl<'b, R> TimeAccess<'b, R> {
+    fn new(deserializer: &'b mut RosDeserializer<R>, signed: bool) -> Self {
+        TimeAccess {
+            deserializer,
+            signed,
+            fields: TIME_FIELDS.iter(),
+        }
+    }
+}
+
+static TIME_FIELDS: &[&str] = &["sec", "nsec"];
+
+impl<'de, 'b, R: Read<'de>> MapAccess<'de> for TimeAccess<'b, R> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.fields.next() {
+            Some(field) => seed.deserialize(field.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        if self.signed {
+            seed.deserialize(i32::deserialize(&mut *self.deserializer)?.into_deserializer())
+        } else {
+            seed.deserialize(u32::deserialize(&mut *self.deserializer)?.into_deserializer())
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use from_slice as typed_from_slice;
+    use serde::de::IgnoredAny;
+    use std::collections::{BTreeMap, HashMap};
+
+    fn pose_array() -> (Schema, &'static [u8]) {
+        let header: HashMap<String, String> =
+            typed_from_slice(include_bytes!("../datatests/pose_array_res.bin")).unwrap();
+        let schema = Schema::from_header(&header).unwrap();
+        (schema, &include_bytes!("../datatests/pose_array_msg.bin")[4..])
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Stamp {
+        nsec: u64,
+        sec: u64,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Header {
+        frame_id: String,
+        stamp: Stamp,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Point {
+        z: f64,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Pose {
+        position: Point,
+    }
+
+    #[test]
+    fn deserializes_structs_by_field_name() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct PoseArray {
+            poses: Vec<Pose>,
+            header: Header,
+        }
+
+        let (schema, data) = pose_array();
+        let mut de = SchemaDeserializer::from_slice(&schema, data, data.len() as u32);
+        let message = PoseArray::deserialize(&mut de).unwrap();
+        assert!(de.is_fully_read());
+        assert_eq!(5, message.poses.len());
+        assert_eq!(Pose { position: Point { z: 3.0 } }, message.poses[4]);
+        assert_eq!(Header {
+                       frame_id: "ABC".into(),
+                       stamp: Stamp { sec: 0, nsec: 0 },
+                   },
+                   message.header);
+    }
+
+    #[test]
+    fn deserializes_flattened_structs() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Stamped {
+            header: Header,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct PoseArray {
+            #[serde(flatten)]
+            stamped: Stamped,
+            #[serde(flatten)]
+            rest: BTreeMap<String, Vec<Pose>>,
+        }
+
+        let (schema, data) = pose_array();
+        let mut de = SchemaDeserializer::from_slice(&schema, data, data.len() as u32);
+        let message = PoseArray::deserialize(&mut de).unwrap();
+        assert_eq!("ABC", message.stamped.header.frame_id);
+        assert_eq!(vec!["poses"], message.rest.keys().collect::<Vec<_>>());
+        assert_eq!(5, message.rest["poses"].len());
+    }
+
+    #[test]
+    fn deserializes_tuples_by_position() {
+        let schema = Schema::parse("my_msgs/Pair", "uint16 a\nstring b\n").unwrap();
+        let data = b"\x07\0\x01\0\0\0x";
+        let mut de = SchemaDeserializer::from_slice(&schema, data, 7);
+        assert_eq!((7u32, String::from("x")), <(u32, String)>::deserialize(&mut de).unwrap());
+    }
+
+    #[test]
+    fn skips_ignored_values() {
+        let (schema, data) = pose_array();
+        let mut de = SchemaDeserializer::new(&schema, io::Cursor::new(data), data.len() as u32);
+        IgnoredAny::deserialize(&mut de).unwrap();
+        assert!(de.is_fully_read());
+    }
+
+    #[test]
+    fn skips_ignored_fields() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Stamped {
+            stamp: Time,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct PointCloud {
+            header: Stamped,
+            is_dense: bool,
+        }
+
+        let definition = format!("Header header\nuint32 width\nPointField[] fields\n\
+                                  uint8[] data\nstring[2] names\nbool is_dense\n\
+                                  {0}\nMSG: std_msgs/Header\n\
+                                  uint32 seq\ntime stamp\nstring frame_id\n\
+                                  {0}\nMSG: my_msgs/PointField\n\
+                                  string name\nuint32 offset\n",
+                                 "=".repeat(80));
+        let schema = Schema::parse("my_msgs/PointCloud", &definition).unwrap();
+        let mut data = b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x03\0\0\0map\x09\0\0\0".to_vec();
+        data.extend_from_slice(b"\x02\0\0\0\x01\0\0\0x\0\0\0\0\x01\0\0\0y\x04\0\0\0");
+        data.extend_from_slice(b"\x00\x10\0\0");
+        data.extend(vec![7; 4096]);
+        data.extend_from_slice(b"\x01\0\0\0a\x02\0\0\0bc\x01");
+        let expected = PointCloud {
+            header: Stamped { stamp: Time::new(2, 3) },
+            is_dense: true,
+        };
+
+        let mut de = SchemaDeserializer::new(&schema, io::Cursor::new(&data), data.len() as u32);
+        assert_eq!(expected, PointCloud::deserialize(&mut de).unwrap());
+        assert!(de.is_fully_read());
+
+        let mut de = SchemaDeserializer::from_slice(&schema, &data, data.len() as u32);
+        assert_eq!(expected, PointCloud::deserialize(&mut de).unwrap());
+        assert!(de.is_fully_read());
+
+        let mut de = SchemaDeserializer::from_slice(&schema, &data[..100], 100);
+        match *PointCloud::deserialize(&mut de).unwrap_err().kind() {
+            ErrorKind::Overflow => {}
+            ref error => panic!("Overflow error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn rejects_huge_fixed_sizes() {
+        let nested = format!("Big[] a\n{}\nMSG: my_msgs/Big\nfloat64[576460752303423488] x\n",
+                             "=".repeat(80));
+        let cases = [("float64[4611686018427387904] a\n", &b"\0\0\0\0"[..]),
+                     ("float64[1152921504606846976] a\nfloat64[1152921504606846976] b\n",
+                      &b"\0\0\0\0"[..]),
+                     (&nested, &b"\x04\0\0\0"[..])];
+        for &(definition, data) in &cases {
+            let schema = Schema::parse("my_msgs/Huge", definition).unwrap();
+            let mut de = SchemaDeserializer::from_slice(&schema, data, 4);
+            match *IgnoredAny::deserialize(&mut de).unwrap_err().kind() {
+                ErrorKind::Overflow => {}
+                ref error => panic!("Overflow error expected, got: {:?}", error),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_bad_data() {
+        let schema = Schema::parse("my_msgs/Pair", "uint16 a\nuint16 b\n").unwrap();
+        let mut de = SchemaDeserializer::from_slice(&schema, b"\x01\0\x02", 3);
+        let error = BTreeMap::<String, u16>::deserialize(&mut de).unwrap_err();
+        match *error.kind() {
+            ErrorKind::Overflow => {}
+            _ => panic!("Overflow error expected, got: {:?}", error),
+        }
+    }
+}
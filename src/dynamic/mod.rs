@@ -0,0 +1,364 @@
+//! Decode messages without knowing their types at compile time.
+//!
+//! ROSMSG data carries no type information, so decoding it requires the
+//! definition of the message type. Nodes send the full definition in the
+//! `message_definition` field of connection headers, which a `Schema` can be
+//! built from. Messages are then decoded into a `DynamicMessage`, which holds
+//! a `Value` for each field, in the order of declaration.
+//!
+//! Going the other way, messages can be built out of a `DynamicMessage` or
+//! any other value keyed by field names, and encoded with `to_vec`.
+//!
+//! When only a few values of a large message are needed, `field_from_slice`
+//! decodes a single value addressed by a `FieldPath`, skipping over the rest.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde_rosmsg::dynamic::{self, Schema, Value};
+//! # use serde_rosmsg::time::Time;
+//! let definition = format!("Header header\nPoint[] points\n\n{}\n\
+//!                           MSG: std_msgs/Header\n\
+//!                           uint32 seq\ntime stamp\nstring frame_id\n\n{}\n\
+//!                           MSG: my_msgs/Point\n\
+//!                           float32 x\nfloat32 y\n",
+//!                          "=".repeat(80),
+//!                          "=".repeat(80));
+//! let schema = Schema::parse("my_msgs/Path", &definition).unwrap();
+//!
+//! let data = b"\x27\0\0\0\
+//!              \x07\0\0\0\x01\0\0\0\x02\0\0\0\x03\0\0\0map\
+//!              \x02\0\0\0\0\0\x80\x3f\0\0\0\x40\0\0\x40\x40\0\0\x80\x40";
+//! let message = dynamic::from_slice(&schema, data).unwrap();
+//! assert_eq!(message["header"]["seq"], Value::U32(7));
+//! assert_eq!(message["header"]["stamp"], Value::Time(Time::new(1, 2)));
+//! assert_eq!(message["header"]["frame_id"].as_str(), Some("map"));
+//! assert_eq!(message["points"][1]["y"].as_f64(), Some(4.0));
+//! ```
+
+use super::de::Deserializer;
+use super::error::{ErrorKind, Result};
+use super::msg::{definition, ArrayLength, BaseType, FieldType, MessageName, MessageSpec,
+                 PrimitiveType, Resolver};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt};
+use serde::de::DeserializeSeed;
+use std::collections::HashMap;
+use std::{fmt, io};
+
+pub use self::de::SchemaDeserializer;
+pub use self::path::{field_from_slice, FieldPath};
+pub use self::ser::{to_message, to_vec, to_writer};
+pub use self::value::{DynamicMessage, Value};
+
+mod de;
+mod path;
+mod ser;
+mod value;
+
+/// Definitions of a message type and all the message types nested in it.
+#[derive(Clone, Debug)]
+pub struct Schema {
+    root: MessageName,
+    messages: HashMap<MessageName, MessageSpec>,
+}
+
+impl Schema {
+    /// Create a schema of a message type, using the resolver to look up the
+    /// nested message types.
+    pub fn new<R>(spec: MessageSpec, resolver: &R) -> Result<Self>
+        where R: Resolver + ?Sized
+    {
+        let mut messages = spec.all_dependencies(resolver)?
+            .into_iter()
+            .map(|nested| (nested.name.clone(), nested.clone()))
+            .collect::<HashMap<_, _>>();
+        let root = spec.name.clone();
+        messages.insert(root.clone(), spec);
+        Ok(Schema { root, messages })
+    }
+
+    /// Create a schema from the name and the full definition of a message
+    /// type, in the format used by connection headers.
+    pub fn parse(name: &str, definition: &str) -> Result<Self> {
+        let mut messages = definition::parse(name, definition)?;
+        let root = MessageName::parse(name)?;
+        let spec = messages.remove(&root).expect("Root message type is always parsed");
+        Self::new(spec, &messages)
+    }
+
+    /// Create a schema from the `type` and `message_definition` fields of a
+    /// connection header.
+    pub fn from_header(header: &HashMap<String, String>) -> Result<Self> {
+        let field = |name: &str| match header.get(name) {
+            Some(value) => Ok(value),
+            None => Err(ErrorKind::MissingHeaderField(name.into())),
+        };
+        Self::parse(field("type")?, field("message_definition")?)
+    }
+
+    /// Definition of the message type described by the schema.
+    pub fn root(&self) -> &MessageSpec {
+        &self.messages[&self.root]
+    }
+}
+
+impl Resolver for Schema {
+    fn resolve(&self, name: &MessageName) -> Option<&MessageSpec> {
+        self.messages.get(name)
+    }
+}
+
+/// Type of the value at the current position of the data.
+#[derive(Clone, Copy)]
+enum Expected<'a> {
+    Message(&'a MessageSpec),
+    Field(&'a FieldType),
+    Item(&'a BaseType),
+    Primitive(PrimitiveType),
+}
+
+impl<'a> Expected<'a> {
+    /// Resolve single items into either messages or primitives.
+    fn resolve(self, schema: &'a Schema) -> Result<Expected<'a>> {
+        let base = match self {
+            Expected::Field(field_type) if field_type.array.is_none() => &field_type.base,
+            Expected::Item(base) => base,
+            expected => return Ok(expected),
+        };
+        match *base {
+            BaseType::Primitive(primitive) => Ok(Expected::Primitive(primitive)),
+            BaseType::Message(ref name) => {
+                match schema.resolve(name) {
+                    Some(spec) => Ok(Expected::Message(spec)),
+                    None => bail!(ErrorKind::MissingMessageType(name.to_string())),
+                }
+            }
+        }
+    }
+
+    /// Encoded size of values of the type, if it is the same for all of them.
+    fn fixed_size(self, schema: &'a Schema) -> Result<Option<usize>> {
+        Ok(match self.resolve(schema)? {
+            Expected::Message(spec) => {
+                let mut size = 0;
+                for field in &spec.fields {
+                    match Expected::Field(&field.field_type).fixed_size(schema)? {
+                        Some(field_size) => {
+                            size = field_size.checked_add(size).ok_or(ErrorKind::Overflow)?
+                        }
+                        None => return Ok(None),
+                    }
+                }
+                Some(size)
+            }
+            Expected::Field(field_type) => {
+                match field_type.array {
+                    Some(ArrayLength::Fixed(len)) => {
+                        match Expected::Item(&field_type.base).fixed_size(schema)? {
+                            Some(size) => Some(size.checked_mul(len).ok_or(ErrorKind::Overflow)?),
+                            None => None,
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            Expected::Primitive(primitive) => {
+                match primitive {
+                    PrimitiveType::Bool | PrimitiveType::Int8 | PrimitiveType::UInt8 |
+                    PrimitiveType::Byte | PrimitiveType::Char => Some(1),
+                    PrimitiveType::Int16 | PrimitiveType::UInt16 => Some(2),
+                    PrimitiveType::Int32 | PrimitiveType::UInt32 | PrimitiveType::Float32 => {
+                        Some(4)
+                    }
+                    PrimitiveType::Int64 | PrimitiveType::UInt64 | PrimitiveType::Float64 |
+                    PrimitiveType::Time | PrimitiveType::Duration => Some(8),
+                    PrimitiveType::String => None,
+                }
+            }
+            Expected::Item(_) => unreachable!("Items are always resolved"),
+        })
+    }
+}
+
+impl<'a> fmt::Display for Expected<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Expected::Message(spec) => spec.name.fmt(f),
+            Expected::Field(field_type) => field_type.fmt(f),
+            Expected::Item(base) => base.fmt(f),
+            Expected::Primitive(primitive) => primitive.fmt(f),
+        }
+    }
+}
+
+/// Decode a message described by the schema from an IO stream of ROSMSG
+/// data.
+pub fn from_reader<R: io::Read>(schema: &Schema, mut reader: R) -> Result<DynamicMessage> {
+    let length = reader.read_u32::<LittleEndian>()?;
+    let mut deserializer = Deserializer::new(reader, length);
+    decode(schema, &mut deserializer)
+}
+
+/// Decode a message described by the schema from bytes of ROSMSG data.
+pub fn from_slice(schema: &Schema, bytes: &[u8]) -> Result<DynamicMessage> {
+    if bytes.len() < 4 {
+        bail!(ErrorKind::EndOfBuffer);
+    }
+    let (length, bytes) = bytes.split_at(4);
+    let mut deserializer = Deserializer::from_slice(bytes, LittleEndian::read_u32(length));
+    decode(schema, &mut deserializer)
+}
+
+fn decode<'de, R>(schema: &Schema, deserializer: &mut Deserializer<R>) -> Result<DynamicMessage>
+    where R: super::read::Read<'de>
+{
+    let seed = de::MessageSeed {
+        schema,
+        spec: schema.root(),
+    };
+    let message = seed.deserialize(&mut *deserializer)?;
+    if !deserializer.is_fully_read() {
+        bail!(ErrorKind::Underflow);
+    }
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use from_slice as typed_from_slice;
+    use time::Time;
+
+    fn header(bytes: &[u8]) -> HashMap<String, String> {
+        typed_from_slice(bytes).unwrap()
+    }
+
+    fn check_pose(pose: &Value) {
+        for (index, axis) in ["x", "y", "z"].iter().enumerate() {
+            assert_eq!(Value::F64(index as f64 + 1.0), pose["position"][*axis]);
+        }
+        for (index, axis) in ["x", "y", "z", "w"].iter().enumerate() {
+            assert_eq!(Value::F64(index as f64 + 4.0), pose["orientation"][*axis]);
+        }
+    }
+
+    #[test]
+    fn decodes_pose() {
+        let schema = Schema::from_header(&header(include_bytes!("../datatests/pose_req.bin")))
+            .unwrap();
+        assert_eq!("geometry_msgs/Pose", schema.root().name.to_string());
+        let message = from_slice(&schema, include_bytes!("../datatests/pose_msg.bin")).unwrap();
+        check_pose(&Value::Message(message));
+    }
+
+    #[test]
+    fn decodes_pose_array() {
+        let schema =
+            Schema::from_header(&header(include_bytes!("../datatests/pose_array_res.bin")))
+                .unwrap();
+        let data = include_bytes!("../datatests/pose_array_msg.bin");
+        let message = from_reader(&schema, io::Cursor::new(&data[..])).unwrap();
+        let names = message.iter().map(|field| field.0.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["header", "poses"], names);
+        assert_eq!(Value::U32(1), message["header"]["seq"]);
+        assert_eq!(Value::Time(Time::new(0, 0)), message["header"]["stamp"]);
+        assert_eq!(Value::String("ABC".into()), message["header"]["frame_id"]);
+        let poses = message["poses"].as_array().unwrap();
+        assert_eq!(5, poses.len());
+        for pose in poses {
+            check_pose(pose);
+        }
+    }
+
+    #[test]
+    fn decodes_fixed_arrays() {
+        let schema = Schema::from_header(
+                &header(include_bytes!("../datatests/pose_with_covariance_req.bin")))
+            .unwrap();
+        let data = include_bytes!("../datatests/pose_with_covariance_msg.bin");
+        let message = from_slice(&schema, data).unwrap();
+        check_pose(&message["pose"]);
+        let covariance = message["covariance"].as_array().unwrap();
+        assert_eq!(36, covariance.len());
+        assert_eq!(Value::F64(43.0), covariance[35]);
+    }
+
+    #[test]
+    fn decodes_all_primitives() {
+        let schema = Schema::parse("my_msgs/All",
+                                   "bool a\nint8 b\nuint8 c\nint16 d\nuint16 e\nint32 f\n\
+                                    uint32 g\nint64 h\nuint64 i\nfloat32 j\nfloat64 k\n\
+                                    string l\ntime m\nduration n\nbyte o\nchar p\n\
+                                    uint8[] q\nint32 CONSTANT=3\n")
+            .unwrap();
+        let mut data = vec![1, 0xfe, 0xfe, 0xfe, 0xff, 0xfe, 0xff];
+        data.extend_from_slice(&[0xfe, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff]);
+        data.extend_from_slice(&[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        data.extend_from_slice(&[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        data.extend_from_slice(&[0, 0, 0xc0, 0x3f, 0, 0, 0, 0, 0, 0, 0xf8, 0xbf]);
+        data.extend_from_slice(b"\x02\0\0\0hi\x01\0\0\0\x02\0\0\0\xff\xff\xff\xff\0\0\0\0");
+        data.extend_from_slice(b"\xff\x41\x02\0\0\0\x05\x06");
+        let mut bytes = vec![data.len() as u8, 0, 0, 0];
+        bytes.extend(data);
+        let message = from_slice(&schema, &bytes).unwrap();
+        let values = message.iter().map(|field| field.1.clone()).collect::<Vec<_>>();
+        assert_eq!(vec![Value::Bool(true),
+                        Value::I8(-2),
+                        Value::U8(254),
+                        Value::I16(-2),
+                        Value::U16(65534),
+                        Value::I32(-2),
+                        Value::U32(4294967294),
+                        Value::I64(-2),
+                        Value::U64(18446744073709551614),
+                        Value::F32(1.5),
+                        Value::F64(-1.5),
+                        Value::String("hi".into()),
+                        Value::Time(Time::new(1, 2)),
+                        Value::Duration(::time::Duration::new(-1, 0)),
+                        Value::I8(-1),
+                        Value::U8(0x41),
+                        Value::Array(vec![Value::U8(5), Value::U8(6)])],
+                   values);
+    }
+
+    #[test]
+    fn rejects_bad_data() {
+        let schema = Schema::parse("my_msgs/Pair", "uint16 a\nuint16 b\n").unwrap();
+        let error = from_slice(&schema, b"\x03\0\0\0\x01\0\x02").unwrap_err();
+        match *error.kind() {
+            ErrorKind::Overflow => {}
+            _ => panic!("Overflow error expected, got: {:?}", error),
+        }
+        let error = from_slice(&schema, b"\x05\0\0\0\x01\0\x02\0\x03").unwrap_err();
+        match *error.kind() {
+            ErrorKind::Underflow => {}
+            _ => panic!("Underflow error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn rejects_huge_array_lengths() {
+        let schema = Schema::parse("my_msgs/Samples", "float64[] a
+").unwrap();
+        let error = from_slice(&schema, b"\x08\0\0\0\xff\xff\xff\x7f\0\0\0\0").unwrap_err();
+        match *error.kind() {
+            ErrorKind::Overflow => {}
+            _ => panic!("Overflow error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn rejects_incomplete_schemas() {
+        let error = Schema::parse("my_msgs/Path", "Point[] points\n").unwrap_err();
+        match *error.kind() {
+            ErrorKind::MissingMessageType(ref name) if name == "my_msgs/Point" => {}
+            _ => panic!("Missing message type error expected, got: {:?}", error),
+        }
+        let error = Schema::from_header(&HashMap::new()).unwrap_err();
+        match *error.kind() {
+            ErrorKind::MissingHeaderField(ref name) if name == "type" => {}
+            _ => panic!("Missing header field error expected, got: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! Decoding of single values out of messages, addressed by field paths.
+
+use super::super::de::Deserializer;
+use super::super::error::{Error, ErrorKind, Result};
+use super::super::msg::{ArrayLength, BaseType, PrimitiveType};
+use super::super::read::SliceRead;
+use super::de::{self, FieldSeed, ItemSeed, MessageSeed};
+use super::value::Value;
+use super::{Expected, Schema};
+use byteorder::{ByteOrder, LittleEndian};
+use serde::de::{Deserialize, DeserializeSeed};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// Path to a value nested inside of a message, like `poses[3].position.x`.
+///
+/// Paths consist of field names separated by dots, with items of arrays
+/// picked by their index in square brackets. The parts of `time` and
+/// `duration` values are addressed as `sec` and `nsec`, or `secs` and
+/// `nsecs`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_rosmsg::dynamic::FieldPath;
+/// let path: FieldPath = "poses[3].position.x".parse().unwrap();
+/// assert_eq!(path.to_string(), "poses[3].position.x");
+/// assert!(FieldPath::parse("poses[three]").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Segment {
+    Field(String),
+    Index(usize),
+}
+
+impl FieldPath {
+    /// Parse a field path.
+    pub fn parse(path: &str) -> Result<Self> {
+        let bad_path = || Error::from(ErrorKind::BadFieldPath(path.into()));
+        let mut segments = Vec::new();
+        for part in path.split('.') {
+            let (name, mut indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+            if name.is_empty() ||
+               !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(bad_path());
+            }
+            segments.push(Segment::Field(name.into()));
+            while !indices.is_empty() {
+                let end = match indices.find(']') {
+                    Some(end) if indices.starts_with('[') => end,
+                    _ => return Err(bad_path()),
+                };
+                let index = &indices[1..end];
+                if index.is_empty() || !index.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(bad_path());
+                }
+                segments.push(Segment::Index(index.parse().map_err(|_| bad_path())?));
+                indices = &indices[end + 1..];
+            }
+        }
+        Ok(FieldPath { segments })
+    }
+}
+
+impl FromStr for FieldPath {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self> {
+        Self::parse(path)
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (position, segment) in self.segments.iter().enumerate() {
+            if position > 0 {
+                if let Segment::Field(_) = *segment {
+                    f.write_str(".")?;
+                }
+            }
+            segment.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Segment::Field(ref name) => f.write_str(name),
+            Segment::Index(index) => write!(f, "[{}]", index),
+        }
+    }
+}
+
+/// Decode a single value out of bytes of ROSMSG data holding a message
+/// described by the schema.
+///
+/// The data in front of the value is skipped over, using the length prefixes
+/// of strings and variable size arrays to find the offset of the value, and
+/// only the value itself is decoded.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_rosmsg::dynamic::{self, Schema, Value};
+/// let definition = format!("string frame_id\nPoint[] points\n\n{}\n\
+///                           MSG: my_msgs/Point\nfloat32 x\nfloat32 y\n",
+///                          "=".repeat(80));
+/// let schema = Schema::parse("my_msgs/Path", &definition).unwrap();
+///
+/// let data = b"\x1b\0\0\0\x03\0\0\0map\
+///              \x02\0\0\0\0\0\x80\x3f\0\0\0\x40\0\0\x40\x40\0\0\x80\x40";
+/// let path = "points[1].y".parse().unwrap();
+/// assert_eq!(dynamic::field_from_slice(&schema, &path, data).unwrap(), Value::F32(4.0));
+/// ```
+pub fn field_from_slice(schema: &Schema, path: &FieldPath, bytes: &[u8]) -> Result<Value> {
+    if bytes.len() < 4 {
+        bail!(ErrorKind::EndOfBuffer);
+    }
+    let (length, bytes) = bytes.split_at(4);
+    let mut deserializer = Deserializer::from_slice(bytes, LittleEndian::read_u32(length));
+    let mut expected = Expected::Message(schema.root());
+    for segment in &path.segments {
+        expected = seek(&mut deserializer, schema, expected, segment)?;
+    }
+    match expected {
+        Expected::Message(spec) => {
+            MessageSeed { schema, spec }.deserialize(&mut deserializer).map(Value::Message)
+        }
+        Expected::Field(field_type) => {
+            FieldSeed { schema, field_type }.deserialize(&mut deserializer)
+        }
+        Expected::Item(base) => ItemSeed { schema, base }.deserialize(&mut deserializer),
+        Expected::Primitive(primitive) => {
+            let base = BaseType::Primitive(primitive);
+            ItemSeed {
+                    schema,
+                    base: &base,
+                }
+                .deserialize(&mut deserializer)
+        }
+    }
+}
+
+/// Move the deserializer to the start of the value addressed by the segment,
+/// returning the type of that value.
+fn seek<'a, 'de>(deserializer: &mut Deserializer<SliceRead<'de>>,
+                 schema: &'a Schema,
+                 expected: Expected<'a>,
+                 segment: &Segment)
+                 -> Result<Expected<'a>> {
+    let expected = expected.resolve(schema)?;
+    match (expected, segment) {
+        (Expected::Message(spec), Segment::Field(name)) => {
+            if let Some(position) = spec.fields.iter().position(|field| field.name == *name) {
+                for field in &spec.fields[..position] {
+                    de::skip(deserializer, schema, Expected::Field(&field.field_type))?;
+                }
+                return Ok(Expected::Field(&spec.fields[position].field_type));
+            }
+        }
+        (Expected::Primitive(primitive), Segment::Field(name)) => {
+            let part = match primitive {
+                PrimitiveType::Time => PrimitiveType::UInt32,
+                PrimitiveType::Duration => PrimitiveType::Int32,
+                _ => bail!(ErrorKind::UnknownField(expected.to_string(), name.clone())),
+            };
+            match name.as_str() {
+                "sec" | "secs" => return Ok(Expected::Primitive(part)),
+                "nsec" | "nsecs" => {
+                    deserializer.skip_bytes(4)?;
+                    return Ok(Expected::Primitive(part));
+                }
+                _ => {}
+            }
+        }
+        (Expected::Field(field_type), &Segment::Index(index)) => {
+            let len = match field_type.array {
+                Some(ArrayLength::Fixed(len)) => len,
+                _ => u32::deserialize(&mut *deserializer)? as usize,
+            };
+            if index >= len {
+                bail!(ErrorKind::IndexOutOfBounds(index, len));
+            }
+            let item = Expected::Item(&field_type.base);
+            match item.fixed_size(schema)? {
+                Some(size) => {
+                    let size = size.checked_mul(index).ok_or(ErrorKind::Overflow)?;
+                    let size = u32::try_from(size).map_err(|_| ErrorKind::Overflow)?;
+                    deserializer.skip_bytes(size)?;
+                }
+                None => {
+                    for _ in 0..index {
+                        de::skip(deserializer, schema, item)?;
+                    }
+                }
+            }
+            return Ok(item);
+        }
+        _ => {}
+    }
+    bail!(ErrorKind::UnknownField(expected.to_string(), segment.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::from_slice;
+    use from_slice as typed_from_slice;
+    use std::collections::HashMap;
+    use time::Time;
+
+    fn pose_array() -> Schema {
+        let header: HashMap<String, String> =
+            typed_from_slice(include_bytes!("../datatests/pose_array_res.bin")).unwrap();
+        Schema::from_header(&header).unwrap()
+    }
+
+    fn field(schema: &Schema, path: &str, data: &[u8]) -> Result<Value> {
+        field_from_slice(schema, &path.parse()?, data)
+    }
+
+    #[test]
+    fn parses_paths() {
+        let path = FieldPath::parse("a.b_2[3][14].c").unwrap();
+        assert_eq!(vec![Segment::Field("a".into()),
+                        Segment::Field("b_2".into()),
+                        Segment::Index(3),
+                        Segment::Index(14),
+                        Segment::Field("c".into())],
+                   path.segments);
+        assert_eq!("a.b_2[3][14].c", path.to_string());
+        for path in &["", "a.", ".a", "a[", "a[]", "a[-1]", "a[1]b", "[1]", "a-b", "a[+1]"] {
+            match *FieldPath::parse(path).unwrap_err().kind() {
+                ErrorKind::BadFieldPath(ref bad) => assert_eq!(path, bad),
+                ref error => panic!("Bad field path error expected, got: {:?}", error),
+            }
+        }
+    }
+
+    #[test]
+    fn decodes_single_fields() {
+        let schema = pose_array();
+        let data = include_bytes!("../datatests/pose_array_msg.bin");
+        let message = from_slice(&schema, data).unwrap();
+        for path in &["header",
+                      "header.seq",
+                      "header.stamp",
+                      "header.frame_id",
+                      "poses",
+                      "poses[0]",
+                      "poses[4].position",
+                      "poses[3].orientation.w"] {
+            let mut expected = Value::Message(message.clone());
+            for part in path.split(['.', '[']) {
+                expected = match part.trim_end_matches(']').parse::<usize>() {
+                    Ok(index) => expected[index].clone(),
+                    Err(_) => expected[part].clone(),
+                };
+            }
+            assert_eq!(expected, field(&schema, path, data).unwrap(), "{}", path);
+        }
+    }
+
+    #[test]
+    fn decodes_parts_of_time() {
+        let schema = Schema::parse("my_msgs/Sample",
+                                   "string[2] s\ntime[] t\nduration d\nuint8 after\n")
+            .unwrap();
+        let data = b"\x20\0\0\0\x01\0\0\0a\x02\0\0\0bc\x01\0\0\0\x05\0\0\0\x06\0\0\0\
+                     \xff\xff\xff\xff\x07\0\0\0\x09";
+        assert_eq!(Value::String("bc".into()), field(&schema, "s[1]", data).unwrap());
+        assert_eq!(Value::Time(Time::new(5, 6)), field(&schema, "t[0]", data).unwrap());
+        assert_eq!(Value::U32(6), field(&schema, "t[0].nsecs", data).unwrap());
+        assert_eq!(Value::I32(-1), field(&schema, "d.sec", data).unwrap());
+        assert_eq!(Value::I32(7), field(&schema, "d.nsec", data).unwrap());
+        assert_eq!(Value::U8(9), field(&schema, "after", data).unwrap());
+    }
+
+    #[test]
+    fn rejects_missing_fields() {
+        let schema = pose_array();
+        let data = include_bytes!("../datatests/pose_array_msg.bin");
+        match *field(&schema, "poses[5]", data).unwrap_err().kind() {
+            ErrorKind::IndexOutOfBounds(5, 5) => {}
+            ref error => panic!("Index out of bounds error expected, got: {:?}", error),
+        }
+        let cases = [("header.time", "std_msgs/Header", "time"),
+                     ("header[0]", "std_msgs/Header", "[0]"),
+                     ("header.seq.x", "uint32", "x"),
+                     ("header.stamp.x", "time", "x"),
+                     ("poses.position", "geometry_msgs/Pose[]", "position")];
+        for &(path, expected_type, expected_field) in &cases {
+            match *field(&schema, path, data).unwrap_err().kind() {
+                ErrorKind::UnknownField(ref field_type, ref name) => {
+                    assert_eq!(expected_type, field_type);
+                    assert_eq!(expected_field, name);
+                }
+                ref error => panic!("Unknown field error expected, got: {:?}", error),
+            }
+        }
+        let schema = Schema::parse("my_msgs/Huge", "float64[4611686018427387904] a\n").unwrap();
+        match *field(&schema, "a[4611686018427387903]", b"\0\0\0\0").unwrap_err().kind() {
+            ErrorKind::Overflow => {}
+            ref error => panic!("Overflow error expected, got: {:?}", error),
+        }
+        let schema = pose_array();
+        match *field(&schema, "poses[4]", &data[..100]).unwrap_err().kind() {
+            ErrorKind::EndOfBuffer => {}
+            ref error => panic!("End of buffer error expected, got: {:?}", error),
+        }
+    }
+}
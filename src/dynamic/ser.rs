@@ -0,0 +1,809 @@
+//! Encoding of dynamically typed messages, driven by a schema.
+
+use super::super::error::{Error, ErrorKind, Result};
+use super::super::msg::{ArrayLength, MessageSpec, PrimitiveType};
+use super::super::ser;
+use super::super::time::{Duration, Time};
+use super::value::{DynamicMessage, Value};
+use super::{Expected, Schema};
+use serde::ser::{Error as SerError, Impossible, Serialize, SerializeMap, SerializeSeq,
+                 SerializeStruct, SerializeTuple, SerializeTupleStruct, Serializer};
+use std::convert::TryFrom;
+use std::io;
+
+/// Convert a value into a message described by the schema.
+///
+/// The value can be a `DynamicMessage`, or any other type that serializes
+/// into a map or a structure keyed by field names, like the values of
+/// self-describing formats. Fields can also be given by position, through
+/// sequences and tuples.
+///
+/// Numbers are converted into the types of their fields, as long as they
+/// fit. Values of `time` and `duration` fields are maps with `sec` and `nsec`
+/// entries, with `secs` and `nsecs` accepted too. Missing and `null` fields
+/// get their default values, while unknown fields and fixed size arrays
+/// with the wrong number of items are rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate serde_rosmsg;
+/// # #[macro_use]
+/// # extern crate serde_derive;
+/// # use serde_rosmsg::dynamic::{self, Schema, Value};
+/// # fn main() {
+/// #[derive(Serialize)]
+/// struct Point {
+///     y: u8,
+/// }
+///
+/// let schema = Schema::parse("my_msgs/Point", "float32 x\nfloat32 y\n").unwrap();
+/// let message = dynamic::to_message(&schema, &Point { y: 2 }).unwrap();
+/// assert_eq!(message["x"], Value::F32(0.0));
+/// assert_eq!(message["y"], Value::F32(2.0));
+/// # }
+/// ```
+pub fn to_message<T>(schema: &Schema, value: &T) -> Result<DynamicMessage>
+    where T: Serialize + ?Sized
+{
+    let serializer = ValueSerializer {
+        schema,
+        expected: Expected::Message(schema.root()),
+    };
+    match value.serialize(serializer)? {
+        Value::Message(message) => Ok(message),
+        _ => unreachable!("Messages are always serialized into messages"),
+    }
+}
+
+/// Serialize a value as a message described by the schema into the IO
+/// stream, with the length prefix.
+///
+/// The value is converted like in `to_message`.
+pub fn to_writer<W, T>(schema: &Schema, writer: &mut W, value: &T) -> Result<()>
+    where W: io::Write,
+          T: Serialize + ?Sized
+{
+    let message = Value::Message(to_message(schema, value)?);
+    ser::to_writer(writer, &Encoded::new(schema, &message))
+}
+
+/// Serialize a value as a message described by the schema into a ROSMSG
+/// byte vector, with the length prefix.
+///
+/// The value is converted like in `to_message`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate serde_json;
+/// # extern crate serde_rosmsg;
+/// # use serde_rosmsg::dynamic::{self, Schema};
+/// # fn main() {
+/// let schema = Schema::parse("my_msgs/Sample", "time t\nint8[2] a\nstring s\n").unwrap();
+/// let input = r#"{"s": "hi", "t": {"secs": 1, "nsecs": 2}, "a": [-2, 7]}"#;
+/// let value: serde_json::Value = serde_json::from_str(input).unwrap();
+/// assert_eq!(dynamic::to_vec(&schema, &value).unwrap(),
+///            b"\x10\0\0\0\x01\0\0\0\x02\0\0\0\xfe\x07\x02\0\0\0hi".to_vec());
+/// # }
+/// ```
+pub fn to_vec<T>(schema: &Schema, value: &T) -> Result<Vec<u8>>
+    where T: Serialize + ?Sized
+{
+    let message = Value::Message(to_message(schema, value)?);
+    ser::to_vec(&Encoded::new(schema, &message))
+}
+
+/// Value of the type described by the schema, which serializes with the
+/// ROSMSG layout of that type.
+struct Encoded<'a> {
+    schema: &'a Schema,
+    expected: Expected<'a>,
+    value: &'a Value,
+}
+
+impl<'a> Encoded<'a> {
+    fn new(schema: &'a Schema, value: &'a Value) -> Self {
+        Encoded {
+            schema,
+            expected: Expected::Message(schema.root()),
+            value,
+        }
+    }
+
+    fn nested(&self, expected: Expected<'a>, value: &'a Value) -> Self {
+        Encoded {
+            schema: self.schema,
+            expected,
+            value,
+        }
+    }
+}
+
+impl<'a> Serialize for Encoded<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        let expected = self.expected.resolve(self.schema).map_err(S::Error::custom)?;
+        match (expected, self.value) {
+            (Expected::Message(spec), Value::Message(message)) => {
+                let mut tuple = serializer.serialize_tuple(spec.fields.len())?;
+                for (field, value) in spec.fields.iter().zip(message) {
+                    tuple.serialize_element(&self.nested(Expected::Field(&field.field_type),
+                                                         &value.1))?;
+                }
+                tuple.end()
+            }
+            (Expected::Field(field_type), Value::Array(items)) => {
+                let expected = Expected::Item(&field_type.base);
+                if let Some(ArrayLength::Fixed(len)) = field_type.array {
+                    let mut tuple = serializer.serialize_tuple(len)?;
+                    for item in items {
+                        tuple.serialize_element(&self.nested(expected, item))?;
+                    }
+                    return tuple.end();
+                }
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(&self.nested(expected, item))?;
+                }
+                seq.end()
+            }
+            (_, value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// Default value of the given type, with zeroes, empty strings and empty
+/// variable size arrays.
+fn default_value(schema: &Schema, expected: Expected) -> Result<Value> {
+    Ok(match expected.resolve(schema)? {
+        Expected::Message(spec) => {
+            let mut message = DynamicMessage::new();
+            for field in &spec.fields {
+                message.insert(field.name.as_str(),
+                               default_value(schema, Expected::Field(&field.field_type))?);
+            }
+            Value::Message(message)
+        }
+        Expected::Field(field_type) => {
+            let len = match field_type.array {
+                Some(ArrayLength::Fixed(len)) => len,
+                _ => 0,
+            };
+            // The length can come from a connection header, so it is not
+            // trusted with allocating memory for more than a message holds.
+            if default_size(schema, expected)? > u32::MAX as usize {
+                bail!(ErrorKind::Overflow);
+            }
+            let item = default_value(schema, Expected::Item(&field_type.base))?;
+            Value::Array(vec![item; len])
+        }
+        Expected::Primitive(primitive) => {
+            match primitive {
+                PrimitiveType::Bool => Value::Bool(false),
+                PrimitiveType::Int8 | PrimitiveType::Byte => Value::I8(0),
+                PrimitiveType::UInt8 | PrimitiveType::Char => Value::U8(0),
+                PrimitiveType::Int16 => Value::I16(0),
+                PrimitiveType::UInt16 => Value::U16(0),
+                PrimitiveType::Int32 => Value::I32(0),
+                PrimitiveType::UInt32 => Value::U32(0),
+                PrimitiveType::Int64 => Value::I64(0),
+                PrimitiveType::UInt64 => Value::U64(0),
+                PrimitiveType::Float32 => Value::F32(0.0),
+                PrimitiveType::Float64 => Value::F64(0.0),
+                PrimitiveType::String => Value::String(String::new()),
+                PrimitiveType::Time => Value::Time(Time::default()),
+                PrimitiveType::Duration => Value::Duration(Duration::default()),
+            }
+        }
+        Expected::Item(_) => unreachable!("Items are always resolved"),
+    })
+}
+
+/// Encoded size of the default value of the given type.
+fn default_size(schema: &Schema, expected: Expected) -> Result<usize> {
+    if let Some(size) = expected.fixed_size(schema)? {
+        return Ok(size);
+    }
+    Ok(match expected.resolve(schema)? {
+        Expected::Message(spec) => {
+            let mut size = 0usize;
+            for field in &spec.fields {
+                let field_size = default_size(schema, Expected::Field(&field.field_type))?;
+                size = field_size.checked_add(size).ok_or(ErrorKind::Overflow)?;
+            }
+            size
+        }
+        Expected::Field(field_type) => {
+            match field_type.array {
+                Some(ArrayLength::Fixed(len)) => {
+                    default_size(schema, Expected::Item(&field_type.base))?
+                        .checked_mul(len)
+                        .ok_or(ErrorKind::Overflow)?
+                }
+                _ => 4,
+            }
+        }
+        // Strings are the only primitives without a fixed size.
+        Expected::Primitive(_) => 4,
+        Expected::Item(_) => unreachable!("Items are always resolved"),
+    })
+}
+
+/// Convert a scalar value into the given primitive type, if it fits.
+fn convert(value: &Value, primitive: PrimitiveType) -> Option<Value> {
+    Some(match primitive {
+        PrimitiveType::Bool => Value::Bool(value.as_bool()?),
+        PrimitiveType::Int8 | PrimitiveType::Byte => Value::I8(i8::try_from(value.as_i64()?).ok()?),
+        PrimitiveType::UInt8 | PrimitiveType::Char => {
+            Value::U8(u8::try_from(value.as_u64()?).ok()?)
+        }
+        PrimitiveType::Int16 => Value::I16(i16::try_from(value.as_i64()?).ok()?),
+        PrimitiveType::UInt16 => Value::U16(u16::try_from(value.as_u64()?).ok()?),
+        PrimitiveType::Int32 => Value::I32(i32::try_from(value.as_i64()?).ok()?),
+        PrimitiveType::UInt32 => Value::U32(u32::try_from(value.as_u64()?).ok()?),
+        PrimitiveType::Int64 => Value::I64(value.as_i64()?),
+        PrimitiveType::UInt64 => Value::U64(value.as_u64()?),
+        PrimitiveType::Float32 => Value::F32(value.as_f64()? as f32),
+        PrimitiveType::Float64 => Value::F64(value.as_f64()?),
+        PrimitiveType::String => Value::String(value.as_str()?.into()),
+        PrimitiveType::Time | PrimitiveType::Duration => return None,
+    })
+}
+
+/// Serializer that converts values into the type expected by the schema.
+#[derive(Clone, Copy)]
+struct ValueSerializer<'a> {
+    schema: &'a Schema,
+    expected: Expected<'a>,
+}
+
+impl<'a> ValueSerializer<'a> {
+    fn nested(self, expected: Expected<'a>) -> Self {
+        ValueSerializer {
+            schema: self.schema,
+            expected,
+        }
+    }
+
+    fn mismatch(self, actual: &str) -> Error {
+        ErrorKind::MismatchedValue(self.expected.to_string(), actual.into()).into()
+    }
+
+    fn scalar(self, value: Value) -> Result<Value> {
+        if let Expected::Primitive(primitive) = self.expected.resolve(self.schema)? {
+            if let Some(value) = convert(&value, primitive) {
+                return Ok(value);
+            }
+        }
+        Err(self.mismatch(&format!("{:?}", value)))
+    }
+
+    fn record(self, actual: &str) -> Result<Compound<'a>> {
+        let record = match self.expected.resolve(self.schema)? {
+            Expected::Message(spec) => Record::Message(spec),
+            Expected::Primitive(PrimitiveType::Time) => Record::Time,
+            Expected::Primitive(PrimitiveType::Duration) => Record::Duration,
+            _ => return Err(self.mismatch(actual)),
+        };
+        Ok(Compound::Record {
+            serializer: self,
+            values: vec![None; record.len()],
+            record,
+            next: 0,
+        })
+    }
+}
+
+impl<'a> Serializer for ValueSerializer<'a> {
+    type Ok = Value;
+    type Error = Error;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Impossible<Value, Error>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Impossible<Value, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value> {
+        self.scalar(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value> {
+        self.scalar(Value::I8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value> {
+        self.scalar(Value::I16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value> {
+        self.scalar(Value::I32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value> {
+        self.scalar(Value::I64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value> {
+        self.scalar(Value::U8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value> {
+        self.scalar(Value::U16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value> {
+        self.scalar(Value::U32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value> {
+        self.scalar(Value::U64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value> {
+        self.scalar(Value::F32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value> {
+        self.scalar(Value::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value> {
+        self.scalar(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value> {
+        self.scalar(Value::String(v.into()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value> {
+        let mut seq = self.serialize_seq(Some(v.len()))?;
+        for byte in v {
+            SerializeSeq::serialize_element(&mut seq, byte)?;
+        }
+        SerializeSeq::end(seq)
+    }
+
+    fn serialize_none(self) -> Result<Value> {
+        default_value(self.schema, self.expected)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value> {
+        default_value(self.schema, self.expected)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value> {
+        default_value(self.schema, self.expected)
+    }
+
+    fn serialize_unit_variant(self,
+                              _name: &'static str,
+                              _variant_index: u32,
+                              variant: &'static str)
+                              -> Result<Value> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self,
+                                                       _name: &'static str,
+                                                       value: &T)
+                                                       -> Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(self,
+                                                        _name: &'static str,
+                                                        _variant_index: u32,
+                                                        _variant: &'static str,
+                                                        _value: &T)
+                                                        -> Result<Value> {
+        bail!(ErrorKind::UnsupportedEnumType)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a>> {
+        if let Expected::Field(field_type) = self.expected.resolve(self.schema)? {
+            let len = match field_type.array {
+                Some(ArrayLength::Fixed(len)) => Some(len),
+                _ => None,
+            };
+            return Ok(Compound::Array {
+                serializer: self.nested(Expected::Item(&field_type.base)),
+                len,
+                items: Vec::new(),
+            });
+        }
+        self.record("a sequence")
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Compound<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Compound<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(self,
+                               _name: &'static str,
+                               _variant_index: u32,
+                               _variant: &'static str,
+                               _len: usize)
+                               -> Result<Self::SerializeTupleVariant> {
+        bail!(ErrorKind::UnsupportedEnumType)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>> {
+        self.record("a map")
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Compound<'a>> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(self,
+                                _name: &'static str,
+                                _variant_index: u32,
+                                _variant: &'static str,
+                                _len: usize)
+                                -> Result<Self::SerializeStructVariant> {
+        bail!(ErrorKind::UnsupportedEnumType)
+    }
+}
+
+/// Types with named fields.
+#[derive(Clone, Copy)]
+enum Record<'a> {
+    Message(&'a MessageSpec),
+    Time,
+    Duration,
+}
+
+impl<'a> Record<'a> {
+    fn len(self) -> usize {
+        match self {
+            Record::Message(spec) => spec.fields.len(),
+            Record::Time | Record::Duration => 2,
+        }
+    }
+
+    fn position(self, name: &str) -> Option<usize> {
+        match self {
+            Record::Message(spec) => spec.fields.iter().position(|field| field.name == name),
+            Record::Time | Record::Duration => {
+                match name {
+                    "sec" | "secs" => Some(0),
+                    "nsec" | "nsecs" => Some(1),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn field(self, position: usize) -> Expected<'a> {
+        match self {
+            Record::Message(spec) => Expected::Field(&spec.fields[position].field_type),
+            Record::Time => Expected::Primitive(PrimitiveType::UInt32),
+            Record::Duration => Expected::Primitive(PrimitiveType::Int32),
+        }
+    }
+
+    fn name(self) -> String {
+        match self {
+            Record::Message(spec) => spec.name.to_string(),
+            Record::Time => "time".into(),
+            Record::Duration => "duration".into(),
+        }
+    }
+
+    fn finish(self, schema: &Schema, values: Vec<Option<Value>>) -> Result<Value> {
+        let mut values = values.into_iter()
+            .enumerate()
+            .map(|(position, value)| match value {
+                Some(value) => Ok(value),
+                None => default_value(schema, self.field(position)),
+            });
+        let mut next = || values.next().expect("Records have all of their fields");
+        Ok(match self {
+            Record::Message(spec) => {
+                let mut message = DynamicMessage::new();
+                for field in &spec.fields {
+                    message.insert(field.name.as_str(), next()?);
+                }
+                Value::Message(message)
+            }
+            Record::Time => {
+                let (sec, nsec) = (next()?, next()?);
+                match (sec, nsec) {
+                    (Value::U32(sec), Value::U32(nsec)) => {
+                        Value::Time(Time::checked_new(sec, nsec)
+                            .ok_or(ErrorKind::TimeOutOfRange)?)
+                    }
+                    _ => unreachable!("Times consist of unsigned integers"),
+                }
+            }
+            Record::Duration => {
+                let (sec, nsec) = (next()?, next()?);
+                match (sec, nsec) {
+                    (Value::I32(sec), Value::I32(nsec)) => {
+                        Value::Duration(Duration::checked_new(sec, nsec)
+                            .ok_or(ErrorKind::TimeOutOfRange)?)
+                    }
+                    _ => unreachable!("Durations consist of signed integers"),
+                }
+            }
+        })
+    }
+}
+
+/// Builder of arrays and records out of their items.
+enum Compound<'a> {
+    Array {
+        serializer: ValueSerializer<'a>,
+        len: Option<usize>,
+        items: Vec<Value>,
+    },
+    Record {
+        serializer: ValueSerializer<'a>,
+        record: Record<'a>,
+        values: Vec<Option<Value>>,
+        next: usize,
+    },
+}
+
+impl<'a> Compound<'a> {
+    fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        match *self {
+            Compound::Array { serializer, ref mut items, .. } => {
+                items.push(value.serialize(serializer)?);
+            }
+            Compound::Record { serializer, record, ref mut values, ref mut next } => {
+                if *next >= values.len() {
+                    return Err(serializer.mismatch(&format!("more than {} fields", values.len())));
+                }
+                values[*next] = Some(value.serialize(serializer.nested(record.field(*next)))?);
+                *next += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        match *self {
+            Compound::Array { serializer, .. } => Err(serializer.mismatch("a map")),
+            Compound::Record { serializer, record, ref mut next, .. } => {
+                let string = serializer.nested(Expected::Primitive(PrimitiveType::String));
+                let name = match key.serialize(string)? {
+                    Value::String(name) => name,
+                    _ => unreachable!("Strings are always serialized into strings"),
+                };
+                *next = record.position(&name)
+                    .ok_or_else(|| ErrorKind::UnknownField(record.name(), name))?;
+                Ok(())
+            }
+        }
+    }
+
+    fn finish(self) -> Result<Value> {
+        match self {
+            Compound::Array { len, items, .. } => {
+                match len {
+                    Some(len) if len != items.len() => {
+                        bail!(ErrorKind::MismatchedArrayLength(len, items.len()))
+                    }
+                    _ => Ok(Value::Array(items)),
+                }
+            }
+            Compound::Record { serializer, record, values, .. } => {
+                record.finish(serializer.schema, values)
+            }
+        }
+    }
+}
+
+impl<'a> SerializeSeq for Compound<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl<'a> SerializeTuple for Compound<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl<'a> SerializeTupleStruct for Compound<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl<'a> SerializeMap for Compound<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        self.key(key)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl<'a> SerializeStruct for Compound<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self,
+                                              key: &'static str,
+                                              value: &T)
+                                              -> Result<()> {
+        self.key(key)?;
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::from_slice;
+    use from_slice as typed_from_slice;
+    use std::collections::{BTreeMap, HashMap};
+
+    fn schema_from_header(request: &[u8]) -> Schema {
+        let header: HashMap<String, String> = typed_from_slice(request).unwrap();
+        Schema::from_header(&header).unwrap()
+    }
+
+    #[test]
+    fn encodes_decoded_messages() {
+        let schema = schema_from_header(include_bytes!("../datatests/pose_array_res.bin"));
+        let data = include_bytes!("../datatests/pose_array_msg.bin");
+        let message = from_slice(&schema, data).unwrap();
+        assert_eq!(message, to_message(&schema, &message).unwrap());
+        assert_eq!(data.to_vec(), to_vec(&schema, &message).unwrap());
+
+        let schema =
+            schema_from_header(include_bytes!("../datatests/pose_with_covariance_req.bin"));
+        let data = include_bytes!("../datatests/pose_with_covariance_msg.bin");
+        let message = from_slice(&schema, data).unwrap();
+        let mut cursor = io::Cursor::new(Vec::new());
+        to_writer(&schema, &mut cursor, &message).unwrap();
+        assert_eq!(data.to_vec(), cursor.into_inner());
+    }
+
+    #[test]
+    fn fills_missing_fields() {
+        #[derive(Serialize)]
+        struct Stamped {
+            header: BTreeMap<&'static str, u8>,
+            data: Option<Vec<u16>>,
+        }
+
+        let schema = Schema::parse("my_msgs/Stamped",
+                                   &format!("Header header\nint8[2] fixed\nint32[] data\n\
+                                             {}\nMSG: std_msgs/Header\n\
+                                             uint32 seq\ntime stamp\nstring frame_id\n",
+                                            "=".repeat(80)))
+            .unwrap();
+        let mut header = BTreeMap::new();
+        header.insert("seq", 3);
+        let message = to_message(&schema,
+                                 &Stamped {
+                                     header,
+                                     data: None,
+                                 })
+            .unwrap();
+        assert_eq!(Value::U32(3), message["header"]["seq"]);
+        assert_eq!(Value::Time(Time::new(0, 0)), message["header"]["stamp"]);
+        assert_eq!(Value::String(String::new()), message["header"]["frame_id"]);
+        assert_eq!(Value::Array(vec![Value::I8(0), Value::I8(0)]), message["fixed"]);
+        assert_eq!(Value::Array(vec![]), message["data"]);
+        let names = message.iter().map(|field| field.0.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["header", "fixed", "data"], names);
+        let empty = Stamped {
+            header: BTreeMap::new(),
+            data: Some(vec![]),
+        };
+        assert_eq!(b"\x16\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0".to_vec(),
+                   to_vec(&schema, &empty).unwrap());
+
+        let schema = Schema::parse("my_msgs/Huge", "float64[4611686018427387904] a\n").unwrap();
+        match *to_message(&schema, &()).unwrap_err().kind() {
+            ErrorKind::Overflow => {}
+            ref error => panic!("Overflow error expected, got: {:?}", error),
+        }
+        let schema = Schema::parse("my_msgs/Huge", "string[2147483648] a\n").unwrap();
+        match *to_vec(&schema, &BTreeMap::<&str, u8>::new()).unwrap_err().kind() {
+            ErrorKind::Overflow => {}
+            ref error => panic!("Overflow error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn accepts_fields_by_position() {
+        let schema = Schema::parse("my_msgs/Sample", "uint16 a\nduration d\nstring s\n").unwrap();
+        let message = to_message(&schema, &(7u8, (-1i64, 1_500_000_000u32), "x")).unwrap();
+        assert_eq!(Value::U16(7), message["a"]);
+        assert_eq!(Value::Duration(Duration::new(0, 500_000_000)), message["d"]);
+        assert_eq!(Value::String("x".into()), message["s"]);
+        assert_eq!(b"\x0f\0\0\0\x07\0\0\0\0\0\0\x65\xcd\x1d\x01\0\0\0x".to_vec(),
+                   to_vec(&schema, &(7u8, (-1i64, 1_500_000_000u32), "x")).unwrap());
+    }
+
+    #[test]
+    fn rejects_mismatched_values() {
+        let schema = Schema::parse("my_msgs/Sample", "uint8 a\nfloat32[2] b\ntime t\n").unwrap();
+        let message = |a: i64, b: Vec<f64>, t: u32| {
+            let mut message = DynamicMessage::new();
+            message.insert("a", Value::I64(a));
+            message.insert("b", Value::Array(b.into_iter().map(Value::F64).collect()));
+            message.insert("t", Value::Time(Time { sec: u32::MAX, nsec: t }));
+            message
+        };
+        assert!(to_vec(&schema, &message(1, vec![1.0, 2.0], 0)).is_ok());
+        match *to_vec(&schema, &message(256, vec![1.0, 2.0], 0)).unwrap_err().kind() {
+            ErrorKind::MismatchedValue(ref expected, ref actual) => {
+                assert_eq!("uint8", expected);
+                assert_eq!("I64(256)", actual);
+            }
+            ref error => panic!("Mismatched value error expected, got: {:?}", error),
+        }
+        match *to_vec(&schema, &message(1, vec![1.0], 0)).unwrap_err().kind() {
+            ErrorKind::MismatchedArrayLength(2, 1) => {}
+            ref error => panic!("Mismatched array length error expected, got: {:?}", error),
+        }
+        match *to_vec(&schema, &message(1, vec![1.0, 2.0], 1_000_000_000)).unwrap_err().kind() {
+            ErrorKind::TimeOutOfRange => {}
+            ref error => panic!("Time out of range error expected, got: {:?}", error),
+        }
+        let mut unknown = message(1, vec![1.0, 2.0], 0);
+        unknown.insert("c", Value::Bool(true));
+        match *to_vec(&schema, &unknown).unwrap_err().kind() {
+            ErrorKind::UnknownField(ref message, ref field) => {
+                assert_eq!("my_msgs/Sample", message);
+                assert_eq!("c", field);
+            }
+            ref error => panic!("Unknown field error expected, got: {:?}", error),
+        }
+        match *to_vec(&schema, &"text").unwrap_err().kind() {
+            ErrorKind::MismatchedValue(ref expected, _) => assert_eq!("my_msgs/Sample", expected),
+            ref error => panic!("Mismatched value error expected, got: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Dynamically typed values of ROS messages.
+
+use super::super::time::{Duration, Time};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::ops::Index;
+use std::slice;
+
+/// Any value that a ROS message field can hold.
+///
+/// Primitive types map to the variants of matching Rust types, with `byte`
+/// decoded as `I8` and `char` as `U8`, like in `roscpp`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A `bool` field.
+    Bool(bool),
+    /// An `int8` or `byte` field.
+    I8(i8),
+    /// A `uint8` or `char` field.
+    U8(u8),
+    /// An `int16` field.
+    I16(i16),
+    /// A `uint16` field.
+    U16(u16),
+    /// An `int32` field.
+    I32(i32),
+    /// A `uint32` field.
+    U32(u32),
+    /// An `int64` field.
+    I64(i64),
+    /// A `uint64` field.
+    U64(u64),
+    /// A `float32` field.
+    F32(f32),
+    /// A `float64` field.
+    F64(f64),
+    /// A `string` field.
+    String(String),
+    /// A `time` field.
+    Time(Time),
+    /// A `duration` field.
+    Duration(Duration),
+    /// An array field of either fixed or variable size.
+    Array(Vec<Value>),
+    /// A nested message.
+    Message(DynamicMessage),
+}
+
+impl Value {
+    /// Get the value of a `bool` field.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Value::Bool(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Get the value of any integer field, if it fits into an `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Value::I8(v) => Some(i64::from(v)),
+            Value::U8(v) => Some(i64::from(v)),
+            Value::I16(v) => Some(i64::from(v)),
+            Value::U16(v) => Some(i64::from(v)),
+            Value::I32(v) => Some(i64::from(v)),
+            Value::U32(v) => Some(i64::from(v)),
+            Value::I64(v) => Some(v),
+            Value::U64(v) if v <= i64::MAX as u64 => Some(v as i64),
+            _ => None,
+        }
+    }
+
+    /// Get the value of any integer field, if it fits into a `u64`.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Value::U64(v) => Some(v),
+            _ => self.as_i64().and_then(|v| if v < 0 { None } else { Some(v as u64) }),
+        }
+    }
+
+    /// Get the value of any numeric field as an `f64`.
+    ///
+    /// Large 64-bit integers lose precision in the conversion.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Value::F32(v) => Some(f64::from(v)),
+            Value::F64(v) => Some(v),
+            Value::U64(v) => Some(v as f64),
+            _ => self.as_i64().map(|v| v as f64),
+        }
+    }
+
+    /// Get the value of a `string` field.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            Value::String(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a `time` field.
+    pub fn as_time(&self) -> Option<Time> {
+        match *self {
+            Value::Time(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a `duration` field.
+    pub fn as_duration(&self) -> Option<Duration> {
+        match *self {
+            Value::Duration(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Get the items of an array field.
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match *self {
+            Value::Array(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Get the fields of a nested message.
+    pub fn as_message(&self) -> Option<&DynamicMessage> {
+        match *self {
+            Value::Message(ref v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// Values serialize into self-describing data, with messages as maps from
+/// field names to values.
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Value::Bool(v) => serializer.serialize_bool(v),
+            Value::I8(v) => serializer.serialize_i8(v),
+            Value::U8(v) => serializer.serialize_u8(v),
+            Value::I16(v) => serializer.serialize_i16(v),
+            Value::U16(v) => serializer.serialize_u16(v),
+            Value::I32(v) => serializer.serialize_i32(v),
+            Value::U32(v) => serializer.serialize_u32(v),
+            Value::I64(v) => serializer.serialize_i64(v),
+            Value::U64(v) => serializer.serialize_u64(v),
+            Value::F32(v) => serializer.serialize_f32(v),
+            Value::F64(v) => serializer.serialize_f64(v),
+            Value::String(ref v) => serializer.serialize_str(v),
+            Value::Time(ref v) => v.serialize(serializer),
+            Value::Duration(ref v) => v.serialize(serializer),
+            Value::Array(ref v) => v.serialize(serializer),
+            Value::Message(ref v) => v.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Index<&'a str> for Value {
+    type Output = Value;
+
+    /// Get a field of a nested message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a message, or if the field is missing.
+    fn index(&self, name: &'a str) -> &Value {
+        match *self {
+            Value::Message(ref message) => &message[name],
+            _ => panic!("Value is not a message, so it has no field {:?}", name),
+        }
+    }
+}
+
+impl Index<usize> for Value {
+    type Output = Value;
+
+    /// Get an item of an array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not an array, or if the index is out of bounds.
+    fn index(&self, index: usize) -> &Value {
+        match *self {
+            Value::Array(ref items) => &items[index],
+            _ => panic!("Value is not an array, so it has no item {}", index),
+        }
+    }
+}
+
+/// A message with dynamically typed fields, in the order of declaration.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamicMessage {
+    fields: Vec<(String, Value)>,
+}
+
+impl DynamicMessage {
+    /// Create a message without any fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of a field, returning the previous value if the field
+    /// was already set.
+    ///
+    /// New fields are added after the existing ones.
+    pub fn insert<S: Into<String>>(&mut self, name: S, value: Value) -> Option<Value> {
+        let name = name.into();
+        if let Some(old) = self.get_mut(&name) {
+            return Some(::std::mem::replace(old, value));
+        }
+        self.fields.push((name, value));
+        None
+    }
+
+    /// Get the value of a field.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields.iter().find(|field| field.0 == name).map(|field| &field.1)
+    }
+
+    /// Get the mutable value of a field.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.fields.iter_mut().find(|field| field.0 == name).map(|field| &mut field.1)
+    }
+
+    /// Iterate over the names and values of the fields.
+    pub fn iter(&self) -> slice::Iter<'_, (String, Value)> {
+        self.fields.iter()
+    }
+
+    /// Number of fields in the message.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Check if the message has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl<'a> Index<&'a str> for DynamicMessage {
+    type Output = Value;
+
+    /// Get the value of a field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is missing.
+    fn index(&self, name: &'a str) -> &Value {
+        match self.get(name) {
+            Some(value) => value,
+            None => panic!("Message has no field {:?}", name),
+        }
+    }
+}
+
+impl Serialize for DynamicMessage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (name, value) in self {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> IntoIterator for &'a DynamicMessage {
+    type Item = &'a (String, Value);
+    type IntoIter = slice::Iter<'a, (String, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_numbers() {
+        assert_eq!(Some(-3), Value::I8(-3).as_i64());
+        assert_eq!(None, Value::I8(-3).as_u64());
+        assert_eq!(Some(200), Value::U8(200).as_u64());
+        assert_eq!(None, Value::U64(u64::MAX).as_i64());
+        assert_eq!(Some(u64::MAX), Value::U64(u64::MAX).as_u64());
+        assert_eq!(Some(1.5), Value::F32(1.5).as_f64());
+        assert_eq!(Some(7.0), Value::U16(7).as_f64());
+        assert_eq!(None, Value::String("7".into()).as_f64());
+        assert_eq!(None, Value::F64(1.0).as_i64());
+    }
+
+    #[test]
+    fn keeps_field_order() {
+        let mut message = DynamicMessage::new();
+        assert_eq!(None, message.insert("b", Value::Bool(true)));
+        assert_eq!(None, message.insert("a", Value::U8(1)));
+        assert_eq!(Some(Value::Bool(true)), message.insert("b", Value::Bool(false)));
+        let names = message.iter().map(|field| field.0.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["b", "a"], names);
+        assert_eq!(Value::U8(1), message["a"]);
+        assert_eq!(None, message.get("c"));
+        assert_eq!(2, message.len());
+    }
+
+    #[test]
+    fn indexes_nested_values() {
+        let mut point = DynamicMessage::new();
+        point.insert("x", Value::F64(2.0));
+        let mut message = DynamicMessage::new();
+        message.insert("points", Value::Array(vec![Value::Message(point)]));
+        let message = Value::Message(message);
+        assert_eq!(Some(2.0), message["points"][0]["x"].as_f64());
+    }
+
+    #[test]
+    #[should_panic(expected = "Message has no field \"y\"")]
+    fn panics_on_missing_fields() {
+        let _ = &DynamicMessage::new()["y"];
+    }
+}
@@ -43,5 +43,83 @@ error_chain! {
             description("Size annotation in variable size array is missing")
                 display("Size annotation in variable size array is missing")
         }
+        BadOptionLength(l: u32) {
+            description("Options need to be encoded as arrays of up to one element")
+                display("Options need to be encoded as arrays of up to one element, got: {}", l)
+        }
+        BadEnumValue(v: i64) {
+            description("Enumeration value does not match any variant")
+                display("Enumeration value does not match any variant: {}", v)
+        }
+        BadMessageName(n: String) {
+            description("Message names need to have a format of \"package/Name\"")
+                display("Message names need to have a format of \"package/Name\", got: {}", n)
+        }
+        BadMessageDefinition(line: usize, column: usize, reason: String) {
+            description("Message definition could not be parsed")
+                display("Bad message definition at line {}, column {}: {}", line, column, reason)
+        }
+        MissingMessageType(n: String) {
+            description("Definition of a nested message type is missing")
+                display("Definition of a nested message type is missing: {}", n)
+        }
+        RecursiveMessageType(n: String) {
+            description("Message type contains itself")
+                display("Message type contains itself: {}", n)
+        }
+        MissingPackage(n: String) {
+            description("Package could not be found in the search paths")
+                display("Package could not be found in the search paths: {}", n)
+        }
+        MissingHeaderField(f: String) {
+            description("Connection header is missing a required field")
+                display("Connection header is missing a required field: {}", f)
+        }
+        HeaderError(e: String) {
+            description("Connection header reports an error")
+                display("Connection header reports an error: {}", e)
+        }
+        ServiceError(e: String) {
+            description("Service call failed")
+                display("Service call failed: {}", e)
+        }
+        MismatchedHeaderField(f: String, expected: String, actual: String) {
+            description("Connection header field does not match the expected value")
+                display("Connection header field {} does not match, expected {}, got {}",
+                        f, expected, actual)
+        }
+        UnknownField(t: String, f: String) {
+            description("Type has no field with the given name")
+                display("Type {} has no field {}", t, f)
+        }
+        MismatchedValue(expected: String, actual: String) {
+            description("Value does not match the type of its field")
+                display("Value does not match the type {}, got: {}", expected, actual)
+        }
+        MismatchedArrayLength(expected: usize, actual: usize) {
+            description("Fixed size array has the wrong number of items")
+                display("Fixed size array needs {} items, got {}", expected, actual)
+        }
+        BadFieldPath(p: String) {
+            description("Field paths need to have a format like \"poses[3].position.x\"")
+                display("Field paths need to have a format like \"poses[3].position.x\", got: {}",
+                        p)
+        }
+        IndexOutOfBounds(index: usize, len: usize) {
+            description("Array index is out of bounds")
+                display("Array index {} is out of bounds for length {}", index, len)
+        }
+        TimeOutOfRange {
+            description("Time or duration does not fit into the target type")
+                display("Time or duration does not fit into the target type")
+        }
+        MessageTooLarge {
+            description("Serialized data does not fit into a 32-bit length")
+                display("Serialized data does not fit into a 32-bit length")
+        }
+        FrameTooLarge(len: u32, max: u32) {
+            description("Frame is longer than the allowed length")
+                display("Frame length {} is longer than the allowed length {}", len, max)
+        }
     }
 }
@@ -0,0 +1,324 @@
+//! Framing of TCPROS byte streams.
+//!
+//! TCPROS connections carry a connection header followed by any number of
+//! messages, each prefixed by its 32-bit length. Sockets deliver that data in
+//! chunks split at arbitrary points, so `FrameDecoder` buffers the chunks
+//! and yields each frame once it is complete. `FrameEncoder` does the
+//! opposite, queueing frames until the socket accepts them.
+//!
+//! Frames are kept together with their length prefix, so they can be passed
+//! straight to `from_slice`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde_rosmsg::frame::{FrameDecoder, FrameEncoder};
+//! let mut encoder = FrameEncoder::new();
+//! encoder.push(&String::from("Hello")).unwrap();
+//! encoder.push(&String::from("World")).unwrap();
+//! let data = encoder.pending().to_vec();
+//!
+//! let mut decoder = FrameDecoder::new();
+//! let mut messages = Vec::<String>::new();
+//! for chunk in data.chunks(3) {
+//!     decoder.extend(chunk);
+//!     while let Some(message) = decoder.next_message().unwrap() {
+//!         messages.push(message);
+//!     }
+//! }
+//! assert_eq!(messages, vec!["Hello", "World"]);
+//! assert!(decoder.is_empty());
+//! ```
+
+use super::de::from_slice;
+use super::error::{ErrorKind, Result};
+use super::ser::to_writer;
+use byteorder::{ByteOrder, LittleEndian};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io;
+
+/// Resumable splitter of a byte stream into length-prefixed frames.
+#[derive(Clone, Debug, Default)]
+pub struct FrameDecoder {
+    buffer: Vec<u8>,
+    start: usize,
+    max_length: Option<u32>,
+}
+
+impl FrameDecoder {
+    /// Create a decoder without any buffered data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the largest length of a frame, excluding its prefix.
+    ///
+    /// Frames announcing a larger length are rejected as soon as their
+    /// prefix is received, instead of buffering data for them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::frame::FrameDecoder;
+    /// let mut decoder = FrameDecoder::new().max_frame_length(4);
+    /// decoder.extend(b"\x04\0\0\0abcd\x05\0\0\0");
+    /// assert_eq!(decoder.next_frame().unwrap(), Some(b"\x04\0\0\0abcd".to_vec()));
+    /// assert!(decoder.next_frame().is_err());
+    /// ```
+    pub fn max_frame_length(mut self, max_length: u32) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Append a chunk of received data to the buffer.
+    pub fn extend(&mut self, chunk: &[u8]) {
+        if self.start > 0 && self.start >= self.buffer.len() / 2 {
+            self.buffer.drain(..self.start);
+            self.start = 0;
+        }
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Take the next complete frame out of the buffer, including its length
+    /// prefix.
+    ///
+    /// Returns `None` if more data is needed to complete the frame.
+    pub fn next_frame(&mut self) -> Result<Option<Vec<u8>>> {
+        let data = &self.buffer[self.start..];
+        if data.len() < 4 {
+            return Ok(None);
+        }
+        let length = LittleEndian::read_u32(data);
+        if let Some(max_length) = self.max_length {
+            if length > max_length {
+                bail!(ErrorKind::FrameTooLarge(length, max_length));
+            }
+        }
+        let end = 4 + length as usize;
+        if data.len() < end {
+            return Ok(None);
+        }
+        let frame = data[..end].to_vec();
+        self.start += end;
+        Ok(Some(frame))
+    }
+
+    /// Take the next complete frame out of the buffer and deserialize it.
+    ///
+    /// Returns `None` if more data is needed to complete the frame.
+    pub fn next_message<T: DeserializeOwned>(&mut self) -> Result<Option<T>> {
+        match self.next_frame()? {
+            Some(frame) => from_slice(&frame).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Number of buffered bytes that are not part of a returned frame yet.
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len() - self.start
+    }
+
+    /// Check if all the buffered data was returned in frames.
+    ///
+    /// Data left in the buffer when the stream ends belongs to a truncated
+    /// frame.
+    pub fn is_empty(&self) -> bool {
+        self.buffered_len() == 0
+    }
+}
+
+/// Queue of length-prefixed frames waiting to be written into a byte stream.
+#[derive(Clone, Debug, Default)]
+pub struct FrameEncoder {
+    buffer: Vec<u8>,
+    start: usize,
+}
+
+impl FrameEncoder {
+    /// Create an encoder without any queued data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serialize a value as a frame at the end of the queue.
+    pub fn push<T: Serialize>(&mut self, value: &T) -> Result<()> {
+        if self.start == self.buffer.len() {
+            self.buffer.clear();
+            self.start = 0;
+        }
+        to_writer(&mut self.buffer, value)
+    }
+
+    /// Queued data that was not written yet.
+    pub fn pending(&self) -> &[u8] {
+        &self.buffer[self.start..]
+    }
+
+    /// Mark the first `len` bytes of the queued data as written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `len` bytes are queued.
+    pub fn consume(&mut self, len: usize) {
+        assert!(len <= self.pending().len(),
+                "Cannot consume more than the queued data");
+        self.start += len;
+    }
+
+    /// Write as much of the queued data into the writer as it accepts.
+    ///
+    /// Errors are returned as they are, including `WouldBlock` errors of
+    /// non-blocking writers, with the data that was not written kept in the
+    /// queue for the next attempt.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::frame::FrameEncoder;
+    /// let mut encoder = FrameEncoder::new();
+    /// encoder.push(&7u8).unwrap();
+    /// let mut output = Vec::new();
+    /// encoder.write_to(&mut output).unwrap();
+    /// assert_eq!(output, b"\x01\0\0\0\x07");
+    /// assert!(encoder.is_empty());
+    /// ```
+    pub fn write_to<W: io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        while !self.is_empty() {
+            match writer.write(self.pending()) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(len) => self.consume(len),
+                Err(ref error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if all the queued data was written.
+    pub fn is_empty(&self) -> bool {
+        self.pending().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use header::ConnectionHeader;
+    use std::cmp;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Pose {
+        position: (f64, f64, f64),
+        orientation: (f64, f64, f64, f64),
+    }
+
+    fn recording() -> Vec<u8> {
+        let mut data = include_bytes!("datatests/pose_res.bin").to_vec();
+        for _ in 0..3 {
+            data.extend_from_slice(include_bytes!("datatests/pose_msg.bin"));
+        }
+        data
+    }
+
+    #[test]
+    fn decodes_streams_split_at_any_point() {
+        let data = recording();
+        for &chunk_size in &[1, 3, 4, 7, 64, 100, data.len()] {
+            let mut decoder = FrameDecoder::new();
+            let mut header = None;
+            let mut poses = Vec::new();
+            for chunk in data.chunks(chunk_size) {
+                decoder.extend(chunk);
+                if header.is_none() {
+                    header = decoder.next_message::<ConnectionHeader>().unwrap();
+                }
+                if header.is_some() {
+                    while let Some(pose) = decoder.next_message::<Pose>().unwrap() {
+                        poses.push(pose);
+                    }
+                }
+            }
+            assert!(decoder.is_empty());
+            assert_eq!(Some("/meow"), header.unwrap().topic());
+            assert_eq!(3, poses.len());
+            for pose in &poses {
+                assert_eq!((1.0, 2.0, 3.0), pose.position);
+                assert_eq!((4.0, 5.0, 6.0, 7.0), pose.orientation);
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_partial_frames() {
+        let data = include_bytes!("datatests/pose_msg.bin");
+        let mut decoder = FrameDecoder::new();
+        decoder.extend(&data[..3]);
+        assert_eq!(None, decoder.next_frame().unwrap());
+        decoder.extend(&data[3..10]);
+        assert_eq!(None, decoder.next_frame().unwrap());
+        assert_eq!(10, decoder.buffered_len());
+        decoder.extend(&data[10..]);
+        decoder.extend(&data[..5]);
+        assert_eq!(Some(data.to_vec()), decoder.next_frame().unwrap());
+        assert_eq!(None, decoder.next_frame().unwrap());
+        assert_eq!(5, decoder.buffered_len());
+        assert!(!decoder.is_empty());
+    }
+
+    #[test]
+    fn rejects_large_frames() {
+        let mut decoder = FrameDecoder::new().max_frame_length(55);
+        decoder.extend(include_bytes!("datatests/pose_msg.bin"));
+        match *decoder.next_frame().unwrap_err().kind() {
+            ErrorKind::FrameTooLarge(56, 55) => {}
+            ref error => panic!("Frame too large error expected, got: {:?}", error),
+        }
+    }
+
+    /// Writer accepting a limited number of bytes before blocking.
+    struct Socket {
+        data: Vec<u8>,
+        capacity: usize,
+    }
+
+    impl io::Write for Socket {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.capacity == 0 {
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            let len = cmp::min(cmp::min(self.capacity, buf.len()), 5);
+            self.data.extend_from_slice(&buf[..len]);
+            self.capacity -= len;
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn encodes_into_blocking_writers() {
+        let header: ConnectionHeader = from_slice(include_bytes!("datatests/pose_res.bin"))
+            .unwrap();
+        let mut encoder = FrameEncoder::new();
+        encoder.push(&header).unwrap();
+        encoder.push(&((1.0, 2.0, 3.0), (4.0, 5.0, 6.0, 7.0))).unwrap();
+        let mut socket = Socket {
+            data: Vec::new(),
+            capacity: 100,
+        };
+        let error = encoder.write_to(&mut socket).unwrap_err();
+        assert_eq!(io::ErrorKind::WouldBlock, error.kind());
+        assert_eq!(100, socket.data.len());
+        encoder.push(&((1.0, 2.0, 3.0), (4.0, 5.0, 6.0, 7.0))).unwrap();
+        socket.capacity = usize::MAX;
+        encoder.write_to(&mut socket).unwrap();
+        assert!(encoder.is_empty());
+        let mut expected = include_bytes!("datatests/pose_res.bin").to_vec();
+        expected.extend_from_slice(include_bytes!("datatests/pose_msg.bin"));
+        expected.extend_from_slice(include_bytes!("datatests/pose_msg.bin"));
+        assert_eq!(expected, socket.data);
+    }
+}
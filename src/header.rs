@@ -0,0 +1,416 @@
+//! Typed TCPROS connection headers.
+//!
+//! Connection headers are sent as maps of `key=value` strings, so they can be
+//! decoded into a `HashMap<String, String>`. A `ConnectionHeader` keeps the
+//! fields in the order they were received instead, which makes encoding it
+//! again produce the exact same bytes, and provides typed access to the
+//! fields defined by TCPROS.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde_rosmsg::{from_slice, to_vec};
+//! # use serde_rosmsg::header::{ConnectionHeader, Role};
+//! let mut header = ConnectionHeader::new();
+//! header.insert("callerid", "/talker");
+//! header.insert("topic", "/chatter");
+//! header.insert("type", "std_msgs/String");
+//! header.insert("md5sum", "992ce8a1687cec8c8bd883ec73ca41d1");
+//! header.insert("tcp_nodelay", "1");
+//! header.validate(Role::Subscriber).unwrap();
+//!
+//! let data = to_vec(&header).unwrap();
+//! let decoded: ConnectionHeader = from_slice(&data).unwrap();
+//! assert_eq!(decoded.topic(), Some("/chatter"));
+//! assert_eq!(decoded.tcp_nodelay(), true);
+//! assert_eq!(decoded.latching(), false);
+//! assert_eq!(to_vec(&decoded).unwrap(), data);
+//! ```
+
+use super::error::{ErrorKind, Result};
+use super::message::check_type_fields;
+use super::RosMessage;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::{cmp, fmt};
+use std::iter::FromIterator;
+use std::slice;
+
+/// Side of a TCPROS connection that sends a connection header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A node publishing messages on a topic.
+    Publisher,
+    /// A node subscribing to a topic.
+    Subscriber,
+    /// A node calling a service.
+    ServiceClient,
+    /// A node providing a service.
+    ServiceServer,
+}
+
+impl Role {
+    /// Fields that headers sent in this role need to contain.
+    pub fn required_fields(self) -> &'static [&'static str] {
+        match self {
+            Role::Publisher => &["md5sum", "type"],
+            Role::Subscriber => &["callerid", "topic", "md5sum", "type"],
+            Role::ServiceClient => &["callerid", "service", "md5sum"],
+            Role::ServiceServer => &["callerid"],
+        }
+    }
+}
+
+/// A TCPROS connection header, with fields in the order they were given.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionHeader {
+    fields: Vec<(String, String)>,
+}
+
+impl ConnectionHeader {
+    /// Create a header without any fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a header describing messages of type `T`, with the `type`,
+    /// `md5sum` and `message_definition` fields.
+    pub fn for_message<T: RosMessage>() -> Self {
+        let mut header = Self::new();
+        header.insert("type", T::ROS_TYPE);
+        header.insert("md5sum", T::MD5SUM);
+        header.insert("message_definition", T::DEFINITION);
+        header
+    }
+
+    /// Set the value of a field, returning the previous value if the field
+    /// was already set.
+    ///
+    /// New fields are added after the existing ones. If the field is
+    /// repeated, its last occurrence is changed.
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> Option<String>
+        where K: Into<String>,
+              V: Into<String>
+    {
+        let key = key.into();
+        let value = value.into();
+        if let Some(field) = self.fields.iter_mut().rev().find(|field| field.0 == key) {
+            return Some(::std::mem::replace(&mut field.1, value));
+        }
+        self.fields.push((key, value));
+        None
+    }
+
+    /// Remove a field, including all its repetitions, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let position = self.fields.iter().rposition(|field| field.0 == key)?;
+        let value = self.fields.remove(position).1;
+        self.fields.retain(|field| field.0 != key);
+        Some(value)
+    }
+
+    /// Get the value of a field.
+    ///
+    /// If the field is repeated, the value of its last occurrence is used,
+    /// like ROS nodes do.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.iter().rev().find(|field| field.0 == key).map(|field| field.1.as_str())
+    }
+
+    /// Iterate over the keys and values of the fields.
+    pub fn iter(&self) -> slice::Iter<'_, (String, String)> {
+        self.fields.iter()
+    }
+
+    /// Number of fields in the header.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Check if the header has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Name of the topic.
+    pub fn topic(&self) -> Option<&str> {
+        self.get("topic")
+    }
+
+    /// Name of the message type, or `*` if any type is accepted.
+    pub fn ros_type(&self) -> Option<&str> {
+        self.get("type")
+    }
+
+    /// MD5 sum of the message or service type, or `*` if any type is
+    /// accepted.
+    pub fn md5sum(&self) -> Option<&str> {
+        self.get("md5sum")
+    }
+
+    /// Name of the node sending the header.
+    pub fn callerid(&self) -> Option<&str> {
+        self.get("callerid")
+    }
+
+    /// Full definition of the message type.
+    pub fn message_definition(&self) -> Option<&str> {
+        self.get("message_definition")
+    }
+
+    /// Name of the service.
+    pub fn service(&self) -> Option<&str> {
+        self.get("service")
+    }
+
+    /// Reason for refusing the connection.
+    pub fn error(&self) -> Option<&str> {
+        self.get("error")
+    }
+
+    /// Check if the publisher sends the last message to new subscribers.
+    pub fn latching(&self) -> bool {
+        self.flag("latching")
+    }
+
+    /// Check if the subscriber asks for disabling Nagle's algorithm.
+    pub fn tcp_nodelay(&self) -> bool {
+        self.flag("tcp_nodelay")
+    }
+
+    /// Check if the service client keeps the connection open for multiple
+    /// calls.
+    pub fn persistent(&self) -> bool {
+        self.flag("persistent")
+    }
+
+    fn flag(&self, key: &str) -> bool {
+        matches!(self.get(key), Some("1") | Some("true"))
+    }
+
+    /// Check that the header contains all the fields required in the given
+    /// role, and that it does not report an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::header::{ConnectionHeader, Role};
+    /// let mut header = ConnectionHeader::new();
+    /// header.insert("callerid", "/add_two_ints_server");
+    /// header.validate(Role::ServiceServer).unwrap();
+    /// assert!(header.validate(Role::ServiceClient).is_err());
+    ///
+    /// header.insert("error", "unknown service");
+    /// assert!(header.validate(Role::ServiceServer).is_err());
+    /// ```
+    pub fn validate(&self, role: Role) -> Result<()> {
+        if let Some(error) = self.error() {
+            bail!(ErrorKind::HeaderError(error.into()));
+        }
+        for &field in role.required_fields() {
+            if self.get(field).is_none() {
+                bail!(ErrorKind::MissingHeaderField(field.into()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the header describes messages of type `T`.
+    ///
+    /// Behaves like `message::validate_header`.
+    pub fn validate_message<T: RosMessage>(&self) -> Result<()> {
+        check_type_fields::<T, _>(|field| self.get(field))
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for ConnectionHeader {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut header = Self::new();
+        for (key, value) in iter {
+            header.insert(key, value);
+        }
+        header
+    }
+}
+
+impl<'a> IntoIterator for &'a ConnectionHeader {
+    type Item = &'a (String, String);
+    type IntoIter = slice::Iter<'a, (String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Serialize for ConnectionHeader {
+    fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ConnectionHeader {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_map(HeaderVisitor)
+    }
+}
+
+struct HeaderVisitor;
+
+impl<'de> Visitor<'de> for HeaderVisitor {
+    type Value = ConnectionHeader;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a connection header")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> ::std::result::Result<ConnectionHeader, A::Error>
+        where A: MapAccess<'de>
+    {
+        // Repeated fields are kept, so that encoding gives back the same data.
+        // The length comes from the data, so it is not trusted with allocating memory.
+        let mut fields = Vec::with_capacity(cmp::min(map.size_hint().unwrap_or(0), 4096));
+        while let Some(field) = map.next_entry()? {
+            fields.push(field);
+        }
+        Ok(ConnectionHeader { fields })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use de::from_slice;
+    use ser::to_vec;
+    use std::collections::HashMap;
+
+    #[derive(Serialize, Deserialize)]
+    struct Data {
+        data: String,
+    }
+
+    impl RosMessage for Data {
+        const ROS_TYPE: &'static str = "std_msgs/String";
+        const MD5SUM: &'static str = "992ce8a1687cec8c8bd883ec73ca41d1";
+        const DEFINITION: &'static str = "string data\n";
+    }
+
+    #[test]
+    fn round_trips_recorded_headers() {
+        let requests: [&[u8]; 4] = [include_bytes!("datatests/pose_req.bin"),
+                                    include_bytes!("datatests/pose_res.bin"),
+                                    include_bytes!("datatests/string_req.bin"),
+                                    include_bytes!("datatests/pose_array_res.bin")];
+        for data in &requests {
+            let header: ConnectionHeader = from_slice(data).unwrap();
+            assert_eq!(data.to_vec(), to_vec(&header).unwrap());
+        }
+    }
+
+    #[test]
+    fn reads_typed_fields() {
+        let header: ConnectionHeader = from_slice(include_bytes!("datatests/pose_req.bin"))
+            .unwrap();
+        let keys = header.iter().map(|field| field.0.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["callerid", "md5sum", "message_definition", "tcp_nodelay", "topic",
+                        "type"],
+                   keys);
+        assert_eq!(Some("/rostopic_8282_1487460134332"), header.callerid());
+        assert_eq!(Some("e45d45a5a1ce597b249e23fb30fc871f"), header.md5sum());
+        assert_eq!(Some("/meow"), header.topic());
+        assert_eq!(Some("geometry_msgs/Pose"), header.ros_type());
+        assert!(header.message_definition().unwrap().starts_with("# A representation of pose"));
+        assert!(!header.tcp_nodelay());
+        assert!(!header.latching());
+        assert!(!header.persistent());
+        assert_eq!(None, header.service());
+        assert_eq!(None, header.error());
+        header.validate(Role::Subscriber).unwrap();
+
+        let header: ConnectionHeader = from_slice(include_bytes!("datatests/pose_res.bin"))
+            .unwrap();
+        assert!(header.latching());
+        header.validate(Role::Publisher).unwrap();
+    }
+
+    #[test]
+    fn keeps_field_order() {
+        let mut header = vec![("b", "1"), ("a", "2")].into_iter().collect::<ConnectionHeader>();
+        assert_eq!(Some("1".into()), header.insert("b", "3"));
+        assert_eq!(None, header.insert("c", "4"));
+        assert_eq!(Some("2".into()), header.remove("a"));
+        assert_eq!(None, header.remove("a"));
+        let fields = header.iter().cloned().collect::<Vec<_>>();
+        assert_eq!(vec![("b".into(), "3".into()), ("c".into(), "4".into())], fields);
+        assert_eq!(2, header.len());
+    }
+
+    #[test]
+    fn uses_last_repeated_fields() {
+        let data = to_vec(&["topic=/a", "type=std_msgs/String", "topic=/b"]).unwrap();
+        assert_eq!("/b", from_slice::<HashMap<String, String>>(&data).unwrap()["topic"]);
+        let mut header: ConnectionHeader = from_slice(&data).unwrap();
+        assert_eq!(Some("/b"), header.topic());
+        assert_eq!(3, header.len());
+        assert_eq!(data, to_vec(&header).unwrap());
+
+        assert_eq!(Some("/b".into()), header.insert("topic", "/c"));
+        let fields = header.iter().cloned().collect::<Vec<_>>();
+        assert_eq!(vec![("topic".into(), "/a".into()),
+                        ("type".into(), "std_msgs/String".into()),
+                        ("topic".into(), "/c".into())],
+                   fields);
+        assert_eq!(Some("/c".into()), header.remove("topic"));
+        assert_eq!(None, header.topic());
+        assert_eq!(1, header.len());
+    }
+
+    #[test]
+    fn validates_roles() {
+        let mut header = ConnectionHeader::for_message::<Data>();
+        header.validate(Role::Publisher).unwrap();
+        header.validate_message::<Data>().unwrap();
+        for &(role, field) in &[(Role::Subscriber, "callerid"),
+                                (Role::ServiceClient, "callerid"),
+                                (Role::ServiceServer, "callerid")] {
+            match *header.validate(role).unwrap_err().kind() {
+                ErrorKind::MissingHeaderField(ref missing) => assert_eq!(field, missing),
+                ref error => panic!("Missing header field error expected, got: {:?}", error),
+            }
+        }
+        header.insert("callerid", "/node");
+        header.validate(Role::ServiceServer).unwrap();
+        match *header.validate(Role::Subscriber).unwrap_err().kind() {
+            ErrorKind::MissingHeaderField(ref missing) => assert_eq!("topic", missing),
+            ref error => panic!("Missing header field error expected, got: {:?}", error),
+        }
+        header.insert("service", "/add_two_ints");
+        header.validate(Role::ServiceClient).unwrap();
+
+        header.insert("error", "Client wants topic /a to have datatype/md5sum [*/*]");
+        match *header.validate(Role::ServiceServer).unwrap_err().kind() {
+            ErrorKind::HeaderError(ref error) => assert!(error.starts_with("Client wants")),
+            ref error => panic!("Header error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn validates_message_types() {
+        let mut header = ConnectionHeader::for_message::<Data>();
+        header.insert("md5sum", "*");
+        header.validate_message::<Data>().unwrap();
+        header.insert("type", "std_msgs/Int8");
+        match *header.validate_message::<Data>().unwrap_err().kind() {
+            ErrorKind::MismatchedHeaderField(ref field, _, ref actual) => {
+                assert_eq!("type", field);
+                assert_eq!("std_msgs/Int8", actual);
+            }
+            ref error => panic!("Mismatched header field error expected, got: {:?}", error),
+        }
+    }
+}
@@ -63,11 +63,28 @@
 #![recursion_limit = "1024"]
 
 extern crate byteorder;
+#[cfg(feature = "async")]
+extern crate bytes;
+#[cfg(feature = "chrono")]
+extern crate chrono;
 #[macro_use]
 extern crate error_chain;
+#[cfg(all(test, feature = "async"))]
+extern crate futures;
+#[cfg(feature = "async")]
+extern crate futures_io;
+extern crate md5;
 #[macro_use]
 extern crate serde_derive;
+#[macro_use]
 extern crate serde;
+#[cfg(feature = "derive")]
+extern crate serde_rosmsg_derive;
+#[cfg(feature = "async")]
+extern crate tokio_util;
+// Generated code refers to this crate by name.
+#[cfg(any(test, feature = "msgs"))]
+extern crate self as serde_rosmsg;
 
 #[doc(inline)]
 pub use self::ser::*;
@@ -75,8 +92,32 @@ pub use self::ser::*;
 pub use self::de::*;
 #[doc(inline)]
 pub use self::error::Error;
+#[doc(inline)]
+pub use self::config::Config;
+#[doc(inline)]
+pub use self::message::{header_from_slice, header_to_vec, RosMessage};
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use serde_rosmsg_derive::*;
 
 pub mod ser;
 pub mod de;
+pub mod config;
+pub mod message;
+pub mod header;
+pub mod frame;
+pub mod service;
+#[cfg(feature = "async")]
+pub mod codec;
+pub mod msg;
+pub mod read;
 pub mod error;
+pub mod time;
+pub mod big_array;
+pub mod codegen;
+pub mod dynamic;
+#[cfg(feature = "msgs")]
+pub mod msgs;
+#[doc(hidden)]
+pub mod derive;
 mod datatests;
@@ -0,0 +1,290 @@
+//! Metadata of typed ROS messages.
+//!
+//! ROS nodes describe the type of the data they exchange in connection
+//! headers, using the name of the message type, its MD5 sum and its full
+//! definition. Types implementing `RosMessage` carry all three.
+//!
+//! With the `derive` feature enabled, the trait can be derived for structs,
+//! calculating all the metadata at compile time from the types of the fields.
+//! The name of the message type has to be given with the `rosmsg` attribute:
+//!
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+//! extern crate serde_rosmsg;
+//! #[macro_use]
+//! extern crate serde_derive;
+//! use serde_rosmsg::{time, RosMessage};
+//!
+//! #[derive(Serialize, Deserialize, RosMessage)]
+//! #[rosmsg(type = "geometry_msgs/Point")]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//!     z: f64,
+//! }
+//!
+//! #[derive(Serialize, Deserialize, RosMessage)]
+//! #[rosmsg(type = "my_msgs/Path", constant = "uint8 MAX_POINTS=100")]
+//! struct Path {
+//!     points: Vec<Point>,
+//!     stamp: time::Time,
+//! }
+//!
+//! fn main() {
+//!     assert_eq!(Point::MD5SUM, "4a842b65f413084dc2b10fb484ea7f17");
+//!     assert_eq!(Path::DEFINITION,
+//!                "uint8 MAX_POINTS=100\n\
+//!                 geometry_msgs/Point[] points\n\
+//!                 time stamp\n\n\
+//!                 ================================================================================\n\
+//!                 MSG: geometry_msgs/Point\n\
+//!                 float64 x\n\
+//!                 float64 y\n\
+//!                 float64 z\n");
+//! }
+//! ```
+//!
+//! Fields of types `bool`, `i8` to `i64`, `u8` to `u64`, `f32`, `f64` and
+//! `String` map to the matching built-in ROS types, and so do `time::Time`
+//! and `time::Duration`, as long as the path names the `time` module.
+//! `Vec<T>` maps to variable size arrays, and `[T; N]` to fixed size arrays.
+//! Fields of any other type are nested messages, which need to implement
+//! `RosMessage` themselves. Other built-in types, like `byte`, can be given
+//! with the `type` attribute on the field.
+//!
+//! # Connection headers
+//!
+//! Before exchanging messages, ROS nodes exchange connection headers, which
+//! are maps of `key=value` strings. The metadata of the message type goes into
+//! the `type`, `md5sum` and `message_definition` fields. `header_to_vec` adds
+//! these fields to a header, while `header_from_slice` checks that the type
+//! described by a received header matches the expected one. The `header`
+//! module provides a `ConnectionHeader` type with typed access to the fields.
+
+use super::de::from_slice;
+use super::error::{ErrorKind, Result};
+use super::ser::to_vec;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// A Rust type that represents a ROS message type.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate serde_rosmsg;
+/// # #[macro_use]
+/// # extern crate serde_derive;
+/// # use serde_rosmsg::RosMessage;
+/// # fn main() {
+/// #[derive(Serialize, Deserialize)]
+/// struct Data {
+///     data: String,
+/// }
+///
+/// impl RosMessage for Data {
+///     const ROS_TYPE: &'static str = "std_msgs/String";
+///     const MD5SUM: &'static str = "992ce8a1687cec8c8bd883ec73ca41d1";
+///     const DEFINITION: &'static str = "string data\n";
+/// }
+///
+/// assert_eq!(Data::ROS_TYPE, "std_msgs/String");
+/// # }
+/// ```
+pub trait RosMessage: Serialize + DeserializeOwned {
+    /// Full name of the message type, like `std_msgs/Header`.
+    const ROS_TYPE: &'static str;
+    /// MD5 sum of the message type, as calculated by `genmsg`.
+    const MD5SUM: &'static str;
+    /// Full definition of the message type, including all nested message
+    /// types, in the format produced by `gendeps --cat`.
+    const DEFINITION: &'static str;
+}
+
+/// Get the connection header fields that describe the message type.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate serde_rosmsg;
+/// # #[macro_use]
+/// # extern crate serde_derive;
+/// # use serde_rosmsg::RosMessage;
+/// # use serde_rosmsg::message::header_fields;
+/// # fn main() {
+/// #[derive(Serialize, Deserialize)]
+/// struct Data {
+///     data: String,
+/// }
+///
+/// impl RosMessage for Data {
+///     const ROS_TYPE: &'static str = "std_msgs/String";
+///     const MD5SUM: &'static str = "992ce8a1687cec8c8bd883ec73ca41d1";
+///     const DEFINITION: &'static str = "string data\n";
+/// }
+///
+/// let fields = header_fields::<Data>();
+/// assert_eq!(fields.len(), 3);
+/// assert_eq!(fields["type"], "std_msgs/String");
+/// assert_eq!(fields["md5sum"], "992ce8a1687cec8c8bd883ec73ca41d1");
+/// assert_eq!(fields["message_definition"], "string data\n");
+/// # }
+/// ```
+pub fn header_fields<T: RosMessage>() -> HashMap<String, String> {
+    let mut fields = HashMap::new();
+    fields.insert("type".into(), T::ROS_TYPE.into());
+    fields.insert("md5sum".into(), T::MD5SUM.into());
+    fields.insert("message_definition".into(), T::DEFINITION.into());
+    fields
+}
+
+/// Serialize a connection header for publishing messages of type `T`.
+///
+/// The header contains the given fields, like `callerid` and `topic`, and
+/// the fields describing the message type, which take precedence.
+pub fn header_to_vec<T: RosMessage>(fields: &HashMap<String, String>) -> Result<Vec<u8>> {
+    let mut header = fields.clone();
+    header.extend(header_fields::<T>());
+    to_vec(&header)
+}
+
+/// Check that a connection header describes messages of type `T`.
+///
+/// The `type` and `md5sum` fields need to match the message type. Either of
+/// them can also be a `*` wildcard, which tools like `rostopic` send when
+/// they accept any type.
+pub fn validate_header<T: RosMessage>(header: &HashMap<String, String>) -> Result<()> {
+    check_type_fields::<T, _>(|field| header.get(field).map(String::as_str))
+}
+
+/// Check the `type` and `md5sum` fields of a header, given the way to look
+/// them up.
+pub(crate) fn check_type_fields<'a, T, F>(get: F) -> Result<()>
+    where T: RosMessage,
+          F: Fn(&str) -> Option<&'a str>
+{
+    for &(field, expected) in &[("type", T::ROS_TYPE), ("md5sum", T::MD5SUM)] {
+        match get(field) {
+            None => bail!(ErrorKind::MissingHeaderField(field.into())),
+            Some(value) if value != expected && value != "*" => {
+                bail!(ErrorKind::MismatchedHeaderField(field.into(),
+                                                       expected.into(),
+                                                       value.into()))
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Deserialize a connection header and check that it describes messages of
+/// type `T`.
+pub fn header_from_slice<T: RosMessage>(bytes: &[u8]) -> Result<HashMap<String, String>> {
+    let header = from_slice(bytes)?;
+    validate_header::<T>(&header)?;
+    Ok(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Point {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Quaternion {
+        x: f64,
+        y: f64,
+        z: f64,
+        w: f64,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Pose {
+        position: Point,
+        orientation: Quaternion,
+    }
+
+    impl RosMessage for Pose {
+        const ROS_TYPE: &'static str = "geometry_msgs/Pose";
+        const MD5SUM: &'static str = "e45d45a5a1ce597b249e23fb30fc871f";
+        const DEFINITION: &'static str = include_str!("datatests/pose_message_definition.txt");
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Position {
+        position: Point,
+    }
+
+    impl RosMessage for Position {
+        const ROS_TYPE: &'static str = "my_msgs/Position";
+        const MD5SUM: &'static str = "e7bb0ef028c744b081acdc57743b11d8";
+        const DEFINITION: &'static str = "geometry_msgs/Point position\n";
+    }
+
+    #[test]
+    fn validates_received_headers() {
+        let header = header_from_slice::<Pose>(include_bytes!("datatests/pose_req.bin")).unwrap();
+        assert_eq!("/meow", header["topic"]);
+        let header = header_from_slice::<Pose>(include_bytes!("datatests/pose_res.bin")).unwrap();
+        assert_eq!("1", header["latching"]);
+    }
+
+    #[test]
+    fn rejects_mismatched_headers() {
+        let error = header_from_slice::<Position>(include_bytes!("datatests/pose_req.bin"))
+            .unwrap_err();
+        match *error.kind() {
+            ErrorKind::MismatchedHeaderField(ref field, ref expected, ref actual)
+                if field == "type" && expected == "my_msgs/Position" &&
+                   actual == "geometry_msgs/Pose" => {}
+            _ => panic!("Mismatched type error expected, got: {:?}", error),
+        }
+        let mut header = header_fields::<Pose>();
+        header.insert("md5sum".into(), "e7bb0ef028c744b081acdc57743b11d8".into());
+        let error = validate_header::<Pose>(&header).unwrap_err();
+        match *error.kind() {
+            ErrorKind::MismatchedHeaderField(ref field, _, _) if field == "md5sum" => {}
+            _ => panic!("Mismatched MD5 sum error expected, got: {:?}", error),
+        }
+        header.remove("md5sum");
+        let error = validate_header::<Pose>(&header).unwrap_err();
+        match *error.kind() {
+            ErrorKind::MissingHeaderField(ref field) if field == "md5sum" => {}
+            _ => panic!("Missing MD5 sum error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn accepts_wildcards() {
+        let mut header = HashMap::new();
+        header.insert(String::from("type"), String::from("*"));
+        header.insert(String::from("md5sum"), String::from("*"));
+        validate_header::<Pose>(&header).unwrap();
+        validate_header::<Position>(&header).unwrap();
+    }
+
+    #[test]
+    fn builds_headers() {
+        let mut fields = HashMap::new();
+        fields.insert(String::from("callerid"), String::from("/node"));
+        fields.insert(String::from("topic"), String::from("/meow"));
+        fields.insert(String::from("type"), String::from("*"));
+        let bytes = header_to_vec::<Pose>(&fields).unwrap();
+        let header = header_from_slice::<Pose>(&bytes).unwrap();
+        assert_eq!(5, header.len());
+        assert_eq!("/node", header["callerid"]);
+        assert_eq!("/meow", header["topic"]);
+        assert_eq!("geometry_msgs/Pose", header["type"]);
+        assert_eq!(Pose::DEFINITION, header["message_definition"]);
+        let expected = from_slice::<HashMap<String, String>>(include_bytes!("datatests/pose_req.bin"))
+            .unwrap();
+        assert_eq!(expected["message_definition"], header["message_definition"]);
+    }
+}
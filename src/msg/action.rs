@@ -0,0 +1,240 @@
+//! Action definitions, expanded into the messages used by actionlib.
+
+use super::super::error::Result;
+use super::{parse, MessageName, MessageSpec};
+
+/// Comment that `genaction` puts at the start of every generated message.
+const AUTOGEN: &str = "# ====== DO NOT MODIFY! AUTOGENERATED FROM AN ACTION DEFINITION ======\n";
+
+/// A parsed action definition.
+///
+/// Actions are described in `.action` files, containing the goal, result and
+/// feedback message definitions, delimited by `---` lines. Just like
+/// `genaction` does, the definition of action `Foo` is expanded into seven
+/// messages:
+///
+/// * `FooGoal`, `FooResult` and `FooFeedback`, defined by the action file
+/// * `FooActionGoal`, `FooActionResult` and `FooActionFeedback`, which wrap
+///   the messages above with a `Header` and an `actionlib_msgs/GoalID` or
+///   `actionlib_msgs/GoalStatus`
+/// * `FooAction`, which contains the three wrapper messages
+///
+/// The text of every generated message matches the text produced by
+/// `genaction`, so it can be used for computing MD5 sums and full message
+/// definitions.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_rosmsg::msg::ActionSpec;
+/// let spec = ActionSpec::parse("actionlib_tutorials/Fibonacci",
+///                              "int32 order\n---\nint32[] sequence\n---\nint32[] sequence\n")
+///     .unwrap();
+/// assert_eq!(spec.goal.fields[0].name, "order");
+/// assert_eq!(spec.action_goal.name.to_string(), "actionlib_tutorials/FibonacciActionGoal");
+/// assert_eq!(spec.action_goal.fields[1].field_type.to_string(), "actionlib_msgs/GoalID");
+/// assert_eq!(spec.action_goal.fields[2].field_type.to_string(),
+///            "actionlib_tutorials/FibonacciGoal");
+/// assert_eq!(spec.messages().len(), 7);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActionSpec {
+    /// Full name of the action type.
+    pub name: MessageName,
+    /// The `FooGoal` message, sent to request the action.
+    pub goal: MessageSpec,
+    /// The `FooResult` message, sent once the action is done.
+    pub result: MessageSpec,
+    /// The `FooFeedback` message, sent while the action is in progress.
+    pub feedback: MessageSpec,
+    /// The `FooAction` message, containing all wrapper messages.
+    pub action: MessageSpec,
+    /// The `FooActionGoal` message, published on the `goal` topic.
+    pub action_goal: MessageSpec,
+    /// The `FooActionResult` message, published on the `result` topic.
+    pub action_result: MessageSpec,
+    /// The `FooActionFeedback` message, published on the `feedback` topic.
+    pub action_feedback: MessageSpec,
+    /// The definition that the action was parsed from.
+    pub text: String,
+}
+
+impl ActionSpec {
+    /// Parse an action definition, given the action's full name.
+    ///
+    /// Errors contain the line and column of the problematic definition,
+    /// counted from the start of the whole action definition.
+    pub fn parse(name: &str, text: &str) -> Result<Self> {
+        let name = MessageName::parse(name)?;
+        let sections = parse::sections(text, 3)?;
+        let message_name = |suffix: &str| {
+            MessageName::new(name.package.clone(), format!("{}{}", name.name, suffix))
+        };
+        // Like genaction, every line of a section is terminated by a new line
+        let section = |index: usize| -> Result<MessageSpec> {
+            let suffix = ["Goal", "Result", "Feedback"][index];
+            let mut text = format!("{}{}", AUTOGEN, sections[index].text);
+            if index == 2 {
+                text.push('\n');
+            }
+            parse::message_at(message_name(suffix), &text, sections[index].first_line - 1)
+        };
+        let wrapper = |suffix: &str, body: String| {
+            parse::message(message_name(suffix), &format!("{}\n{}", AUTOGEN, body))
+        };
+        Ok(ActionSpec {
+            goal: section(0)?,
+            result: section(1)?,
+            feedback: section(2)?,
+            action: wrapper("Action",
+                            format!("{0}ActionGoal action_goal\n\
+                                     {0}ActionResult action_result\n\
+                                     {0}ActionFeedback action_feedback\n",
+                                    name.name))?,
+            action_goal: wrapper("ActionGoal",
+                                 format!("Header header\n\
+                                          actionlib_msgs/GoalID goal_id\n\
+                                          {}Goal goal\n",
+                                         name.name))?,
+            action_result: wrapper("ActionResult",
+                                   format!("Header header\n\
+                                            actionlib_msgs/GoalStatus status\n\
+                                            {}Result result\n",
+                                           name.name))?,
+            action_feedback: wrapper("ActionFeedback",
+                                     format!("Header header\n\
+                                              actionlib_msgs/GoalStatus status\n\
+                                              {}Feedback feedback\n",
+                                             name.name))?,
+            name,
+            text: text.into(),
+        })
+    }
+
+    /// Get all seven messages generated from the action.
+    pub fn messages(&self) -> Vec<&MessageSpec> {
+        vec![&self.action,
+             &self.action_goal,
+             &self.action_result,
+             &self.action_feedback,
+             &self.goal,
+             &self.result,
+             &self.feedback]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::error::ErrorKind;
+
+    static FIBONACCI: &str = "#goal definition\n\
+                              int32 order\n\
+                              ---\n\
+                              #result definition\n\
+                              int32[] sequence\n\
+                              ---\n\
+                              #feedback\n\
+                              int32[] sequence\n";
+
+    #[test]
+    fn names_generated_messages() {
+        let spec = ActionSpec::parse("actionlib_tutorials/Fibonacci", FIBONACCI).unwrap();
+        let names = spec.messages()
+            .into_iter()
+            .map(|message| message.name.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["actionlib_tutorials/FibonacciAction",
+                        "actionlib_tutorials/FibonacciActionGoal",
+                        "actionlib_tutorials/FibonacciActionResult",
+                        "actionlib_tutorials/FibonacciActionFeedback",
+                        "actionlib_tutorials/FibonacciGoal",
+                        "actionlib_tutorials/FibonacciResult",
+                        "actionlib_tutorials/FibonacciFeedback"],
+                   names);
+    }
+
+    #[test]
+    fn generates_text_like_genaction() {
+        let spec = ActionSpec::parse("actionlib_tutorials/Fibonacci", FIBONACCI).unwrap();
+        assert_eq!("# ====== DO NOT MODIFY! AUTOGENERATED FROM AN ACTION DEFINITION ======\n\
+                    #goal definition\n\
+                    int32 order\n",
+                   spec.goal.text);
+        assert_eq!("# ====== DO NOT MODIFY! AUTOGENERATED FROM AN ACTION DEFINITION ======\n\
+                    #result definition\n\
+                    int32[] sequence\n",
+                   spec.result.text);
+        assert_eq!("# ====== DO NOT MODIFY! AUTOGENERATED FROM AN ACTION DEFINITION ======\n\
+                    #feedback\n\
+                    int32[] sequence\n\n",
+                   spec.feedback.text);
+        assert_eq!("# ====== DO NOT MODIFY! AUTOGENERATED FROM AN ACTION DEFINITION ======\n\
+                    \n\
+                    FibonacciActionGoal action_goal\n\
+                    FibonacciActionResult action_result\n\
+                    FibonacciActionFeedback action_feedback\n",
+                   spec.action.text);
+        assert_eq!("# ====== DO NOT MODIFY! AUTOGENERATED FROM AN ACTION DEFINITION ======\n\
+                    \n\
+                    Header header\n\
+                    actionlib_msgs/GoalID goal_id\n\
+                    FibonacciGoal goal\n",
+                   spec.action_goal.text);
+        assert_eq!("# ====== DO NOT MODIFY! AUTOGENERATED FROM AN ACTION DEFINITION ======\n\
+                    \n\
+                    Header header\n\
+                    actionlib_msgs/GoalStatus status\n\
+                    FibonacciResult result\n",
+                   spec.action_result.text);
+        assert_eq!("# ====== DO NOT MODIFY! AUTOGENERATED FROM AN ACTION DEFINITION ======\n\
+                    \n\
+                    Header header\n\
+                    actionlib_msgs/GoalStatus status\n\
+                    FibonacciFeedback feedback\n",
+                   spec.action_feedback.text);
+    }
+
+    #[test]
+    fn resolves_wrapper_field_types() {
+        let spec = ActionSpec::parse("actionlib_tutorials/Fibonacci", FIBONACCI).unwrap();
+        let types = |message: &MessageSpec| {
+            message.fields
+                .iter()
+                .map(|field| format!("{} {}", field.field_type, field.name))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["actionlib_tutorials/FibonacciActionGoal action_goal",
+                        "actionlib_tutorials/FibonacciActionResult action_result",
+                        "actionlib_tutorials/FibonacciActionFeedback action_feedback"],
+                   types(&spec.action));
+        assert_eq!(vec!["std_msgs/Header header",
+                        "actionlib_msgs/GoalStatus status",
+                        "actionlib_tutorials/FibonacciResult result"],
+                   types(&spec.action_result));
+        assert!(spec.action_feedback.has_header());
+        assert_eq!(vec!["int32[] sequence"], types(&spec.feedback));
+    }
+
+    #[test]
+    fn reports_positions_in_whole_file() {
+        let error = ActionSpec::parse("my_msgs/Do", "int32 a\n---\n---\nint32 b\nint32 c d\n")
+            .unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadMessageDefinition(5, 9, _) => {}
+            _ => panic!("Bad definition at 5:9 expected, got: {:?}", error),
+        }
+        let error = ActionSpec::parse("my_msgs/Do", "int32 a d\n---\n---\n").unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadMessageDefinition(1, 9, _) => {}
+            _ => panic!("Bad definition at 1:9 expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn requires_exactly_two_delimiters() {
+        ActionSpec::parse("my_msgs/Do", "int32 a\n---\nint32 b\n").unwrap_err();
+        ActionSpec::parse("my_msgs/Do", "---\n---\n---\n").unwrap_err();
+        ActionSpec::parse("my_msgs/Do", "---\n---\n").unwrap();
+    }
+}
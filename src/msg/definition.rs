@@ -0,0 +1,256 @@
+//! Full message definitions, as sent in connection headers.
+//!
+//! Publishers send the definition of their message type in the
+//! `message_definition` field of the connection header, so that tools like
+//! `rostopic echo` and `rosbag` can decode messages without having the
+//! definitions installed. The full definition is the text of the message
+//! followed by the texts of all the nested message types, in the format
+//! produced by `gendeps --cat`:
+//!
+//! ```text
+//! Header header
+//! Pose[] poses
+//!
+//! ================================================================================
+//! MSG: std_msgs/Header
+//! uint32 seq
+//! ...
+//! ```
+
+use super::super::error::{ErrorKind, Result};
+use super::{parse, MessageName, MessageSpec, Resolver};
+use std::collections::HashMap;
+
+/// Prefix of the line that introduces a nested message type.
+static MESSAGE_PREFIX: &str = "MSG: ";
+
+fn separator() -> String {
+    format!("\n{}\n", "=".repeat(80))
+}
+
+fn collect<'a, R>(spec: &'a MessageSpec,
+                  resolver: &'a R,
+                  parents: &mut Vec<&'a MessageName>,
+                  specs: &mut Vec<&'a MessageSpec>)
+                  -> Result<()>
+    where R: Resolver + ?Sized
+{
+    if parents.contains(&&spec.name) {
+        bail!(ErrorKind::RecursiveMessageType(spec.name.to_string()));
+    }
+    parents.push(&spec.name);
+    for name in spec.dependencies() {
+        let nested = match resolver.resolve(name) {
+            Some(nested) => nested,
+            None => bail!(ErrorKind::MissingMessageType(name.to_string())),
+        };
+        if !specs.iter().any(|item| item.name == *name) {
+            specs.push(nested);
+        }
+        collect(nested, resolver, parents, specs)?;
+    }
+    parents.pop();
+    Ok(())
+}
+
+impl MessageSpec {
+    /// Get the definitions of all nested message types, recursively.
+    ///
+    /// Each type is listed once, in the order used by full message
+    /// definitions: depth first, in order of field declaration.
+    pub fn all_dependencies<'a, R>(&'a self, resolver: &'a R) -> Result<Vec<&'a MessageSpec>>
+        where R: Resolver + ?Sized
+    {
+        let mut specs = Vec::new();
+        collect(self, resolver, &mut Vec::new(), &mut specs)?;
+        Ok(specs)
+    }
+
+    /// Generate the full definition of the message type.
+    ///
+    /// The `resolver` has to provide the definitions of all nested message
+    /// types. The result matches the output of `gendeps --cat` byte for
+    /// byte, which is what `roscpp` and `rospy` send in the
+    /// `message_definition` field of connection headers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::msg::MessageSpec;
+    /// # use std::collections::HashMap;
+    /// let mut registry = HashMap::new();
+    /// let point = MessageSpec::parse("geometry_msgs/Point", "float64 x\nfloat64 y\n").unwrap();
+    /// registry.insert(point.name.clone(), point);
+    ///
+    /// let spec = MessageSpec::parse("my_msgs/Path", "geometry_msgs/Point[] points\n").unwrap();
+    /// assert_eq!(spec.full_definition(&registry).unwrap(),
+    ///            format!("geometry_msgs/Point[] points\n\n{}\n\
+    ///                     MSG: geometry_msgs/Point\n\
+    ///                     float64 x\nfloat64 y\n",
+    ///                    "=".repeat(80)));
+    /// ```
+    pub fn full_definition<R>(&self, resolver: &R) -> Result<String>
+        where R: Resolver + ?Sized
+    {
+        let separator = separator();
+        let mut text = self.text.clone();
+        for spec in self.all_dependencies(resolver)? {
+            text.push_str(&separator);
+            text.push_str(MESSAGE_PREFIX);
+            text.push_str(&spec.name.to_string());
+            text.push('\n');
+            text.push_str(&spec.text);
+        }
+        Ok(text)
+    }
+}
+
+/// Parse a full message definition into a registry of message types.
+///
+/// The registry contains the message type itself, under the given `name`,
+/// and all the nested message types listed in the definition. Errors contain
+/// the line and column of the problematic definition, counted from the start
+/// of the whole text.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_rosmsg::msg::{definition, MessageName};
+/// let text = format!("Point[] points\n\n{}\nMSG: my_msgs/Point\nfloat64 x\nfloat64 y\n",
+///                    "=".repeat(80));
+/// let registry = definition::parse("my_msgs/Path", &text).unwrap();
+/// let path = &registry[&MessageName::new("my_msgs", "Path")];
+/// assert_eq!(path.full_definition(&registry).unwrap(), text);
+/// let point = &registry[&MessageName::new("my_msgs", "Point")];
+/// assert_eq!(point.fields.len(), 2);
+/// ```
+pub fn parse(name: &str, text: &str) -> Result<HashMap<MessageName, MessageSpec>> {
+    let separator = separator();
+    let mut registry = HashMap::new();
+    let mut sections = text.split(separator.as_str());
+    let mut first_line = 1;
+    let mut section = sections.next().unwrap_or("");
+    let mut name = MessageName::parse(name)?;
+    loop {
+        let spec = parse::message_at(name.clone(), section, first_line)?;
+        // Every text is followed by a new line and the separator line
+        first_line += section.matches('\n').count() + 2;
+        registry.insert(name, spec);
+        section = match sections.next() {
+            Some(section) => section,
+            None => break,
+        };
+        let (heading, rest) = match section.find('\n') {
+            Some(index) => (&section[..index], &section[index + 1..]),
+            None => (section, ""),
+        };
+        if !heading.starts_with(MESSAGE_PREFIX) {
+            bail!(ErrorKind::BadMessageDefinition(first_line,
+                                                  1,
+                                                  format!("expected \"{}\" line",
+                                                          MESSAGE_PREFIX.trim())));
+        }
+        name = MessageName::parse(heading[MESSAGE_PREFIX.len()..].trim())?;
+        first_line += 1;
+        section = rest;
+    }
+    Ok(registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static POSE: &str = include_str!("../datatests/pose_message_definition.txt");
+    static POSE_ARRAY: &str = include_str!("../datatests/pose_array_message_definition.txt");
+    static POSE_WITH_COVARIANCE: &str =
+        include_str!("../datatests/pose_with_covariance_message_definition.txt");
+
+    fn names(specs: &[&MessageSpec]) -> Vec<String> {
+        specs.iter().map(|spec| spec.name.to_string()).collect()
+    }
+
+    #[test]
+    fn regenerates_definitions_byte_for_byte() {
+        for &(name, text) in &[("geometry_msgs/Pose", POSE),
+                               ("geometry_msgs/PoseArray", POSE_ARRAY),
+                               ("geometry_msgs/PoseWithCovariance", POSE_WITH_COVARIANCE)] {
+            let registry = parse(name, text).unwrap();
+            let spec = &registry[&MessageName::parse(name).unwrap()];
+            assert_eq!(text, spec.full_definition(&registry).unwrap());
+        }
+    }
+
+    #[test]
+    fn combines_definitions_from_any_registry() {
+        let mut registry = parse("geometry_msgs/PoseArray", POSE_ARRAY).unwrap();
+        let pose = parse("geometry_msgs/PoseWithCovariance", POSE_WITH_COVARIANCE).unwrap();
+        registry.extend(pose);
+        let spec = &registry[&MessageName::new("geometry_msgs", "Pose")];
+        assert_eq!(POSE, spec.full_definition(&registry).unwrap());
+    }
+
+    #[test]
+    fn lists_dependencies_depth_first() {
+        let registry = parse("geometry_msgs/PoseArray", POSE_ARRAY).unwrap();
+        assert_eq!(5, registry.len());
+        let spec = &registry[&MessageName::new("geometry_msgs", "PoseArray")];
+        assert_eq!(vec!["std_msgs/Header",
+                        "geometry_msgs/Pose",
+                        "geometry_msgs/Point",
+                        "geometry_msgs/Quaternion"],
+                   names(&spec.all_dependencies(&registry).unwrap()));
+    }
+
+    #[test]
+    fn lists_shared_dependencies_once() {
+        let mut registry = HashMap::new();
+        for &(name, text) in &[("my_msgs/Point", "float64 x\n"),
+                               ("my_msgs/Line", "Point a\nPoint b\n"),
+                               ("my_msgs/Shape", "Line[] lines\nPoint center\nLine axis\n")] {
+            let spec = MessageSpec::parse(name, text).unwrap();
+            registry.insert(spec.name.clone(), spec);
+        }
+        let spec = &registry[&MessageName::new("my_msgs", "Shape")];
+        assert_eq!(vec!["my_msgs/Line", "my_msgs/Point"],
+                   names(&spec.all_dependencies(&registry).unwrap()));
+        assert_eq!(format!("Line[] lines\nPoint center\nLine axis\n\n{0}\n\
+                            MSG: my_msgs/Line\nPoint a\nPoint b\n\n{0}\n\
+                            MSG: my_msgs/Point\nfloat64 x\n",
+                           "=".repeat(80)),
+                   spec.full_definition(&registry).unwrap());
+    }
+
+    #[test]
+    fn keeps_definitions_without_dependencies() {
+        let registry = parse("std_msgs/String", "string data\n").unwrap();
+        let spec = &registry[&MessageName::new("std_msgs", "String")];
+        assert_eq!("string data\n", spec.full_definition(&registry).unwrap());
+    }
+
+    #[test]
+    fn reports_missing_types() {
+        let spec = MessageSpec::parse("my_msgs/Foo", "Bar bar\n").unwrap();
+        let error = spec.full_definition(&HashMap::new()).unwrap_err();
+        match *error.kind() {
+            ErrorKind::MissingMessageType(ref name) if name == "my_msgs/Bar" => {}
+            _ => panic!("Missing message type error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn reports_positions_in_whole_text() {
+        let text = format!("Bar bar\n\n{0}\nMSG: my_msgs/Bar\nint32 a\nint32 b c\n", "=".repeat(80));
+        let error = parse("my_msgs/Foo", &text).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadMessageDefinition(6, 9, _) => {}
+            _ => panic!("Bad definition at 6:9 expected, got: {:?}", error),
+        }
+        let text = format!("Bar bar\n\n{0}\nmy_msgs/Bar\nint32 a\n", "=".repeat(80));
+        let error = parse("my_msgs/Foo", &text).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadMessageDefinition(4, 1, _) => {}
+            _ => panic!("Bad definition at 4:1 expected, got: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! MD5 sums of message and service types.
+//!
+//! ROS nodes compare MD5 sums of types when connecting, to make sure that
+//! both sides agree on the layout of the data. The sum is calculated from a
+//! canonical version of the definition, which leaves out comments and
+//! formatting, and replaces nested message types with their own MD5 sums.
+
+use super::super::error::{ErrorKind, Result};
+use super::{BaseType, MessageName, MessageSpec, Resolver, ServiceSpec};
+use std::fmt::Write;
+
+fn digest(text: &str) -> String {
+    format!("{:x}", ::md5::compute(text))
+}
+
+fn md5_text<'a, R>(spec: &'a MessageSpec,
+                   resolver: &'a R,
+                   parents: &mut Vec<&'a MessageName>)
+                   -> Result<String>
+    where R: Resolver + ?Sized
+{
+    if parents.contains(&&spec.name) {
+        bail!(ErrorKind::RecursiveMessageType(spec.name.to_string()));
+    }
+    parents.push(&spec.name);
+    let mut text = String::new();
+    for constant in &spec.constants {
+        let _ = writeln!(text,
+                         "{} {}={}",
+                         constant.constant_type,
+                         constant.name,
+                         constant.value_text);
+    }
+    for field in &spec.fields {
+        match field.field_type.base {
+            BaseType::Primitive(_) => {
+                let _ = writeln!(text, "{} {}", field.field_type, field.name);
+            }
+            BaseType::Message(ref name) => {
+                let nested = match resolver.resolve(name) {
+                    Some(nested) => nested,
+                    None => bail!(ErrorKind::MissingMessageType(name.to_string())),
+                };
+                let nested_text = md5_text(nested, resolver, parents)?;
+                let _ = writeln!(text, "{} {}", digest(&nested_text), field.name);
+            }
+        }
+    }
+    parents.pop();
+    // The last line is not terminated by a new line
+    text.pop();
+    Ok(text)
+}
+
+impl MessageSpec {
+    /// Get the canonical text used for calculating the MD5 sum.
+    ///
+    /// Constants are listed first, followed by fields, with nested message
+    /// types replaced by their MD5 sums. The `resolver` has to provide the
+    /// definitions of all nested message types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::msg::{MessageName, MessageSpec};
+    /// # use std::collections::HashMap;
+    /// let mut registry = HashMap::new();
+    /// let point = MessageSpec::parse("geometry_msgs/Point",
+    ///                                "float64 x\nfloat64 y\nfloat64 z\n").unwrap();
+    /// registry.insert(point.name.clone(), point);
+    ///
+    /// let spec = MessageSpec::parse("my_msgs/Path",
+    ///                               "# A path\ngeometry_msgs/Point[] points\nuint8 A=1\n")
+    ///     .unwrap();
+    /// assert_eq!(spec.md5_text(&registry).unwrap(),
+    ///            "uint8 A=1\n4a842b65f413084dc2b10fb484ea7f17 points");
+    /// ```
+    pub fn md5_text<R>(&self, resolver: &R) -> Result<String>
+        where R: Resolver + ?Sized
+    {
+        md5_text(self, resolver, &mut Vec::new())
+    }
+
+    /// Calculate the MD5 sum of the message type, as a hexadecimal string.
+    ///
+    /// The `resolver` has to provide the definitions of all nested message
+    /// types. The result matches the output of `rosmsg md5`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::msg::MessageSpec;
+    /// # use std::collections::HashMap;
+    /// let spec = MessageSpec::parse("std_msgs/String", "string data\n").unwrap();
+    /// assert_eq!(spec.md5sum(&HashMap::new()).unwrap(),
+    ///            "992ce8a1687cec8c8bd883ec73ca41d1");
+    /// ```
+    pub fn md5sum<R>(&self, resolver: &R) -> Result<String>
+        where R: Resolver + ?Sized
+    {
+        Ok(digest(&self.md5_text(resolver)?))
+    }
+}
+
+impl ServiceSpec {
+    /// Get the canonical text used for calculating the MD5 sum.
+    ///
+    /// This is the canonical text of the request, directly followed by the
+    /// canonical text of the response.
+    pub fn md5_text<R>(&self, resolver: &R) -> Result<String>
+        where R: Resolver + ?Sized
+    {
+        Ok(self.request.md5_text(resolver)? + &self.response.md5_text(resolver)?)
+    }
+
+    /// Calculate the MD5 sum of the service type, as a hexadecimal string.
+    ///
+    /// The `resolver` has to provide the definitions of all nested message
+    /// types. The result matches the output of `rossrv md5`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::msg::ServiceSpec;
+    /// # use std::collections::HashMap;
+    /// let spec = ServiceSpec::parse("std_srvs/SetBool",
+    ///                               "bool data\n---\nbool success\nstring message\n")
+    ///     .unwrap();
+    /// assert_eq!(spec.md5sum(&HashMap::new()).unwrap(),
+    ///            "09fb03525b03e7ea1fd3992bafd87e16");
+    /// ```
+    pub fn md5sum<R>(&self, resolver: &R) -> Result<String>
+        where R: Resolver + ?Sized
+    {
+        Ok(digest(&self.md5_text(resolver)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    static HEADER: &str = "uint32 seq\ntime stamp\nstring frame_id\n";
+    static POINT: &str = "float64 x\nfloat64 y\nfloat64 z\n";
+    static QUATERNION: &str = "float64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    static POSE: &str = "Point position\nQuaternion orientation\n";
+
+    fn registry() -> HashMap<MessageName, MessageSpec> {
+        let mut registry = HashMap::new();
+        for &(name, text) in &[("std_msgs/Header", HEADER),
+                               ("geometry_msgs/Point", POINT),
+                               ("geometry_msgs/Quaternion", QUATERNION),
+                               ("geometry_msgs/Pose", POSE)] {
+            let spec = MessageSpec::parse(name, text).unwrap();
+            registry.insert(spec.name.clone(), spec);
+        }
+        registry
+    }
+
+    fn md5sum(name: &str, text: &str) -> String {
+        MessageSpec::parse(name, text).unwrap().md5sum(&registry()).unwrap()
+    }
+
+    #[test]
+    fn matches_primitive_messages() {
+        assert_eq!("992ce8a1687cec8c8bd883ec73ca41d1",
+                   md5sum("std_msgs/String", "string data"));
+        assert_eq!("2176decaecbce78abc3b96ef049fabed",
+                   md5sum("std_msgs/Header", HEADER));
+        assert_eq!("4a842b65f413084dc2b10fb484ea7f17",
+                   md5sum("geometry_msgs/Point", POINT));
+        assert_eq!("d41d8cd98f00b204e9800998ecf8427e",
+                   md5sum("std_msgs/Empty", "# Nothing here\n"));
+    }
+
+    #[test]
+    fn matches_nested_messages() {
+        assert_eq!("e45d45a5a1ce597b249e23fb30fc871f",
+                   md5sum("geometry_msgs/Pose", POSE));
+        assert_eq!("916c28c5764443f268b296bb671b9d97",
+                   md5sum("geometry_msgs/PoseArray", "Header header\nPose[] poses\n"));
+        assert_eq!("c23e848cf1b7533a8d7c259073a97e6f",
+                   md5sum("geometry_msgs/PoseWithCovariance",
+                          "Pose pose\nfloat64[36] covariance\n"));
+    }
+
+    #[test]
+    fn ignores_comments_and_formatting() {
+        assert_eq!(md5sum("std_msgs/String", "string data"),
+                   md5sum("std_msgs/String", "# Comment\n\n  string   data  # Data\n"));
+    }
+
+    #[test]
+    fn lists_constants_first() {
+        let spec = MessageSpec::parse("my_msgs/Status",
+                                      "uint8 level\nuint8 OK=0\nstring S= a # b \n")
+            .unwrap();
+        assert_eq!("uint8 OK=0\nstring S=a # b\nuint8 level",
+                   spec.md5_text(&registry()).unwrap());
+    }
+
+    #[test]
+    fn matches_services() {
+        let spec = ServiceSpec::parse("roscpp_tutorials/TwoInts",
+                                      "int64 a\nint64 b\n---\nint64 sum\n")
+            .unwrap();
+        assert_eq!("int64 a\nint64 bint64 sum", spec.md5_text(&registry()).unwrap());
+        assert_eq!("6a2e34150c00229791cc89ff309fff21",
+                   spec.md5sum(&registry()).unwrap());
+        let spec = ServiceSpec::parse("std_srvs/Empty", "---\n").unwrap();
+        assert_eq!("d41d8cd98f00b204e9800998ecf8427e",
+                   spec.md5sum(&registry()).unwrap());
+    }
+
+    #[test]
+    fn reports_missing_types() {
+        let spec = MessageSpec::parse("my_msgs/Foo", "Bar bar\n").unwrap();
+        let error = spec.md5sum(&registry()).unwrap_err();
+        match *error.kind() {
+            ErrorKind::MissingMessageType(ref name) if name == "my_msgs/Bar" => {}
+            _ => panic!("Missing message type error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn reports_recursive_types() {
+        let mut registry = registry();
+        let spec = MessageSpec::parse("my_msgs/Foo", "Bar bar\n").unwrap();
+        registry.insert(spec.name.clone(), spec.clone());
+        let bar = MessageSpec::parse("my_msgs/Bar", "Foo[] foo\n").unwrap();
+        registry.insert(bar.name.clone(), bar);
+        let error = spec.md5sum(&registry).unwrap_err();
+        match *error.kind() {
+            ErrorKind::RecursiveMessageType(ref name) if name == "my_msgs/Foo" => {}
+            _ => panic!("Recursive message type error expected, got: {:?}", error),
+        }
+    }
+}
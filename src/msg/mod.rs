@@ -0,0 +1,348 @@
+//! Parse ROS message definitions into a structured representation.
+//!
+//! Message types are described in `.msg` files, following ROS's
+//! [specification](http://wiki.ros.org/msg#Message_Description_Specification).
+//! Each non-empty line contains either a field or a constant, and anything
+//! after a `#` is a comment:
+//!
+//! ```text
+//! uint8 STATUS_OK=0      # Constants are primitive types with a value
+//! Header header          # Resolves to std_msgs/Header
+//! geometry_msgs/Pose[] poses
+//! float64[9] covariance  # Fixed size array
+//! ```
+//!
+//! Type names without a package refer to the package of the message itself,
+//! except for `Header`, which always refers to `std_msgs/Header`.
+//!
+//! Service definitions from `.srv` files are parsed into a `ServiceSpec`, and
+//! action definitions from `.action` files are parsed into an `ActionSpec`.
+//! Their MD5 sums can be calculated with the help of a `Resolver`, which
+//! provides the definitions of nested message types. The same goes for full
+//! message definitions, as sent in connection headers, which are handled by
+//! the `definition` module.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde_rosmsg::msg::{ArrayLength, BaseType, MessageSpec, PrimitiveType};
+//! let spec = MessageSpec::parse("geometry_msgs/PoseArray",
+//!                               "Header header\nPose[] poses\n").unwrap();
+//! assert_eq!(spec.fields.len(), 2);
+//! assert_eq!(spec.fields[0].field_type.to_string(), "std_msgs/Header");
+//! assert_eq!(spec.fields[1].field_type.to_string(), "geometry_msgs/Pose[]");
+//! assert_eq!(spec.fields[1].field_type.array, Some(ArrayLength::Variable));
+//! ```
+
+use super::error::Result;
+use std::collections::HashMap;
+use std::fmt;
+
+#[doc(inline)]
+pub use self::action::ActionSpec;
+#[doc(inline)]
+pub use self::srv::ServiceSpec;
+
+pub mod action;
+pub mod definition;
+pub mod md5sum;
+mod parse;
+pub mod srv;
+
+/// Full name of a message type, made of its package and type name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MessageName {
+    /// Name of the package containing the message, like `std_msgs`.
+    pub package: String,
+    /// Name of the message inside the package, like `Header`.
+    pub name: String,
+}
+
+impl MessageName {
+    /// Create a message name from its package and type name.
+    pub fn new<P: Into<String>, N: Into<String>>(package: P, name: N) -> Self {
+        MessageName {
+            package: package.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Parse a full message name in the `package/Name` format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::msg::MessageName;
+    /// let name = MessageName::parse("std_msgs/Header").unwrap();
+    /// assert_eq!(name, MessageName::new("std_msgs", "Header"));
+    /// assert!(MessageName::parse("Header").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Self> {
+        parse::message_name(value)
+    }
+}
+
+impl fmt::Display for MessageName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.package, self.name)
+    }
+}
+
+/// Built-in types of ROS messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PrimitiveType {
+    /// `bool`
+    Bool,
+    /// `int8`
+    Int8,
+    /// `uint8`
+    UInt8,
+    /// `int16`
+    Int16,
+    /// `uint16`
+    UInt16,
+    /// `int32`
+    Int32,
+    /// `uint32`
+    UInt32,
+    /// `int64`
+    Int64,
+    /// `uint64`
+    UInt64,
+    /// `float32`
+    Float32,
+    /// `float64`
+    Float64,
+    /// `string`
+    String,
+    /// `time`, made of `uint32` seconds and nanoseconds
+    Time,
+    /// `duration`, made of `int32` seconds and nanoseconds
+    Duration,
+    /// `byte`, a deprecated alias for `int8`
+    Byte,
+    /// `char`, a deprecated alias for `uint8`
+    Char,
+}
+
+impl PrimitiveType {
+    /// Find the built-in type with the given name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "bool" => PrimitiveType::Bool,
+            "int8" => PrimitiveType::Int8,
+            "uint8" => PrimitiveType::UInt8,
+            "int16" => PrimitiveType::Int16,
+            "uint16" => PrimitiveType::UInt16,
+            "int32" => PrimitiveType::Int32,
+            "uint32" => PrimitiveType::UInt32,
+            "int64" => PrimitiveType::Int64,
+            "uint64" => PrimitiveType::UInt64,
+            "float32" => PrimitiveType::Float32,
+            "float64" => PrimitiveType::Float64,
+            "string" => PrimitiveType::String,
+            "time" => PrimitiveType::Time,
+            "duration" => PrimitiveType::Duration,
+            "byte" => PrimitiveType::Byte,
+            "char" => PrimitiveType::Char,
+            _ => return None,
+        })
+    }
+
+    /// Get the name of the type, as used in message definitions.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Int8 => "int8",
+            PrimitiveType::UInt8 => "uint8",
+            PrimitiveType::Int16 => "int16",
+            PrimitiveType::UInt16 => "uint16",
+            PrimitiveType::Int32 => "int32",
+            PrimitiveType::UInt32 => "uint32",
+            PrimitiveType::Int64 => "int64",
+            PrimitiveType::UInt64 => "uint64",
+            PrimitiveType::Float32 => "float32",
+            PrimitiveType::Float64 => "float64",
+            PrimitiveType::String => "string",
+            PrimitiveType::Time => "time",
+            PrimitiveType::Duration => "duration",
+            PrimitiveType::Byte => "byte",
+            PrimitiveType::Char => "char",
+        }
+    }
+}
+
+impl fmt::Display for PrimitiveType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Type of a single field item, without the array annotation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BaseType {
+    /// A built-in type.
+    Primitive(PrimitiveType),
+    /// A nested message.
+    Message(MessageName),
+}
+
+impl fmt::Display for BaseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BaseType::Primitive(ref v) => v.fmt(f),
+            BaseType::Message(ref v) => v.fmt(f),
+        }
+    }
+}
+
+/// Length of an array field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArrayLength {
+    /// Array of a fixed size, `T[N]`, encoded without a length prefix.
+    Fixed(usize),
+    /// Array of a variable size, `T[]`, encoded with a length prefix.
+    Variable,
+}
+
+/// Type of a message field.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldType {
+    /// Type of the field, or of its items if it's an array.
+    pub base: BaseType,
+    /// Array annotation of the field, if it's an array.
+    pub array: Option<ArrayLength>,
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.base.fmt(f)?;
+        match self.array {
+            None => Ok(()),
+            Some(ArrayLength::Variable) => f.write_str("[]"),
+            Some(ArrayLength::Fixed(len)) => write!(f, "[{}]", len),
+        }
+    }
+}
+
+/// A field of a message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    /// Name of the field.
+    pub name: String,
+    /// Type of the field, with nested message names fully resolved.
+    pub field_type: FieldType,
+    /// Comment written after the field, if any, without the leading `#`.
+    pub comment: Option<String>,
+}
+
+/// Value of a message constant.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstantValue {
+    /// Value of a `bool` constant.
+    Bool(bool),
+    /// Value of a signed integer constant.
+    Int(i64),
+    /// Value of an unsigned integer constant.
+    UInt(u64),
+    /// Value of a floating point constant.
+    Float(f64),
+    /// Value of a `string` constant.
+    String(String),
+}
+
+/// A constant declared in a message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constant {
+    /// Name of the constant.
+    pub name: String,
+    /// Type of the constant. Only numbers, `bool` and `string` are allowed.
+    pub constant_type: PrimitiveType,
+    /// Value of the constant.
+    pub value: ConstantValue,
+    /// Value of the constant, exactly as written in the definition.
+    pub value_text: String,
+    /// Comment written after the constant, if any, without the leading `#`.
+    ///
+    /// String constants never have comments, since `#` is a part of the value.
+    pub comment: Option<String>,
+}
+
+/// A parsed message definition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MessageSpec {
+    /// Full name of the message type.
+    pub name: MessageName,
+    /// Fields of the message, in order of declaration.
+    pub fields: Vec<Field>,
+    /// Constants of the message, in order of declaration.
+    pub constants: Vec<Constant>,
+    /// The definition that the message was parsed from.
+    pub text: String,
+}
+
+impl MessageSpec {
+    /// Parse a message definition, given the message's full name.
+    ///
+    /// Errors contain the line and column of the problematic definition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_rosmsg::msg::{ConstantValue, MessageSpec};
+    /// let spec = MessageSpec::parse("std_msgs/Empty", "int32 X=5 # Five\n").unwrap();
+    /// assert_eq!(spec.constants[0].name, "X");
+    /// assert_eq!(spec.constants[0].value, ConstantValue::Int(5));
+    /// assert_eq!(spec.constants[0].comment, Some(String::from("Five")));
+    ///
+    /// let error = MessageSpec::parse("std_msgs/Empty", "int32 x\nfoo bar baz").unwrap_err();
+    /// assert_eq!(error.to_string(),
+    ///            "Bad message definition at line 2, column 9: unexpected token \"baz\"");
+    /// ```
+    pub fn parse(name: &str, text: &str) -> Result<Self> {
+        parse::message(MessageName::parse(name)?, text)
+    }
+
+    /// Get the names of all messages used by fields of this message.
+    ///
+    /// Each name is listed once, in order of first appearance.
+    pub fn dependencies(&self) -> Vec<&MessageName> {
+        let mut names = Vec::<&MessageName>::new();
+        for field in &self.fields {
+            if let BaseType::Message(ref name) = field.field_type.base {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Check if the message has a `std_msgs/Header` as its first field.
+    pub fn has_header(&self) -> bool {
+        self.fields.first().is_some_and(|field| {
+            field.name == "header"
+                && field.field_type.array.is_none()
+                && field.field_type.base
+                    == BaseType::Message(MessageName::new("std_msgs", "Header"))
+        })
+    }
+}
+
+/// Source of message definitions, used for looking up nested message types.
+pub trait Resolver {
+    /// Find the definition of the message type with the given name.
+    fn resolve(&self, name: &MessageName) -> Option<&MessageSpec>;
+}
+
+impl Resolver for HashMap<MessageName, MessageSpec> {
+    fn resolve(&self, name: &MessageName) -> Option<&MessageSpec> {
+        self.get(name)
+    }
+}
+
+impl<R: Resolver + ?Sized> Resolver for &R {
+    fn resolve(&self, name: &MessageName) -> Option<&MessageSpec> {
+        (**self).resolve(name)
+    }
+}
@@ -0,0 +1,503 @@
+//! Parser of the text format of message definitions.
+
+use super::super::error::{ErrorKind, Result};
+use super::{ArrayLength, BaseType, Constant, ConstantValue, Field, FieldType, MessageName,
+            MessageSpec, PrimitiveType};
+
+/// A word of a definition line, with the column where it starts.
+struct Token<'a> {
+    column: usize,
+    text: &'a str,
+}
+
+fn bad_definition<T>(line: usize, column: usize, reason: String) -> Result<T> {
+    bail!(ErrorKind::BadMessageDefinition(line, column, reason))
+}
+
+#[inline]
+fn column_of(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+fn tokenize<'a>(line: &'a str, content: &'a str) -> Vec<Token<'a>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (offset, c) in content.char_indices().chain(Some((content.len(), ' '))) {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(offset),
+            (Some(begin), true) => {
+                tokens.push(Token {
+                    column: column_of(line, begin),
+                    text: &content[begin..offset],
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+pub fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+pub fn message_name(value: &str) -> Result<MessageName> {
+    let mut parts = value.splitn(2, '/');
+    match (parts.next(), parts.next()) {
+        (Some(package), Some(name)) if is_identifier(package) && is_identifier(name) => {
+            Ok(MessageName::new(package, name))
+        }
+        _ => bail!(ErrorKind::BadMessageName(value.into())),
+    }
+}
+
+fn comment_of(line: &str, hash: Option<usize>) -> Option<String> {
+    hash.map(|hash| line[hash + 1..].trim().into())
+}
+
+fn field_type(package: &str, line_number: usize, token: &Token) -> Result<FieldType> {
+    let (base, array) = match token.text.find('[') {
+        None => (token.text, None),
+        Some(bracket) => {
+            let annotation = &token.text[bracket + 1..];
+            let column = token.column + token.text[..bracket].chars().count();
+            if !annotation.ends_with(']') {
+                return bad_definition(line_number,
+                                      column,
+                                      "array annotation needs to end with \"]\"".into());
+            }
+            let length = &annotation[..annotation.len() - 1];
+            let array = if length.is_empty() {
+                ArrayLength::Variable
+            } else {
+                match length.parse::<usize>() {
+                    Ok(value) if length.chars().all(|c| c.is_ascii_digit()) => {
+                        ArrayLength::Fixed(value)
+                    }
+                    _ => {
+                        return bad_definition(line_number,
+                                              column,
+                                              format!("invalid array length \"{}\"", length))
+                    }
+                }
+            };
+            (&token.text[..bracket], Some(array))
+        }
+    };
+    let base = if let Some(primitive) = PrimitiveType::from_name(base) {
+        BaseType::Primitive(primitive)
+    } else if base.contains('/') {
+        match message_name(base) {
+            Ok(name) => BaseType::Message(name),
+            Err(_) => {
+                return bad_definition(line_number,
+                                      token.column,
+                                      format!("invalid type \"{}\"", base))
+            }
+        }
+    } else if base == "Header" {
+        BaseType::Message(MessageName::new("std_msgs", "Header"))
+    } else if is_identifier(base) {
+        BaseType::Message(MessageName::new(package, base))
+    } else {
+        return bad_definition(line_number, token.column, format!("invalid type \"{}\"", base));
+    };
+    Ok(FieldType { base, array })
+}
+
+fn field(package: &str, line_number: usize, line: &str, hash: Option<usize>) -> Result<Field> {
+    let content = &line[..hash.unwrap_or(line.len())];
+    let tokens = tokenize(line, content);
+    if tokens.len() < 2 {
+        return bad_definition(line_number,
+                              column_of(line, content.trim_end().len()),
+                              "expected a field name".into());
+    }
+    if let Some(token) = tokens.get(2) {
+        return bad_definition(line_number,
+                              token.column,
+                              format!("unexpected token \"{}\"", token.text));
+    }
+    let field_type = field_type(package, line_number, &tokens[0])?;
+    if !is_identifier(tokens[1].text) {
+        return bad_definition(line_number,
+                              tokens[1].column,
+                              format!("invalid field name \"{}\"", tokens[1].text));
+    }
+    Ok(Field {
+        name: tokens[1].text.into(),
+        field_type,
+        comment: comment_of(line, hash),
+    })
+}
+
+fn constant_value(constant_type: PrimitiveType, value: &str) -> Option<ConstantValue> {
+    macro_rules! signed {
+        ($ty:ty) => { value.parse::<$ty>().ok().map(|v| ConstantValue::Int(v as i64)) }
+    }
+    macro_rules! unsigned {
+        ($ty:ty) => { value.parse::<$ty>().ok().map(|v| ConstantValue::UInt(v as u64)) }
+    }
+    match constant_type {
+        PrimitiveType::Bool => {
+            match value {
+                "true" | "True" | "1" => Some(ConstantValue::Bool(true)),
+                "false" | "False" | "0" => Some(ConstantValue::Bool(false)),
+                _ => None,
+            }
+        }
+        PrimitiveType::Int8 | PrimitiveType::Byte => signed!(i8),
+        PrimitiveType::Int16 => signed!(i16),
+        PrimitiveType::Int32 => signed!(i32),
+        PrimitiveType::Int64 => signed!(i64),
+        PrimitiveType::UInt8 | PrimitiveType::Char => unsigned!(u8),
+        PrimitiveType::UInt16 => unsigned!(u16),
+        PrimitiveType::UInt32 => unsigned!(u32),
+        PrimitiveType::UInt64 => unsigned!(u64),
+        PrimitiveType::Float32 | PrimitiveType::Float64 => {
+            value.parse::<f64>().ok().map(ConstantValue::Float)
+        }
+        PrimitiveType::String => Some(ConstantValue::String(value.into())),
+        PrimitiveType::Time | PrimitiveType::Duration => None,
+    }
+}
+
+fn constant(line_number: usize, line: &str, hash: Option<usize>) -> Result<Constant> {
+    let content = &line[..hash.unwrap_or(line.len())];
+    let tokens = tokenize(line, content);
+    let type_token = &tokens[0];
+    let constant_type = match PrimitiveType::from_name(type_token.text) {
+        Some(PrimitiveType::Time) |
+        Some(PrimitiveType::Duration) |
+        None => {
+            return bad_definition(line_number,
+                                  type_token.column,
+                                  format!("invalid constant type \"{}\"", type_token.text))
+        }
+        Some(v) => v,
+    };
+    // String constants contain everything after the equals sign, including `#`
+    let (content, comment) = match constant_type {
+        PrimitiveType::String => (line, None),
+        _ => (content, comment_of(line, hash)),
+    };
+    let type_end = content.find(type_token.text).unwrap_or(0) + type_token.text.len();
+    let equals = type_end + content[type_end..].find('=').unwrap_or(0);
+    let raw_name = &content[type_end..equals];
+    let name = raw_name.trim();
+    let name_column = column_of(line, type_end + raw_name.len() - raw_name.trim_start().len());
+    if !is_identifier(name) {
+        return bad_definition(line_number,
+                              name_column,
+                              format!("invalid constant name \"{}\"", name));
+    }
+    let raw_value = &content[equals + 1..];
+    let value_text = raw_value.trim();
+    let value_column = column_of(line,
+                                 equals + 1 + raw_value.len() - raw_value.trim_start().len());
+    if constant_type != PrimitiveType::String &&
+       (value_text.is_empty() || value_text.contains(char::is_whitespace)) {
+        return bad_definition(line_number,
+                              value_column,
+                              format!("invalid constant value \"{}\"", value_text));
+    }
+    let value = match constant_value(constant_type, value_text) {
+        Some(value) => value,
+        None => {
+            return bad_definition(line_number,
+                                  value_column,
+                                  format!("invalid {} value \"{}\"", constant_type, value_text))
+        }
+    };
+    Ok(Constant {
+        name: name.into(),
+        constant_type,
+        value,
+        value_text: value_text.into(),
+        comment,
+    })
+}
+
+pub fn message(name: MessageName, text: &str) -> Result<MessageSpec> {
+    message_at(name, text, 1)
+}
+
+/// Parse a message definition that starts at the given line of a file.
+pub fn message_at(name: MessageName, text: &str, first_line: usize) -> Result<MessageSpec> {
+    let mut fields = Vec::<Field>::new();
+    let mut constants = Vec::<Constant>::new();
+    for (index, line) in text.split('\n').enumerate() {
+        let line_number = index + first_line;
+        let line = line.trim_end_matches('\r');
+        let hash = line.find('#');
+        let content = &line[..hash.unwrap_or(line.len())];
+        if content.trim().is_empty() {
+            continue;
+        }
+        if content.contains('=') {
+            let constant = constant(line_number, line, hash)?;
+            if constants.iter().any(|v| v.name == constant.name) {
+                return bad_definition(line_number,
+                                      1,
+                                      format!("duplicate constant name \"{}\"", constant.name));
+            }
+            constants.push(constant);
+        } else {
+            let field = field(&name.package, line_number, line, hash)?;
+            if fields.iter().any(|v| v.name == field.name) {
+                return bad_definition(line_number,
+                                      1,
+                                      format!("duplicate field name \"{}\"", field.name));
+            }
+            fields.push(field);
+        }
+    }
+    Ok(MessageSpec {
+        name,
+        fields,
+        constants,
+        text: text.into(),
+    })
+}
+
+/// A part of a definition file, delimited by `---` lines.
+pub struct Section<'a> {
+    /// Line of the file where the section starts.
+    pub first_line: usize,
+    /// Text of the section, without the delimiters.
+    pub text: &'a str,
+}
+
+/// Split a service or action definition into sections.
+///
+/// The expected number of sections is checked, reporting the position of
+/// the first unexpected or last missing delimiter.
+pub fn sections(text: &str, count: usize) -> Result<Vec<Section<'_>>> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    let mut first_line = 1;
+    let mut offset = 0;
+    let mut line_number = 1;
+    for line in text.split('\n') {
+        let next_offset = offset + line.len() + 1;
+        let content = &line[..line.find('#').unwrap_or(line.len())];
+        if content.trim().starts_with("---") {
+            if sections.len() + 1 == count {
+                return bad_definition(line_number,
+                                      column_of(line, line.find('-').unwrap_or(0)),
+                                      format!("expected {} sections", count));
+            }
+            sections.push(Section {
+                first_line,
+                text: &text[start..offset],
+            });
+            start = ::std::cmp::min(next_offset, text.len());
+            first_line = line_number + 1;
+        }
+        offset = next_offset;
+        line_number += 1;
+    }
+    if sections.len() + 1 != count {
+        return bad_definition(line_number - 1,
+                              1,
+                              format!("expected {} sections delimited by \"---\"", count));
+    }
+    sections.push(Section {
+        first_line,
+        text: &text[start..],
+    });
+    Ok(sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<MessageSpec> {
+        message(MessageName::new("test_msgs", "Test"), text)
+    }
+
+    fn field_type_of(spec: &MessageSpec, index: usize) -> String {
+        spec.fields[index].field_type.to_string()
+    }
+
+    fn assert_error(text: &str, line: usize, column: usize) {
+        let error = parse(text).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadMessageDefinition(l, c, _) if l == line && c == column => {}
+            _ => {
+                panic!("Bad definition at {}:{} expected, got: {:?}",
+                       line,
+                       column,
+                       error)
+            }
+        }
+    }
+
+    #[test]
+    fn parses_message_names() {
+        assert_eq!(MessageName::new("std_msgs", "String"),
+                   message_name("std_msgs/String").unwrap());
+        message_name("String").unwrap_err();
+        message_name("std_msgs/").unwrap_err();
+        message_name("/String").unwrap_err();
+        message_name("std_msgs/String/Foo").unwrap_err();
+        message_name("std msgs/String").unwrap_err();
+    }
+
+    #[test]
+    fn parses_empty_message() {
+        let spec = parse("").unwrap();
+        assert!(spec.fields.is_empty());
+        assert!(spec.constants.is_empty());
+        let spec = parse("# Only a comment\n\n   \n").unwrap();
+        assert!(spec.fields.is_empty());
+        assert!(spec.constants.is_empty());
+    }
+
+    #[test]
+    fn parses_primitive_fields() {
+        let spec = parse("bool a\nint8 b\nuint8 c\nint16 d\nuint16 e\nint32 f\nuint32 g\n\
+                          int64 h\nuint64 i\nfloat32 j\nfloat64 k\nstring l\ntime m\n\
+                          duration n\nbyte o\nchar p\n")
+            .unwrap();
+        let types = spec.fields
+            .iter()
+            .map(|field| field.field_type.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!("bool int8 uint8 int16 uint16 int32 uint32 int64 uint64 float32 float64 \
+                    string time duration byte char",
+                   types);
+        assert_eq!("abcdefghijklmnop",
+                   spec.fields.iter().map(|field| field.name.as_str()).collect::<String>());
+    }
+
+    #[test]
+    fn parses_arrays() {
+        let spec = parse("float64[9] covariance\nstring[] names\nPoint[2] ends\n").unwrap();
+        assert_eq!(Some(ArrayLength::Fixed(9)), spec.fields[0].field_type.array);
+        assert_eq!(BaseType::Primitive(PrimitiveType::Float64),
+                   spec.fields[0].field_type.base);
+        assert_eq!(Some(ArrayLength::Variable), spec.fields[1].field_type.array);
+        assert_eq!("test_msgs/Point[2]", field_type_of(&spec, 2));
+    }
+
+    #[test]
+    fn resolves_message_types() {
+        let spec = parse("Header header\ngeometry_msgs/Pose pose\nOther other\n\
+                          std_msgs/Header h2\n")
+            .unwrap();
+        assert_eq!("std_msgs/Header", field_type_of(&spec, 0));
+        assert_eq!("geometry_msgs/Pose", field_type_of(&spec, 1));
+        assert_eq!("test_msgs/Other", field_type_of(&spec, 2));
+        assert_eq!("std_msgs/Header", field_type_of(&spec, 3));
+        assert!(spec.has_header());
+        assert_eq!(vec![&MessageName::new("std_msgs", "Header"),
+                        &MessageName::new("geometry_msgs", "Pose"),
+                        &MessageName::new("test_msgs", "Other")],
+                   spec.dependencies());
+    }
+
+    #[test]
+    fn parses_comments() {
+        let spec = parse("# Leading comment\nint32 x # The x coordinate\n  int32   y#\n").unwrap();
+        assert_eq!(Some(String::from("The x coordinate")), spec.fields[0].comment);
+        assert_eq!(Some(String::from("")), spec.fields[1].comment);
+        assert_eq!("y", spec.fields[1].name);
+    }
+
+    #[test]
+    fn parses_constants() {
+        let spec = parse("int8 A=-5\nuint64 B = 18446744073709551615 # Max\nfloat32 C=1.5\n\
+                          bool D=True\nstring E = Hello # World \nchar F=7\n")
+            .unwrap();
+        assert!(spec.fields.is_empty());
+        let c = &spec.constants;
+        assert_eq!(("A", ConstantValue::Int(-5)), (c[0].name.as_str(), c[0].value.clone()));
+        assert_eq!(("B", ConstantValue::UInt(18446744073709551615)),
+                   (c[1].name.as_str(), c[1].value.clone()));
+        assert_eq!(Some(String::from("Max")), c[1].comment);
+        assert_eq!("18446744073709551615", c[1].value_text);
+        assert_eq!(ConstantValue::Float(1.5), c[2].value);
+        assert_eq!(ConstantValue::Bool(true), c[3].value);
+        assert_eq!("E", c[4].name);
+        assert_eq!(ConstantValue::String(String::from("Hello # World")), c[4].value);
+        assert_eq!(None, c[4].comment);
+        assert_eq!(PrimitiveType::Char, c[5].constant_type);
+        assert_eq!(ConstantValue::UInt(7), c[5].value);
+    }
+
+    #[test]
+    fn handles_windows_line_endings() {
+        let spec = parse("int32 x\r\nstring S=abc\r\n").unwrap();
+        assert_eq!("x", spec.fields[0].name);
+        assert_eq!(ConstantValue::String(String::from("abc")), spec.constants[0].value);
+    }
+
+    #[test]
+    fn reports_positions_of_errors() {
+        assert_error("int32", 1, 6);
+        assert_error("int32 x\n  int32", 2, 8);
+        assert_error("int32 x y", 1, 9);
+        assert_error("int32 x\nint32[3 y", 2, 6);
+        assert_error("int32[a] y", 1, 6);
+        assert_error("int32[-1] y", 1, 6);
+        assert_error("a/b/c y", 1, 1);
+        assert_error("in-t32 y", 1, 1);
+        assert_error("int32 1y", 1, 7);
+        assert_error("int32 x\nint32 x", 2, 1);
+        assert_error("time T=5", 1, 1);
+        assert_error("int32[] T=5", 1, 1);
+        assert_error("int8 T=300", 1, 8);
+        assert_error("uint8  T =  -1", 1, 13);
+        assert_error("int32 T=", 1, 9);
+        assert_error("int32 T=1 2", 1, 9);
+        assert_error("bool T=yes", 1, 8);
+        assert_error("int32 =5", 1, 7);
+        assert_error("int32 A=1\nint32 A=2", 2, 1);
+    }
+
+    #[test]
+    fn splits_sections() {
+        let text = "int32 a\n---\n# Comment\nint32 b\n  --- # Delimiter\nint32 c";
+        let parts = sections(text, 3).unwrap();
+        assert_eq!(3, parts.len());
+        assert_eq!((1, "int32 a\n"), (parts[0].first_line, parts[0].text));
+        assert_eq!((3, "# Comment\nint32 b\n"), (parts[1].first_line, parts[1].text));
+        assert_eq!((6, "int32 c"), (parts[2].first_line, parts[2].text));
+        let parts = sections("---", 2).unwrap();
+        assert_eq!((1, ""), (parts[0].first_line, parts[0].text));
+        assert_eq!((2, ""), (parts[1].first_line, parts[1].text));
+    }
+
+    #[test]
+    fn reports_wrong_number_of_sections() {
+        let error = sections("int32 a\n---\nint32 b\n  ---\n", 2).map(|_| ()).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadMessageDefinition(4, 3, _) => {}
+            _ => panic!("Bad definition at 4:3 expected, got: {:?}", error),
+        }
+        let error = sections("int32 a\nint32 b\n", 2).map(|_| ()).unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadMessageDefinition(3, 1, _) => {}
+            _ => panic!("Bad definition at 3:1 expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn keeps_original_text() {
+        let text = include_str!("../datatests/pose_message_definition.txt");
+        let text = text.split("\n====").next().unwrap();
+        let spec = message(MessageName::new("geometry_msgs", "Pose"), text).unwrap();
+        assert_eq!(text, spec.text);
+        assert_eq!("geometry_msgs/Point", field_type_of(&spec, 0));
+        assert_eq!("orientation", spec.fields[1].name);
+    }
+}
@@ -0,0 +1,117 @@
+//! Service definitions, made of a request and a response message.
+
+use super::super::error::Result;
+use super::{parse, MessageName, MessageSpec};
+
+/// A parsed service definition.
+///
+/// Services are described in `.srv` files, containing the request message
+/// definition, followed by a `---` line and the response message definition.
+/// The request and response are regular messages, named by appending
+/// `Request` and `Response` to the name of the service.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_rosmsg::msg::ServiceSpec;
+/// let spec = ServiceSpec::parse("roscpp_tutorials/TwoInts",
+///                               "int64 a\nint64 b\n---\nint64 sum\n").unwrap();
+/// assert_eq!(spec.request.name.to_string(), "roscpp_tutorials/TwoIntsRequest");
+/// assert_eq!(spec.request.fields.len(), 2);
+/// assert_eq!(spec.response.name.to_string(), "roscpp_tutorials/TwoIntsResponse");
+/// assert_eq!(spec.response.fields[0].name, "sum");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceSpec {
+    /// Full name of the service type.
+    pub name: MessageName,
+    /// Message sent by the client.
+    pub request: MessageSpec,
+    /// Message sent back by the server.
+    pub response: MessageSpec,
+    /// The definition that the service was parsed from.
+    pub text: String,
+}
+
+impl ServiceSpec {
+    /// Parse a service definition, given the service's full name.
+    ///
+    /// Errors contain the line and column of the problematic definition,
+    /// counted from the start of the whole service definition.
+    pub fn parse(name: &str, text: &str) -> Result<Self> {
+        let name = MessageName::parse(name)?;
+        let sections = parse::sections(text, 2)?;
+        let request_name = MessageName::new(name.package.clone(), format!("{}Request", name.name));
+        let response_name =
+            MessageName::new(name.package.clone(), format!("{}Response", name.name));
+        Ok(ServiceSpec {
+            request: parse::message_at(request_name, sections[0].text, sections[0].first_line)?,
+            response: parse::message_at(response_name, sections[1].text, sections[1].first_line)?,
+            name,
+            text: text.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::error::ErrorKind;
+    use super::super::{ConstantValue, PrimitiveType};
+
+    #[test]
+    fn parses_empty_service() {
+        let spec = ServiceSpec::parse("std_srvs/Empty", "---\n").unwrap();
+        assert_eq!(MessageName::new("std_srvs", "EmptyRequest"), spec.request.name);
+        assert_eq!(MessageName::new("std_srvs", "EmptyResponse"), spec.response.name);
+        assert!(spec.request.fields.is_empty());
+        assert!(spec.response.fields.is_empty());
+    }
+
+    #[test]
+    fn parses_typical_service() {
+        let text = "# Get the map\n\
+                    uint8 FULL=0\n\
+                    uint8 mode\n\
+                    ---\n\
+                    nav_msgs/OccupancyGrid map # The map\n\
+                    Header header\n\
+                    string message\n";
+        let spec = ServiceSpec::parse("nav_msgs/GetMap", text).unwrap();
+        assert_eq!(text, spec.text);
+        assert_eq!("# Get the map\nuint8 FULL=0\nuint8 mode\n", spec.request.text);
+        assert_eq!(ConstantValue::UInt(0), spec.request.constants[0].value);
+        assert_eq!(PrimitiveType::UInt8, spec.request.constants[0].constant_type);
+        assert_eq!("mode", spec.request.fields[0].name);
+        assert_eq!(3, spec.response.fields.len());
+        assert_eq!("nav_msgs/OccupancyGrid",
+                   spec.response.fields[0].field_type.to_string());
+        assert_eq!("std_msgs/Header", spec.response.fields[1].field_type.to_string());
+        assert_eq!(Some(String::from("The map")), spec.response.fields[0].comment);
+    }
+
+    #[test]
+    fn resolves_types_in_service_package() {
+        let spec = ServiceSpec::parse("my_srvs/Query", "Filter filter\n---\nResult[] results")
+            .unwrap();
+        assert_eq!("my_srvs/Filter", spec.request.fields[0].field_type.to_string());
+        assert_eq!("my_srvs/Result[]", spec.response.fields[0].field_type.to_string());
+    }
+
+    #[test]
+    fn reports_positions_in_whole_file() {
+        let error = ServiceSpec::parse("my_srvs/Query", "int32 a\n---\nint32 b\nint32 c d\n")
+            .unwrap_err();
+        match *error.kind() {
+            ErrorKind::BadMessageDefinition(4, 9, _) => {}
+            _ => panic!("Bad definition at 4:9 expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn requires_exactly_one_delimiter() {
+        ServiceSpec::parse("my_srvs/Query", "int32 a\n").unwrap_err();
+        ServiceSpec::parse("my_srvs/Query", "int32 a\n---\n---\n").unwrap_err();
+        ServiceSpec::parse("my_srvs", "int32 a\n---\n").unwrap_err();
+    }
+}
@@ -0,0 +1,2000 @@
+// Generated by serde_rosmsg::codegen, do not edit.
+
+pub mod actionlib_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `actionlib_msgs/GoalID`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct GoalID {
+        pub stamp: ::serde_rosmsg::time::Time,
+        pub id: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for GoalID {
+        const ROS_TYPE: &'static str = "actionlib_msgs/GoalID";
+        const MD5SUM: &'static str = "302881f31927c1df708a2dbab0e80ee8";
+        const DEFINITION: &'static str = "# The stamp should store the time at which this goal was requested.\n# It is used by an action server when it tries to preempt all\n# goals that were requested before a certain time\ntime stamp\n\n# The id provides a way to associate feedback and\n# result message with specific goal requests. The id\n# specified must be unique.\nstring id\n\n";
+    }
+
+    /// Message type `actionlib_msgs/GoalStatus`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct GoalStatus {
+        pub goal_id: super::actionlib_msgs::GoalID,
+        pub status: u8,
+        pub text: ::std::string::String,
+    }
+
+    impl GoalStatus {
+        /// The goal has yet to be processed by the action server
+        pub const PENDING: u8 = 0;
+        /// The goal is currently being processed by the action server
+        pub const ACTIVE: u8 = 1;
+        /// The goal received a cancel request after it started executing
+        pub const PREEMPTED: u8 = 2;
+        /// The goal was achieved successfully by the action server (Terminal State)
+        pub const SUCCEEDED: u8 = 3;
+        /// The goal was aborted during execution by the action server due
+        pub const ABORTED: u8 = 4;
+        /// The goal was rejected by the action server without being processed,
+        pub const REJECTED: u8 = 5;
+        /// The goal received a cancel request after it started executing
+        pub const PREEMPTING: u8 = 6;
+        /// The goal received a cancel request before it started executing,
+        pub const RECALLING: u8 = 7;
+        /// The goal received a cancel request before it started executing
+        pub const RECALLED: u8 = 8;
+        /// An action client can determine that a goal is LOST. This should not be
+        pub const LOST: u8 = 9;
+    }
+
+    impl ::serde_rosmsg::RosMessage for GoalStatus {
+        const ROS_TYPE: &'static str = "actionlib_msgs/GoalStatus";
+        const MD5SUM: &'static str = "d388f9b87b3c471f784434d671988d4a";
+        const DEFINITION: &'static str = "GoalID goal_id\nuint8 status\nuint8 PENDING         = 0   # The goal has yet to be processed by the action server\nuint8 ACTIVE          = 1   # The goal is currently being processed by the action server\nuint8 PREEMPTED       = 2   # The goal received a cancel request after it started executing\n                            #   and has since completed its execution (Terminal State)\nuint8 SUCCEEDED       = 3   # The goal was achieved successfully by the action server (Terminal State)\nuint8 ABORTED         = 4   # The goal was aborted during execution by the action server due\n                            #    to some failure (Terminal State)\nuint8 REJECTED        = 5   # The goal was rejected by the action server without being processed,\n                            #    because the goal was unattainable or invalid (Terminal State)\nuint8 PREEMPTING      = 6   # The goal received a cancel request after it started executing\n                            #    and has not yet completed execution\nuint8 RECALLING       = 7   # The goal received a cancel request before it started executing,\n                            #    but the action server has not yet confirmed that the goal is canceled\nuint8 RECALLED        = 8   # The goal received a cancel request before it started executing\n                            #    and was successfully cancelled (Terminal State)\nuint8 LOST            = 9   # An action client can determine that a goal is LOST. This should not be\n                            #    sent over the wire by an action server\n\n#Allow for the user to associate a string with GoalStatus for debugging\nstring text\n\n\n================================================================================\nMSG: actionlib_msgs/GoalID\n# The stamp should store the time at which this goal was requested.\n# It is used by an action server when it tries to preempt all\n# goals that were requested before a certain time\ntime stamp\n\n# The id provides a way to associate feedback and\n# result message with specific goal requests. The id\n# specified must be unique.\nstring id\n\n";
+    }
+
+    /// Message type `actionlib_msgs/GoalStatusArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct GoalStatusArray {
+        pub header: super::std_msgs::Header,
+        pub status_list: ::std::vec::Vec<super::actionlib_msgs::GoalStatus>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for GoalStatusArray {
+        const ROS_TYPE: &'static str = "actionlib_msgs/GoalStatusArray";
+        const MD5SUM: &'static str = "8b2b82f13216d0a8ea88bd3af735e619";
+        const DEFINITION: &'static str = "# Stores the statuses for goals that are currently being tracked\n# by an action server\nHeader header\nGoalStatus[] status_list\n\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: actionlib_msgs/GoalStatus\nGoalID goal_id\nuint8 status\nuint8 PENDING         = 0   # The goal has yet to be processed by the action server\nuint8 ACTIVE          = 1   # The goal is currently being processed by the action server\nuint8 PREEMPTED       = 2   # The goal received a cancel request after it started executing\n                            #   and has since completed its execution (Terminal State)\nuint8 SUCCEEDED       = 3   # The goal was achieved successfully by the action server (Terminal State)\nuint8 ABORTED         = 4   # The goal was aborted during execution by the action server due\n                            #    to some failure (Terminal State)\nuint8 REJECTED        = 5   # The goal was rejected by the action server without being processed,\n                            #    because the goal was unattainable or invalid (Terminal State)\nuint8 PREEMPTING      = 6   # The goal received a cancel request after it started executing\n                            #    and has not yet completed execution\nuint8 RECALLING       = 7   # The goal received a cancel request before it started executing,\n                            #    but the action server has not yet confirmed that the goal is canceled\nuint8 RECALLED        = 8   # The goal received a cancel request before it started executing\n                            #    and was successfully cancelled (Terminal State)\nuint8 LOST            = 9   # An action client can determine that a goal is LOST. This should not be\n                            #    sent over the wire by an action server\n\n#Allow for the user to associate a string with GoalStatus for debugging\nstring text\n\n\n================================================================================\nMSG: actionlib_msgs/GoalID\n# The stamp should store the time at which this goal was requested.\n# It is used by an action server when it tries to preempt all\n# goals that were requested before a certain time\ntime stamp\n\n# The id provides a way to associate feedback and\n# result message with specific goal requests. The id\n# specified must be unique.\nstring id\n\n";
+    }
+}
+
+pub mod diagnostic_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `diagnostic_msgs/DiagnosticArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct DiagnosticArray {
+        /// for timestamp
+        pub header: super::std_msgs::Header,
+        /// an array of components being reported on
+        pub status: ::std::vec::Vec<super::diagnostic_msgs::DiagnosticStatus>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for DiagnosticArray {
+        const ROS_TYPE: &'static str = "diagnostic_msgs/DiagnosticArray";
+        const MD5SUM: &'static str = "60810da900de1dd6ddd437c3503511da";
+        const DEFINITION: &'static str = "# This message is used to send diagnostic information about the state of the robot\nHeader header #for timestamp\nDiagnosticStatus[] status # an array of components being reported on\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: diagnostic_msgs/DiagnosticStatus\n# This message holds the status of an individual component of the robot.\n# \n\n# Possible levels of operations\nbyte OK=0\nbyte WARN=1\nbyte ERROR=2\nbyte STALE=3\n\nbyte level # level of operation enumerated above \nstring name # a description of the test/component reporting\nstring message # a description of the status\nstring hardware_id # a hardware unique string\nKeyValue[] values # an array of values associated with the status\n\n\n================================================================================\nMSG: diagnostic_msgs/KeyValue\nstring key # what to label this value when viewing\nstring value # a value to track over time\n";
+    }
+
+    /// Message type `diagnostic_msgs/DiagnosticStatus`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct DiagnosticStatus {
+        /// level of operation enumerated above
+        pub level: i8,
+        /// a description of the test/component reporting
+        pub name: ::std::string::String,
+        /// a description of the status
+        pub message: ::std::string::String,
+        /// a hardware unique string
+        pub hardware_id: ::std::string::String,
+        /// an array of values associated with the status
+        pub values: ::std::vec::Vec<super::diagnostic_msgs::KeyValue>,
+    }
+
+    impl DiagnosticStatus {
+        pub const OK: i8 = 0;
+        pub const WARN: i8 = 1;
+        pub const ERROR: i8 = 2;
+        pub const STALE: i8 = 3;
+    }
+
+    impl ::serde_rosmsg::RosMessage for DiagnosticStatus {
+        const ROS_TYPE: &'static str = "diagnostic_msgs/DiagnosticStatus";
+        const MD5SUM: &'static str = "d0ce08bc6e5ba34c7754f563a9cabaf1";
+        const DEFINITION: &'static str = "# This message holds the status of an individual component of the robot.\n# \n\n# Possible levels of operations\nbyte OK=0\nbyte WARN=1\nbyte ERROR=2\nbyte STALE=3\n\nbyte level # level of operation enumerated above \nstring name # a description of the test/component reporting\nstring message # a description of the status\nstring hardware_id # a hardware unique string\nKeyValue[] values # an array of values associated with the status\n\n\n================================================================================\nMSG: diagnostic_msgs/KeyValue\nstring key # what to label this value when viewing\nstring value # a value to track over time\n";
+    }
+
+    /// Message type `diagnostic_msgs/KeyValue`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct KeyValue {
+        /// what to label this value when viewing
+        pub key: ::std::string::String,
+        /// a value to track over time
+        pub value: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for KeyValue {
+        const ROS_TYPE: &'static str = "diagnostic_msgs/KeyValue";
+        const MD5SUM: &'static str = "cf57fdc6617a881a88c16e768132149c";
+        const DEFINITION: &'static str = "string key # what to label this value when viewing\nstring value # a value to track over time\n";
+    }
+
+    /// Message type `diagnostic_msgs/AddDiagnosticsRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct AddDiagnosticsRequest {
+        pub load_namespace: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for AddDiagnosticsRequest {
+        const ROS_TYPE: &'static str = "diagnostic_msgs/AddDiagnosticsRequest";
+        const MD5SUM: &'static str = "c26cf6e164288fbc6050d74f838bcdf0";
+        const DEFINITION: &'static str = "# This service is used as part of the process for loading analyzers at runtime,\n# and should be used by a loader script or program, not as a standalone service.\n# Information about dynamic addition of analyzers can be found at\n# http://wiki.ros.org/diagnostics/Tutorials/Adding%20Analyzers%20at%20Runtime\n\n# The load_namespace parameter defines the namespace where parameters for the\n# initialization of analyzers in the diagnostic aggregator have been loaded. The\n# value should be a global name (i.e. /my/name/space), not a relative\n# (my/name/space) or private (~my/name/space) name. Analyzers will not be added\n# if a non-global name is used. The call will also fail if the namespace\n# contains parameters that follow a namespace structure that does not conform to\n# that expected by the analyzer definitions. See\n# http://wiki.ros.org/diagnostics/Tutorials/Configuring%20Diagnostic%20Aggregators\n# and http://wiki.ros.org/diagnostics/Tutorials/Using%20the%20GenericAnalyzer\n# for examples of the structure of yaml files which are expected to have been\n# loaded into the namespace.\nstring load_namespace\n";
+    }
+
+    /// Message type `diagnostic_msgs/AddDiagnosticsResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct AddDiagnosticsResponse {
+        pub success: bool,
+        pub message: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for AddDiagnosticsResponse {
+        const ROS_TYPE: &'static str = "diagnostic_msgs/AddDiagnosticsResponse";
+        const MD5SUM: &'static str = "937c9679a518e3a18d831e57125ea522";
+        const DEFINITION: &'static str = "\n# True if diagnostic aggregator was updated with new diagnostics, False\n# otherwise. A false return value means that either there is a bond in the\n# aggregator which already used the requested namespace, or the initialization\n# of analyzers failed.\nbool success\n\n# Message with additional information about the success or failure\nstring message\n";
+    }
+
+    /// Service type `diagnostic_msgs/AddDiagnostics`, made of `AddDiagnosticsRequest` and `AddDiagnosticsResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct AddDiagnostics;
+
+    impl AddDiagnostics {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "diagnostic_msgs/AddDiagnostics";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "e6ac9bbde83d0d3186523c3687aecaee";
+    }
+
+    /// Message type `diagnostic_msgs/SelfTestRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct SelfTestRequest {
+    }
+
+    impl ::serde_rosmsg::RosMessage for SelfTestRequest {
+        const ROS_TYPE: &'static str = "diagnostic_msgs/SelfTestRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "";
+    }
+
+    /// Message type `diagnostic_msgs/SelfTestResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct SelfTestResponse {
+        pub id: ::std::string::String,
+        pub passed: i8,
+        pub status: ::std::vec::Vec<super::diagnostic_msgs::DiagnosticStatus>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for SelfTestResponse {
+        const ROS_TYPE: &'static str = "diagnostic_msgs/SelfTestResponse";
+        const MD5SUM: &'static str = "ac21b1bab7ab17546986536c22eb34e9";
+        const DEFINITION: &'static str = "string id\nbyte passed\nDiagnosticStatus[] status\n\n================================================================================\nMSG: diagnostic_msgs/DiagnosticStatus\n# This message holds the status of an individual component of the robot.\n# \n\n# Possible levels of operations\nbyte OK=0\nbyte WARN=1\nbyte ERROR=2\nbyte STALE=3\n\nbyte level # level of operation enumerated above \nstring name # a description of the test/component reporting\nstring message # a description of the status\nstring hardware_id # a hardware unique string\nKeyValue[] values # an array of values associated with the status\n\n\n================================================================================\nMSG: diagnostic_msgs/KeyValue\nstring key # what to label this value when viewing\nstring value # a value to track over time\n";
+    }
+
+    /// Service type `diagnostic_msgs/SelfTest`, made of `SelfTestRequest` and `SelfTestResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct SelfTest;
+
+    impl SelfTest {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "diagnostic_msgs/SelfTest";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "ac21b1bab7ab17546986536c22eb34e9";
+    }
+}
+
+pub mod geometry_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `geometry_msgs/Accel`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Accel {
+        pub linear: super::geometry_msgs::Vector3,
+        pub angular: super::geometry_msgs::Vector3,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Accel {
+        const ROS_TYPE: &'static str = "geometry_msgs/Accel";
+        const MD5SUM: &'static str = "9f195f881246fdfa2798d1d3eebca84a";
+        const DEFINITION: &'static str = "# This expresses acceleration in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/AccelStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct AccelStamped {
+        pub header: super::std_msgs::Header,
+        pub accel: super::geometry_msgs::Accel,
+    }
+
+    impl ::serde_rosmsg::RosMessage for AccelStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/AccelStamped";
+        const MD5SUM: &'static str = "d8a98a5d81351b6eb0578c78557e7659";
+        const DEFINITION: &'static str = "# An accel with reference coordinate frame and timestamp\nHeader header\nAccel accel\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Accel\n# This expresses acceleration in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/AccelWithCovariance`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct AccelWithCovariance {
+        pub accel: super::geometry_msgs::Accel,
+        #[serde(with = "::serde_rosmsg::big_array")]
+        pub covariance: [f64; 36],
+    }
+
+    impl ::std::default::Default for AccelWithCovariance {
+        fn default() -> Self {
+            Self {
+                accel: ::std::default::Default::default(),
+                covariance: ::std::array::from_fn(|_| ::std::default::Default::default()),
+            }
+        }
+    }
+
+    impl ::serde_rosmsg::RosMessage for AccelWithCovariance {
+        const ROS_TYPE: &'static str = "geometry_msgs/AccelWithCovariance";
+        const MD5SUM: &'static str = "ad5a718d699c6be72a02b8d6a139f334";
+        const DEFINITION: &'static str = "# This expresses acceleration in free space with uncertainty.\n\nAccel accel\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Accel\n# This expresses acceleration in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/AccelWithCovarianceStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct AccelWithCovarianceStamped {
+        pub header: super::std_msgs::Header,
+        pub accel: super::geometry_msgs::AccelWithCovariance,
+    }
+
+    impl ::serde_rosmsg::RosMessage for AccelWithCovarianceStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/AccelWithCovarianceStamped";
+        const MD5SUM: &'static str = "96adb295225031ec8d57fb4251b0a886";
+        const DEFINITION: &'static str = "# This represents an estimated accel with reference coordinate frame and timestamp.\nHeader header\nAccelWithCovariance accel\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/AccelWithCovariance\n# This expresses acceleration in free space with uncertainty.\n\nAccel accel\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Accel\n# This expresses acceleration in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/Inertia`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Inertia {
+        pub m: f64,
+        pub com: super::geometry_msgs::Vector3,
+        pub ixx: f64,
+        pub ixy: f64,
+        pub ixz: f64,
+        pub iyy: f64,
+        pub iyz: f64,
+        pub izz: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Inertia {
+        const ROS_TYPE: &'static str = "geometry_msgs/Inertia";
+        const MD5SUM: &'static str = "1d26e4bb6c83ff141c5cf0d883c2b0fe";
+        const DEFINITION: &'static str = "# Mass [kg]\nfloat64 m\n\n# Center of mass [m]\ngeometry_msgs/Vector3 com\n\n# Inertia Tensor [kg-m^2]\n#     | ixx ixy ixz |\n# I = | ixy iyy iyz |\n#     | ixz iyz izz |\nfloat64 ixx\nfloat64 ixy\nfloat64 ixz\nfloat64 iyy\nfloat64 iyz\nfloat64 izz\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/InertiaStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct InertiaStamped {
+        pub header: super::std_msgs::Header,
+        pub inertia: super::geometry_msgs::Inertia,
+    }
+
+    impl ::serde_rosmsg::RosMessage for InertiaStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/InertiaStamped";
+        const MD5SUM: &'static str = "ddee48caeab5a966c5e8d166654a9ac7";
+        const DEFINITION: &'static str = "Header header\nInertia inertia\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Inertia\n# Mass [kg]\nfloat64 m\n\n# Center of mass [m]\ngeometry_msgs/Vector3 com\n\n# Inertia Tensor [kg-m^2]\n#     | ixx ixy ixz |\n# I = | ixy iyy iyz |\n#     | ixz iyz izz |\nfloat64 ixx\nfloat64 ixy\nfloat64 ixz\nfloat64 iyy\nfloat64 iyz\nfloat64 izz\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/Point`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Point {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Point {
+        const ROS_TYPE: &'static str = "geometry_msgs/Point";
+        const MD5SUM: &'static str = "4a842b65f413084dc2b10fb484ea7f17";
+        const DEFINITION: &'static str = "# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n";
+    }
+
+    /// Message type `geometry_msgs/Point32`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Point32 {
+        pub x: f32,
+        pub y: f32,
+        pub z: f32,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Point32 {
+        const ROS_TYPE: &'static str = "geometry_msgs/Point32";
+        const MD5SUM: &'static str = "cc153912f1453b708d221682bc23d9ac";
+        const DEFINITION: &'static str = "# This contains the position of a point in free space(with 32 bits of precision).\n# It is recommeded to use Point wherever possible instead of Point32.  \n# \n# This recommendation is to promote interoperability.  \n#\n# This message is designed to take up less space when sending\n# lots of points at once, as in the case of a PointCloud.  \n\nfloat32 x\nfloat32 y\nfloat32 z";
+    }
+
+    /// Message type `geometry_msgs/PointStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PointStamped {
+        pub header: super::std_msgs::Header,
+        pub point: super::geometry_msgs::Point,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PointStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/PointStamped";
+        const MD5SUM: &'static str = "c63aecb41bfdfd6b7e1fac37c7cbe7bf";
+        const DEFINITION: &'static str = "# This represents a Point with reference coordinate frame and timestamp\nHeader header\nPoint point\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n";
+    }
+
+    /// Message type `geometry_msgs/Polygon`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Polygon {
+        pub points: ::std::vec::Vec<super::geometry_msgs::Point32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Polygon {
+        const ROS_TYPE: &'static str = "geometry_msgs/Polygon";
+        const MD5SUM: &'static str = "cd60a26494a087f577976f0329fa120e";
+        const DEFINITION: &'static str = "#A specification of a polygon where the first and last points are assumed to be connected\nPoint32[] points\n\n================================================================================\nMSG: geometry_msgs/Point32\n# This contains the position of a point in free space(with 32 bits of precision).\n# It is recommeded to use Point wherever possible instead of Point32.  \n# \n# This recommendation is to promote interoperability.  \n#\n# This message is designed to take up less space when sending\n# lots of points at once, as in the case of a PointCloud.  \n\nfloat32 x\nfloat32 y\nfloat32 z";
+    }
+
+    /// Message type `geometry_msgs/PolygonStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PolygonStamped {
+        pub header: super::std_msgs::Header,
+        pub polygon: super::geometry_msgs::Polygon,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PolygonStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/PolygonStamped";
+        const MD5SUM: &'static str = "c6be8f7dc3bee7fe9e8d296070f53340";
+        const DEFINITION: &'static str = "# This represents a Polygon with reference coordinate frame and timestamp\nHeader header\nPolygon polygon\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Polygon\n#A specification of a polygon where the first and last points are assumed to be connected\nPoint32[] points\n\n================================================================================\nMSG: geometry_msgs/Point32\n# This contains the position of a point in free space(with 32 bits of precision).\n# It is recommeded to use Point wherever possible instead of Point32.  \n# \n# This recommendation is to promote interoperability.  \n#\n# This message is designed to take up less space when sending\n# lots of points at once, as in the case of a PointCloud.  \n\nfloat32 x\nfloat32 y\nfloat32 z";
+    }
+
+    /// Message type `geometry_msgs/Pose`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Pose {
+        pub position: super::geometry_msgs::Point,
+        pub orientation: super::geometry_msgs::Quaternion,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Pose {
+        const ROS_TYPE: &'static str = "geometry_msgs/Pose";
+        const MD5SUM: &'static str = "e45d45a5a1ce597b249e23fb30fc871f";
+        const DEFINITION: &'static str = "# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/Pose2D`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Pose2D {
+        pub x: f64,
+        pub y: f64,
+        pub theta: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Pose2D {
+        const ROS_TYPE: &'static str = "geometry_msgs/Pose2D";
+        const MD5SUM: &'static str = "938fa65709584ad8e77d238529be13b8";
+        const DEFINITION: &'static str = "# Deprecated\n# Please use the full 3D pose.\n\n# In general our recommendation is to use a full 3D representation of everything and for 2D specific applications make the appropriate projections into the plane for their calculations but optimally will preserve the 3D information during processing.\n\n# If we have parallel copies of 2D datatypes every UI and other pipeline will end up needing to have dual interfaces to plot everything. And you will end up with not being able to use 3D tools for 2D use cases even if they're completely valid, as you'd have to reimplement it with different inputs and outputs. It's not particularly hard to plot the 2D pose or compute the yaw error for the Pose message and there are already tools and libraries that can do this for you.\n\n\n# This expresses a position and orientation on a 2D manifold.\n\nfloat64 x\nfloat64 y\nfloat64 theta\n";
+    }
+
+    /// Message type `geometry_msgs/PoseArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PoseArray {
+        pub header: super::std_msgs::Header,
+        pub poses: ::std::vec::Vec<super::geometry_msgs::Pose>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PoseArray {
+        const ROS_TYPE: &'static str = "geometry_msgs/PoseArray";
+        const MD5SUM: &'static str = "916c28c5764443f268b296bb671b9d97";
+        const DEFINITION: &'static str = "# An array of poses with a header for global reference.\n\nHeader header\n\nPose[] poses\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/PoseStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PoseStamped {
+        pub header: super::std_msgs::Header,
+        pub pose: super::geometry_msgs::Pose,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PoseStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/PoseStamped";
+        const MD5SUM: &'static str = "d3812c3cbc69362b77dc0b19b345f8f5";
+        const DEFINITION: &'static str = "# A Pose with reference coordinate frame and timestamp\nHeader header\nPose pose\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/PoseWithCovariance`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct PoseWithCovariance {
+        pub pose: super::geometry_msgs::Pose,
+        #[serde(with = "::serde_rosmsg::big_array")]
+        pub covariance: [f64; 36],
+    }
+
+    impl ::std::default::Default for PoseWithCovariance {
+        fn default() -> Self {
+            Self {
+                pose: ::std::default::Default::default(),
+                covariance: ::std::array::from_fn(|_| ::std::default::Default::default()),
+            }
+        }
+    }
+
+    impl ::serde_rosmsg::RosMessage for PoseWithCovariance {
+        const ROS_TYPE: &'static str = "geometry_msgs/PoseWithCovariance";
+        const MD5SUM: &'static str = "c23e848cf1b7533a8d7c259073a97e6f";
+        const DEFINITION: &'static str = "# This represents a pose in free space with uncertainty.\n\nPose pose\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/PoseWithCovarianceStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PoseWithCovarianceStamped {
+        pub header: super::std_msgs::Header,
+        pub pose: super::geometry_msgs::PoseWithCovariance,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PoseWithCovarianceStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/PoseWithCovarianceStamped";
+        const MD5SUM: &'static str = "953b798c0f514ff060a53a3498ce6246";
+        const DEFINITION: &'static str = "# This expresses an estimated pose with a reference coordinate frame and timestamp\n\nHeader header\nPoseWithCovariance pose\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/PoseWithCovariance\n# This represents a pose in free space with uncertainty.\n\nPose pose\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/Quaternion`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Quaternion {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+        pub w: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Quaternion {
+        const ROS_TYPE: &'static str = "geometry_msgs/Quaternion";
+        const MD5SUM: &'static str = "a779879fadf0160734f906b8c19c7004";
+        const DEFINITION: &'static str = "# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/QuaternionStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct QuaternionStamped {
+        pub header: super::std_msgs::Header,
+        pub quaternion: super::geometry_msgs::Quaternion,
+    }
+
+    impl ::serde_rosmsg::RosMessage for QuaternionStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/QuaternionStamped";
+        const MD5SUM: &'static str = "e57f1e547e0e1fd13504588ffc8334e2";
+        const DEFINITION: &'static str = "# This represents an orientation with reference coordinate frame and timestamp.\nHeader header\nQuaternion quaternion\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/Transform`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Transform {
+        pub translation: super::geometry_msgs::Vector3,
+        pub rotation: super::geometry_msgs::Quaternion,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Transform {
+        const ROS_TYPE: &'static str = "geometry_msgs/Transform";
+        const MD5SUM: &'static str = "ac9eff44abf714214112b05d54a3cf9b";
+        const DEFINITION: &'static str = "# This represents the transform between two coordinate frames in free space.\n\nVector3 translation\nQuaternion rotation\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/TransformStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct TransformStamped {
+        pub header: super::std_msgs::Header,
+        /// the frame id of the child frame
+        pub child_frame_id: ::std::string::String,
+        pub transform: super::geometry_msgs::Transform,
+    }
+
+    impl ::serde_rosmsg::RosMessage for TransformStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/TransformStamped";
+        const MD5SUM: &'static str = "b5764a33bfeb3588febc2682852579b0";
+        const DEFINITION: &'static str = "# This expresses a transform from coordinate frame header.frame_id\n# to the coordinate frame child_frame_id\n#\n# This message is mostly used by the \n# <a href=\"http://wiki.ros.org/tf\">tf</a> package. \n# See its documentation for more information.\n\nHeader header\nstring child_frame_id # the frame id of the child frame\nTransform transform\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Transform\n# This represents the transform between two coordinate frames in free space.\n\nVector3 translation\nQuaternion rotation\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `geometry_msgs/Twist`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Twist {
+        pub linear: super::geometry_msgs::Vector3,
+        pub angular: super::geometry_msgs::Vector3,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Twist {
+        const ROS_TYPE: &'static str = "geometry_msgs/Twist";
+        const MD5SUM: &'static str = "9f195f881246fdfa2798d1d3eebca84a";
+        const DEFINITION: &'static str = "# This expresses velocity in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/TwistStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct TwistStamped {
+        pub header: super::std_msgs::Header,
+        pub twist: super::geometry_msgs::Twist,
+    }
+
+    impl ::serde_rosmsg::RosMessage for TwistStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/TwistStamped";
+        const MD5SUM: &'static str = "98d34b0043a2093cf9d9345ab6eef12e";
+        const DEFINITION: &'static str = "# A twist with reference coordinate frame and timestamp\nHeader header\nTwist twist\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Twist\n# This expresses velocity in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/TwistWithCovariance`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct TwistWithCovariance {
+        pub twist: super::geometry_msgs::Twist,
+        #[serde(with = "::serde_rosmsg::big_array")]
+        pub covariance: [f64; 36],
+    }
+
+    impl ::std::default::Default for TwistWithCovariance {
+        fn default() -> Self {
+            Self {
+                twist: ::std::default::Default::default(),
+                covariance: ::std::array::from_fn(|_| ::std::default::Default::default()),
+            }
+        }
+    }
+
+    impl ::serde_rosmsg::RosMessage for TwistWithCovariance {
+        const ROS_TYPE: &'static str = "geometry_msgs/TwistWithCovariance";
+        const MD5SUM: &'static str = "1fe8a28e6890a4cc3ae4c3ca5c7d82e6";
+        const DEFINITION: &'static str = "# This expresses velocity in free space with uncertainty.\n\nTwist twist\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Twist\n# This expresses velocity in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/TwistWithCovarianceStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct TwistWithCovarianceStamped {
+        pub header: super::std_msgs::Header,
+        pub twist: super::geometry_msgs::TwistWithCovariance,
+    }
+
+    impl ::serde_rosmsg::RosMessage for TwistWithCovarianceStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/TwistWithCovarianceStamped";
+        const MD5SUM: &'static str = "8927a1a12fb2607ceea095b2dc440a96";
+        const DEFINITION: &'static str = "# This represents an estimated twist with reference coordinate frame and timestamp.\nHeader header\nTwistWithCovariance twist\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/TwistWithCovariance\n# This expresses velocity in free space with uncertainty.\n\nTwist twist\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Twist\n# This expresses velocity in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/Vector3`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Vector3 {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Vector3 {
+        const ROS_TYPE: &'static str = "geometry_msgs/Vector3";
+        const MD5SUM: &'static str = "4a842b65f413084dc2b10fb484ea7f17";
+        const DEFINITION: &'static str = "# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/Vector3Stamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Vector3Stamped {
+        pub header: super::std_msgs::Header,
+        pub vector: super::geometry_msgs::Vector3,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Vector3Stamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/Vector3Stamped";
+        const MD5SUM: &'static str = "7b324c7325e683bf02a9b14b01090ec7";
+        const DEFINITION: &'static str = "# This represents a Vector3 with reference coordinate frame and timestamp\nHeader header\nVector3 vector\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/Wrench`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Wrench {
+        pub force: super::geometry_msgs::Vector3,
+        pub torque: super::geometry_msgs::Vector3,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Wrench {
+        const ROS_TYPE: &'static str = "geometry_msgs/Wrench";
+        const MD5SUM: &'static str = "4f539cf138b23283b520fd271b567936";
+        const DEFINITION: &'static str = "# This represents force in free space, separated into\n# its linear and angular parts.\nVector3  force\nVector3  torque\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `geometry_msgs/WrenchStamped`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct WrenchStamped {
+        pub header: super::std_msgs::Header,
+        pub wrench: super::geometry_msgs::Wrench,
+    }
+
+    impl ::serde_rosmsg::RosMessage for WrenchStamped {
+        const ROS_TYPE: &'static str = "geometry_msgs/WrenchStamped";
+        const MD5SUM: &'static str = "d78d3cb249ce23087ade7e7d0c40cfa7";
+        const DEFINITION: &'static str = "# A wrench with reference coordinate frame and timestamp\nHeader header\nWrench wrench\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Wrench\n# This represents force in free space, separated into\n# its linear and angular parts.\nVector3  force\nVector3  torque\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+}
+
+pub mod nav_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `nav_msgs/GridCells`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct GridCells {
+        pub header: super::std_msgs::Header,
+        pub cell_width: f32,
+        pub cell_height: f32,
+        pub cells: ::std::vec::Vec<super::geometry_msgs::Point>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for GridCells {
+        const ROS_TYPE: &'static str = "nav_msgs/GridCells";
+        const MD5SUM: &'static str = "b9e4f5df6d28e272ebde00a3994830f5";
+        const DEFINITION: &'static str = "#an array of cells in a 2D grid\nHeader header\nfloat32 cell_width\nfloat32 cell_height\ngeometry_msgs/Point[] cells\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n";
+    }
+
+    /// Message type `nav_msgs/MapMetaData`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct MapMetaData {
+        pub map_load_time: ::serde_rosmsg::time::Time,
+        pub resolution: f32,
+        pub width: u32,
+        pub height: u32,
+        pub origin: super::geometry_msgs::Pose,
+    }
+
+    impl ::serde_rosmsg::RosMessage for MapMetaData {
+        const ROS_TYPE: &'static str = "nav_msgs/MapMetaData";
+        const MD5SUM: &'static str = "10cfc8a2818024d3248802c00c95f11b";
+        const DEFINITION: &'static str = "# This hold basic information about the characterists of the OccupancyGrid\n\n# The time at which the map was loaded\ntime map_load_time\n# The map resolution [m/cell]\nfloat32 resolution\n# Map width [cells]\nuint32 width\n# Map height [cells]\nuint32 height\n# The origin of the map [m, m, rad].  This is the real-world pose of the\n# cell (0,0) in the map.\ngeometry_msgs/Pose origin\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `nav_msgs/OccupancyGrid`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct OccupancyGrid {
+        pub header: super::std_msgs::Header,
+        pub info: super::nav_msgs::MapMetaData,
+        pub data: ::std::vec::Vec<i8>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for OccupancyGrid {
+        const ROS_TYPE: &'static str = "nav_msgs/OccupancyGrid";
+        const MD5SUM: &'static str = "3381f2d731d4076ec5c71b0759edbe4e";
+        const DEFINITION: &'static str = "# This represents a 2-D grid map, in which each cell represents the probability of\n# occupancy.\n\nHeader header \n\n#MetaData for the map\nMapMetaData info\n\n# The map data, in row-major order, starting with (0,0).  Occupancy\n# probabilities are in the range [0,100].  Unknown is -1.\nint8[] data\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: nav_msgs/MapMetaData\n# This hold basic information about the characterists of the OccupancyGrid\n\n# The time at which the map was loaded\ntime map_load_time\n# The map resolution [m/cell]\nfloat32 resolution\n# Map width [cells]\nuint32 width\n# Map height [cells]\nuint32 height\n# The origin of the map [m, m, rad].  This is the real-world pose of the\n# cell (0,0) in the map.\ngeometry_msgs/Pose origin\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `nav_msgs/Odometry`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Odometry {
+        pub header: super::std_msgs::Header,
+        pub child_frame_id: ::std::string::String,
+        pub pose: super::geometry_msgs::PoseWithCovariance,
+        pub twist: super::geometry_msgs::TwistWithCovariance,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Odometry {
+        const ROS_TYPE: &'static str = "nav_msgs/Odometry";
+        const MD5SUM: &'static str = "cd5e73d190d741a2f92e81eda573aca7";
+        const DEFINITION: &'static str = "# This represents an estimate of a position and velocity in free space.  \n# The pose in this message should be specified in the coordinate frame given by header.frame_id.\n# The twist in this message should be specified in the coordinate frame given by the child_frame_id\nHeader header\nstring child_frame_id\ngeometry_msgs/PoseWithCovariance pose\ngeometry_msgs/TwistWithCovariance twist\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/PoseWithCovariance\n# This represents a pose in free space with uncertainty.\n\nPose pose\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n\n================================================================================\nMSG: geometry_msgs/TwistWithCovariance\n# This expresses velocity in free space with uncertainty.\n\nTwist twist\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n\n================================================================================\nMSG: geometry_msgs/Twist\n# This expresses velocity in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `nav_msgs/Path`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Path {
+        pub header: super::std_msgs::Header,
+        pub poses: ::std::vec::Vec<super::geometry_msgs::PoseStamped>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Path {
+        const ROS_TYPE: &'static str = "nav_msgs/Path";
+        const MD5SUM: &'static str = "6227e2b7e9cce15051f669a5e197bbf7";
+        const DEFINITION: &'static str = "#An array of poses that represents a Path for a robot to follow\nHeader header\ngeometry_msgs/PoseStamped[] poses\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/PoseStamped\n# A Pose with reference coordinate frame and timestamp\nHeader header\nPose pose\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `nav_msgs/GetMapRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct GetMapRequest {
+    }
+
+    impl ::serde_rosmsg::RosMessage for GetMapRequest {
+        const ROS_TYPE: &'static str = "nav_msgs/GetMapRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "# Get the map as a nav_msgs/OccupancyGrid\n";
+    }
+
+    /// Message type `nav_msgs/GetMapResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct GetMapResponse {
+        pub map: super::nav_msgs::OccupancyGrid,
+    }
+
+    impl ::serde_rosmsg::RosMessage for GetMapResponse {
+        const ROS_TYPE: &'static str = "nav_msgs/GetMapResponse";
+        const MD5SUM: &'static str = "6cdd0a18e0aff5b0a3ca2326a89b54ff";
+        const DEFINITION: &'static str = "nav_msgs/OccupancyGrid map\n\n================================================================================\nMSG: nav_msgs/OccupancyGrid\n# This represents a 2-D grid map, in which each cell represents the probability of\n# occupancy.\n\nHeader header \n\n#MetaData for the map\nMapMetaData info\n\n# The map data, in row-major order, starting with (0,0).  Occupancy\n# probabilities are in the range [0,100].  Unknown is -1.\nint8[] data\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: nav_msgs/MapMetaData\n# This hold basic information about the characterists of the OccupancyGrid\n\n# The time at which the map was loaded\ntime map_load_time\n# The map resolution [m/cell]\nfloat32 resolution\n# Map width [cells]\nuint32 width\n# Map height [cells]\nuint32 height\n# The origin of the map [m, m, rad].  This is the real-world pose of the\n# cell (0,0) in the map.\ngeometry_msgs/Pose origin\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Service type `nav_msgs/GetMap`, made of `GetMapRequest` and `GetMapResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct GetMap;
+
+    impl GetMap {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "nav_msgs/GetMap";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "6cdd0a18e0aff5b0a3ca2326a89b54ff";
+    }
+
+    /// Message type `nav_msgs/GetPlanRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct GetPlanRequest {
+        pub start: super::geometry_msgs::PoseStamped,
+        pub goal: super::geometry_msgs::PoseStamped,
+        pub tolerance: f32,
+    }
+
+    impl ::serde_rosmsg::RosMessage for GetPlanRequest {
+        const ROS_TYPE: &'static str = "nav_msgs/GetPlanRequest";
+        const MD5SUM: &'static str = "e25a43e0752bcca599a8c2eef8282df8";
+        const DEFINITION: &'static str = "# Get a plan from the current position to the goal Pose \n\n# The start pose for the plan\ngeometry_msgs/PoseStamped start\n\n# The final pose of the goal position\ngeometry_msgs/PoseStamped goal\n\n# If the goal is obstructed, how many meters the planner can \n# relax the constraint in x and y before failing. \nfloat32 tolerance\n\n================================================================================\nMSG: geometry_msgs/PoseStamped\n# A Pose with reference coordinate frame and timestamp\nHeader header\nPose pose\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `nav_msgs/GetPlanResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct GetPlanResponse {
+        pub plan: super::nav_msgs::Path,
+    }
+
+    impl ::serde_rosmsg::RosMessage for GetPlanResponse {
+        const ROS_TYPE: &'static str = "nav_msgs/GetPlanResponse";
+        const MD5SUM: &'static str = "0002bc113c0259d71f6cf8cbc9430e18";
+        const DEFINITION: &'static str = "nav_msgs/Path plan\n\n================================================================================\nMSG: nav_msgs/Path\n#An array of poses that represents a Path for a robot to follow\nHeader header\ngeometry_msgs/PoseStamped[] poses\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/PoseStamped\n# A Pose with reference coordinate frame and timestamp\nHeader header\nPose pose\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Service type `nav_msgs/GetPlan`, made of `GetPlanRequest` and `GetPlanResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct GetPlan;
+
+    impl GetPlan {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "nav_msgs/GetPlan";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "421c8ea4d21c6c9db7054b4bbdf1e024";
+    }
+
+    /// Message type `nav_msgs/LoadMapRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct LoadMapRequest {
+        pub map_url: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for LoadMapRequest {
+        const ROS_TYPE: &'static str = "nav_msgs/LoadMapRequest";
+        const MD5SUM: &'static str = "3813ba1ae85fbcd4dc88c90f1426b90b";
+        const DEFINITION: &'static str = "# URL of map resource\n# Can be an absolute path to a file: file:///path/to/maps/floor1.yaml\n# Or, relative to a ROS package: package://my_ros_package/maps/floor2.yaml\nstring map_url\n";
+    }
+
+    /// Message type `nav_msgs/LoadMapResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct LoadMapResponse {
+        pub map: super::nav_msgs::OccupancyGrid,
+        pub result: u8,
+    }
+
+    impl LoadMapResponse {
+        pub const RESULT_SUCCESS: u8 = 0;
+        pub const RESULT_MAP_DOES_NOT_EXIST: u8 = 1;
+        pub const RESULT_INVALID_MAP_DATA: u8 = 2;
+        pub const RESULT_INVALID_MAP_METADATA: u8 = 3;
+        pub const RESULT_UNDEFINED_FAILURE: u8 = 255;
+    }
+
+    impl ::serde_rosmsg::RosMessage for LoadMapResponse {
+        const ROS_TYPE: &'static str = "nav_msgs/LoadMapResponse";
+        const MD5SUM: &'static str = "079b9c828e9f7c1918bf86932fd7267e";
+        const DEFINITION: &'static str = "# Result code defintions\nuint8 RESULT_SUCCESS=0\nuint8 RESULT_MAP_DOES_NOT_EXIST=1\nuint8 RESULT_INVALID_MAP_DATA=2\nuint8 RESULT_INVALID_MAP_METADATA=3\nuint8 RESULT_UNDEFINED_FAILURE=255\n\n# Returned map is only valid if result equals RESULT_SUCCESS\nnav_msgs/OccupancyGrid map\nuint8 result\n\n================================================================================\nMSG: nav_msgs/OccupancyGrid\n# This represents a 2-D grid map, in which each cell represents the probability of\n# occupancy.\n\nHeader header \n\n#MetaData for the map\nMapMetaData info\n\n# The map data, in row-major order, starting with (0,0).  Occupancy\n# probabilities are in the range [0,100].  Unknown is -1.\nint8[] data\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: nav_msgs/MapMetaData\n# This hold basic information about the characterists of the OccupancyGrid\n\n# The time at which the map was loaded\ntime map_load_time\n# The map resolution [m/cell]\nfloat32 resolution\n# Map width [cells]\nuint32 width\n# Map height [cells]\nuint32 height\n# The origin of the map [m, m, rad].  This is the real-world pose of the\n# cell (0,0) in the map.\ngeometry_msgs/Pose origin\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Service type `nav_msgs/LoadMap`, made of `LoadMapRequest` and `LoadMapResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct LoadMap;
+
+    impl LoadMap {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "nav_msgs/LoadMap";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "22e647fdfbe3b23c8c9f419908afaebd";
+    }
+
+    /// Message type `nav_msgs/SetMapRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct SetMapRequest {
+        pub map: super::nav_msgs::OccupancyGrid,
+        pub initial_pose: super::geometry_msgs::PoseWithCovarianceStamped,
+    }
+
+    impl ::serde_rosmsg::RosMessage for SetMapRequest {
+        const ROS_TYPE: &'static str = "nav_msgs/SetMapRequest";
+        const MD5SUM: &'static str = "91149a20d7be299b87c340df8cc94fd4";
+        const DEFINITION: &'static str = "# Set a new map together with an initial pose\nnav_msgs/OccupancyGrid map\ngeometry_msgs/PoseWithCovarianceStamped initial_pose\n\n================================================================================\nMSG: nav_msgs/OccupancyGrid\n# This represents a 2-D grid map, in which each cell represents the probability of\n# occupancy.\n\nHeader header \n\n#MetaData for the map\nMapMetaData info\n\n# The map data, in row-major order, starting with (0,0).  Occupancy\n# probabilities are in the range [0,100].  Unknown is -1.\nint8[] data\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: nav_msgs/MapMetaData\n# This hold basic information about the characterists of the OccupancyGrid\n\n# The time at which the map was loaded\ntime map_load_time\n# The map resolution [m/cell]\nfloat32 resolution\n# Map width [cells]\nuint32 width\n# Map height [cells]\nuint32 height\n# The origin of the map [m, m, rad].  This is the real-world pose of the\n# cell (0,0) in the map.\ngeometry_msgs/Pose origin\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n\n================================================================================\nMSG: geometry_msgs/PoseWithCovarianceStamped\n# This expresses an estimated pose with a reference coordinate frame and timestamp\n\nHeader header\nPoseWithCovariance pose\n\n================================================================================\nMSG: geometry_msgs/PoseWithCovariance\n# This represents a pose in free space with uncertainty.\n\nPose pose\n\n# Row-major representation of the 6x6 covariance matrix\n# The orientation parameters use a fixed-axis representation.\n# In order, the parameters are:\n# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)\nfloat64[36] covariance\n";
+    }
+
+    /// Message type `nav_msgs/SetMapResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct SetMapResponse {
+        pub success: bool,
+    }
+
+    impl ::serde_rosmsg::RosMessage for SetMapResponse {
+        const ROS_TYPE: &'static str = "nav_msgs/SetMapResponse";
+        const MD5SUM: &'static str = "358e233cde0c8a8bcfea4ce193f8fc15";
+        const DEFINITION: &'static str = "bool success\n";
+    }
+
+    /// Service type `nav_msgs/SetMap`, made of `SetMapRequest` and `SetMapResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct SetMap;
+
+    impl SetMap {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "nav_msgs/SetMap";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "c36922319011e63ed7784112ad4fdd32";
+    }
+}
+
+pub mod sensor_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `sensor_msgs/BatteryState`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct BatteryState {
+        pub header: super::std_msgs::Header,
+        /// Voltage in Volts (Mandatory)
+        pub voltage: f32,
+        /// Temperature in Degrees Celsius (If unmeasured NaN)
+        pub temperature: f32,
+        /// Negative when discharging (A)  (If unmeasured NaN)
+        pub current: f32,
+        /// Current charge in Ah  (If unmeasured NaN)
+        pub charge: f32,
+        /// Capacity in Ah (last full capacity)  (If unmeasured NaN)
+        pub capacity: f32,
+        /// Capacity in Ah (design capacity)  (If unmeasured NaN)
+        pub design_capacity: f32,
+        /// Charge percentage on 0 to 1 range  (If unmeasured NaN)
+        pub percentage: f32,
+        /// The charging status as reported. Values defined above
+        pub power_supply_status: u8,
+        /// The battery health metric. Values defined above
+        pub power_supply_health: u8,
+        /// The battery chemistry. Values defined above
+        pub power_supply_technology: u8,
+        /// True if the battery is present
+        pub present: bool,
+        /// An array of individual cell voltages for each cell in the pack
+        pub cell_voltage: ::std::vec::Vec<f32>,
+        /// An array of individual cell temperatures for each cell in the pack
+        pub cell_temperature: ::std::vec::Vec<f32>,
+        /// The location into which the battery is inserted. (slot number or plug)
+        pub location: ::std::string::String,
+        /// The best approximation of the battery serial number
+        pub serial_number: ::std::string::String,
+    }
+
+    impl BatteryState {
+        pub const POWER_SUPPLY_STATUS_UNKNOWN: u8 = 0;
+        pub const POWER_SUPPLY_STATUS_CHARGING: u8 = 1;
+        pub const POWER_SUPPLY_STATUS_DISCHARGING: u8 = 2;
+        pub const POWER_SUPPLY_STATUS_NOT_CHARGING: u8 = 3;
+        pub const POWER_SUPPLY_STATUS_FULL: u8 = 4;
+        pub const POWER_SUPPLY_HEALTH_UNKNOWN: u8 = 0;
+        pub const POWER_SUPPLY_HEALTH_GOOD: u8 = 1;
+        pub const POWER_SUPPLY_HEALTH_OVERHEAT: u8 = 2;
+        pub const POWER_SUPPLY_HEALTH_DEAD: u8 = 3;
+        pub const POWER_SUPPLY_HEALTH_OVERVOLTAGE: u8 = 4;
+        pub const POWER_SUPPLY_HEALTH_UNSPEC_FAILURE: u8 = 5;
+        pub const POWER_SUPPLY_HEALTH_COLD: u8 = 6;
+        pub const POWER_SUPPLY_HEALTH_WATCHDOG_TIMER_EXPIRE: u8 = 7;
+        pub const POWER_SUPPLY_HEALTH_SAFETY_TIMER_EXPIRE: u8 = 8;
+        pub const POWER_SUPPLY_TECHNOLOGY_UNKNOWN: u8 = 0;
+        pub const POWER_SUPPLY_TECHNOLOGY_NIMH: u8 = 1;
+        pub const POWER_SUPPLY_TECHNOLOGY_LION: u8 = 2;
+        pub const POWER_SUPPLY_TECHNOLOGY_LIPO: u8 = 3;
+        pub const POWER_SUPPLY_TECHNOLOGY_LIFE: u8 = 4;
+        pub const POWER_SUPPLY_TECHNOLOGY_NICD: u8 = 5;
+        pub const POWER_SUPPLY_TECHNOLOGY_LIMN: u8 = 6;
+    }
+
+    impl ::serde_rosmsg::RosMessage for BatteryState {
+        const ROS_TYPE: &'static str = "sensor_msgs/BatteryState";
+        const MD5SUM: &'static str = "4ddae7f048e32fda22cac764685e3974";
+        const DEFINITION: &'static str = "# Constants are chosen to match the enums in the linux kernel\n# defined in include/linux/power_supply.h as of version 3.7\n# The one difference is for style reasons the constants are\n# all uppercase not mixed case.\n\n# Power supply status constants\nuint8 POWER_SUPPLY_STATUS_UNKNOWN = 0\nuint8 POWER_SUPPLY_STATUS_CHARGING = 1\nuint8 POWER_SUPPLY_STATUS_DISCHARGING = 2\nuint8 POWER_SUPPLY_STATUS_NOT_CHARGING = 3\nuint8 POWER_SUPPLY_STATUS_FULL = 4\n\n# Power supply health constants\nuint8 POWER_SUPPLY_HEALTH_UNKNOWN = 0\nuint8 POWER_SUPPLY_HEALTH_GOOD = 1\nuint8 POWER_SUPPLY_HEALTH_OVERHEAT = 2\nuint8 POWER_SUPPLY_HEALTH_DEAD = 3\nuint8 POWER_SUPPLY_HEALTH_OVERVOLTAGE = 4\nuint8 POWER_SUPPLY_HEALTH_UNSPEC_FAILURE = 5\nuint8 POWER_SUPPLY_HEALTH_COLD = 6\nuint8 POWER_SUPPLY_HEALTH_WATCHDOG_TIMER_EXPIRE = 7\nuint8 POWER_SUPPLY_HEALTH_SAFETY_TIMER_EXPIRE = 8\n\n# Power supply technology (chemistry) constants\nuint8 POWER_SUPPLY_TECHNOLOGY_UNKNOWN = 0\nuint8 POWER_SUPPLY_TECHNOLOGY_NIMH = 1\nuint8 POWER_SUPPLY_TECHNOLOGY_LION = 2\nuint8 POWER_SUPPLY_TECHNOLOGY_LIPO = 3\nuint8 POWER_SUPPLY_TECHNOLOGY_LIFE = 4\nuint8 POWER_SUPPLY_TECHNOLOGY_NICD = 5\nuint8 POWER_SUPPLY_TECHNOLOGY_LIMN = 6\n\nHeader  header\nfloat32 voltage          # Voltage in Volts (Mandatory)\nfloat32 temperature      # Temperature in Degrees Celsius (If unmeasured NaN)\nfloat32 current          # Negative when discharging (A)  (If unmeasured NaN)\nfloat32 charge           # Current charge in Ah  (If unmeasured NaN)\nfloat32 capacity         # Capacity in Ah (last full capacity)  (If unmeasured NaN)\nfloat32 design_capacity  # Capacity in Ah (design capacity)  (If unmeasured NaN)\nfloat32 percentage       # Charge percentage on 0 to 1 range  (If unmeasured NaN)\nuint8   power_supply_status     # The charging status as reported. Values defined above\nuint8   power_supply_health     # The battery health metric. Values defined above\nuint8   power_supply_technology # The battery chemistry. Values defined above\nbool    present          # True if the battery is present\n\nfloat32[] cell_voltage   # An array of individual cell voltages for each cell in the pack\n                         # If individual voltages unknown but number of cells known set each to NaN\nfloat32[] cell_temperature # An array of individual cell temperatures for each cell in the pack\n                           # If individual temperatures unknown but number of cells known set each to NaN\nstring location          # The location into which the battery is inserted. (slot number or plug)\nstring serial_number     # The best approximation of the battery serial number\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/CameraInfo`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct CameraInfo {
+        /// Header timestamp should be acquisition time of image
+        pub header: super::std_msgs::Header,
+        pub height: u32,
+        pub width: u32,
+        pub distortion_model: ::std::string::String,
+        pub D: ::std::vec::Vec<f64>,
+        /// 3x3 row-major matrix
+        pub K: [f64; 9],
+        /// 3x3 row-major matrix
+        pub R: [f64; 9],
+        /// 3x4 row-major matrix
+        pub P: [f64; 12],
+        pub binning_x: u32,
+        pub binning_y: u32,
+        pub roi: super::sensor_msgs::RegionOfInterest,
+    }
+
+    impl ::serde_rosmsg::RosMessage for CameraInfo {
+        const ROS_TYPE: &'static str = "sensor_msgs/CameraInfo";
+        const MD5SUM: &'static str = "c9a58c1b0b154e0e6da7578cb991d214";
+        const DEFINITION: &'static str = "# This message defines meta information for a camera. It should be in a\n# camera namespace on topic \"camera_info\" and accompanied by up to five\n# image topics named:\n#\n#   image_raw - raw data from the camera driver, possibly Bayer encoded\n#   image            - monochrome, distorted\n#   image_color      - color, distorted\n#   image_rect       - monochrome, rectified\n#   image_rect_color - color, rectified\n#\n# The image_pipeline contains packages (image_proc, stereo_image_proc)\n# for producing the four processed image topics from image_raw and\n# camera_info. The meaning of the camera parameters are described in\n# detail at http://www.ros.org/wiki/image_pipeline/CameraInfo.\n\n#######################################################################\n#                     Image acquisition info                          #\n#######################################################################\n\n# Time of image acquisition, camera coordinate frame ID\nHeader header    # Header timestamp should be acquisition time of image\n                 # Header frame_id should be optical frame of camera\n                 # origin of frame should be optical center of camera\n                 # +x should point to the right in the image\n                 # +y should point down in the image\n                 # +z should point into the plane of the image\n\n\n#######################################################################\n#                      Calibration Parameters                         #\n#######################################################################\n# These are fixed during camera calibration. Their values will be the #\n# same in all messages until the camera is recalibrated. Note that    #\n# self-calibrating systems may \"recalibrate\" frequently.              #\n#######################################################################\n\n# The image dimensions with which the camera was calibrated. Normally\n# this will be the full camera resolution in pixels.\nuint32 height\nuint32 width\n\n# The distortion model used. Supported models are listed in\n# sensor_msgs/distortion_models.h. For most cameras, \"plumb_bob\" - a\n# simple model of radial and tangential distortion - is sufficient.\nstring distortion_model\n\n# The distortion parameters, size depending on the distortion model.\n# For \"plumb_bob\", the 5 parameters are: (k1, k2, t1, t2, k3).\nfloat64[] D\n\n# Intrinsic camera matrix for the raw (distorted) images.\nfloat64[9]  K # 3x3 row-major matrix\n\n# Rectification matrix (stereo cameras only)\nfloat64[9]  R # 3x3 row-major matrix\n\n# Projection/camera matrix\nfloat64[12] P # 3x4 row-major matrix\n\n\n#######################################################################\n#                      Operational Parameters                         #\n#######################################################################\n\n# Binning refers here to any camera setting which combines rectangular\n#  neighborhoods of pixels into larger \"super-pixels.\" It reduces the\n#  resolution of the output image to\n#  (width / binning_x) x (height / binning_y).\n# The default values binning_x = binning_y = 0 is considered the same\n#  as binning_x = binning_y = 1 (no subsampling).\nuint32 binning_x\nuint32 binning_y\n\n# Region of interest (subwindow of full camera resolution), given in\n#  full resolution (unbinned) image coordinates. A particular ROI\n#  always denotes the same window of pixels on the camera sensor,\n#  regardless of binning settings.\n# The default setting of roi (all values 0) is considered the same as\n#  full resolution (roi.width = width, roi.height = height).\nRegionOfInterest roi\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: sensor_msgs/RegionOfInterest\n# This message is used to specify a region of interest within an image.\n#\n# When used to specify the ROI setting of the camera when the image was\n# taken, the height and width fields should either match the height and\n# width fields for the associated image; or height = width = 0\n# indicates that the full resolution image was captured.\n\nuint32 x_offset  # Leftmost pixel of the ROI\n                 # (0 if the ROI includes the left edge of the image)\nuint32 y_offset  # Topmost pixel of the ROI\n                 # (0 if the ROI includes the top edge of the image)\nuint32 height    # Height of ROI\nuint32 width     # Width of ROI\n\n# True if a distinct rectified ROI should be calculated from the \"raw\"\n# ROI in this message. Typically this should be False if the full image\n# is captured (ROI not used), and True if a subwindow is captured (ROI\n# used).\nbool do_rectify\n";
+    }
+
+    /// Message type `sensor_msgs/ChannelFloat32`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct ChannelFloat32 {
+        pub name: ::std::string::String,
+        pub values: ::std::vec::Vec<f32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for ChannelFloat32 {
+        const ROS_TYPE: &'static str = "sensor_msgs/ChannelFloat32";
+        const MD5SUM: &'static str = "3d40139cdd33dfedcb71ffeeeb42ae7f";
+        const DEFINITION: &'static str = "# This message is used by the PointCloud message to hold optional data\n# associated with each point in the cloud. The length of the values\n# array should be the same as the length of the points array in the\n# PointCloud, and each value should be associated with the corresponding\n# point.\n\n# Channel names in existing practice include:\n#   \"u\", \"v\" - row and column (respectively) in the left stereo image.\n#              This is opposite to usual conventions but remains for\n#              historical reasons. The newer PointCloud2 message has no\n#              such problem.\n#   \"rgb\" - For point clouds produced by color stereo cameras. uint8\n#           (R,G,B) values packed into the least significant 24 bits,\n#           in order.\n#   \"intensity\" - laser or pixel intensity.\n#   \"distance\"\n\n# The channel name should give semantics of the channel (e.g.\n# \"intensity\" instead of \"value\").\nstring name\n\n# The values array should be 1-1 with the elements of the associated\n# PointCloud.\nfloat32[] values\n";
+    }
+
+    /// Message type `sensor_msgs/CompressedImage`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct CompressedImage {
+        /// Header timestamp should be acquisition time of image
+        pub header: super::std_msgs::Header,
+        /// Specifies the format of the data
+        pub format: ::std::string::String,
+        /// Compressed image buffer
+        pub data: ::std::vec::Vec<u8>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for CompressedImage {
+        const ROS_TYPE: &'static str = "sensor_msgs/CompressedImage";
+        const MD5SUM: &'static str = "8f7a12909da2c9d3332d540a0977563f";
+        const DEFINITION: &'static str = "# This message contains a compressed image\n\nHeader header        # Header timestamp should be acquisition time of image\n                     # Header frame_id should be optical frame of camera\n                     # origin of frame should be optical center of camera\n                     # +x should point to the right in the image\n                     # +y should point down in the image\n                     # +z should point into to plane of the image\n\nstring format        # Specifies the format of the data\n                     #   Acceptable values:\n                     #     jpeg, png\nuint8[] data         # Compressed image buffer\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/FluidPressure`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct FluidPressure {
+        /// timestamp of the measurement
+        pub header: super::std_msgs::Header,
+        /// Absolute pressure reading in Pascals.
+        pub fluid_pressure: f64,
+        /// 0 is interpreted as variance unknown
+        pub variance: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for FluidPressure {
+        const ROS_TYPE: &'static str = "sensor_msgs/FluidPressure";
+        const MD5SUM: &'static str = "804dc5cea1c5306d6a2eb80b9833befe";
+        const DEFINITION: &'static str = " # Single pressure reading.  This message is appropriate for measuring the\n # pressure inside of a fluid (air, water, etc).  This also includes\n # atmospheric or barometric pressure.\n\n # This message is not appropriate for force/pressure contact sensors.\n\n Header header           # timestamp of the measurement\n                         # frame_id is the location of the pressure sensor\n\n float64 fluid_pressure  # Absolute pressure reading in Pascals.\n\n float64 variance        # 0 is interpreted as variance unknown\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/Illuminance`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Illuminance {
+        /// timestamp is the time the illuminance was measured
+        pub header: super::std_msgs::Header,
+        /// Measurement of the Photometric Illuminance in Lux.
+        pub illuminance: f64,
+        /// 0 is interpreted as variance unknown
+        pub variance: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Illuminance {
+        const ROS_TYPE: &'static str = "sensor_msgs/Illuminance";
+        const MD5SUM: &'static str = "8cf5febb0952fca9d650c3d11a81a188";
+        const DEFINITION: &'static str = " # Single photometric illuminance measurement.  Light should be assumed to be\n # measured along the sensor's x-axis (the area of detection is the y-z plane).\n # The illuminance should have a 0 or positive value and be received with\n # the sensor's +X axis pointing toward the light source.\n\n # Photometric illuminance is the measure of the human eye's sensitivity of the\n # intensity of light encountering or passing through a surface.\n\n # All other Photometric and Radiometric measurements should\n # not use this message.\n # This message cannot represent:\n # Luminous intensity (candela/light source output)\n # Luminance (nits/light output per area)\n # Irradiance (watt/area), etc.\n\n Header header           # timestamp is the time the illuminance was measured\n                         # frame_id is the location and direction of the reading\n\n float64 illuminance     # Measurement of the Photometric Illuminance in Lux.\n\n float64 variance        # 0 is interpreted as variance unknown\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/Image`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Image {
+        /// Header timestamp should be acquisition time of image
+        pub header: super::std_msgs::Header,
+        /// image height, that is, number of rows
+        pub height: u32,
+        /// image width, that is, number of columns
+        pub width: u32,
+        /// Encoding of pixels -- channel meaning, ordering, size
+        pub encoding: ::std::string::String,
+        /// is this data bigendian?
+        pub is_bigendian: u8,
+        /// Full row length in bytes
+        pub step: u32,
+        /// actual matrix data, size is (step * rows)
+        pub data: ::std::vec::Vec<u8>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Image {
+        const ROS_TYPE: &'static str = "sensor_msgs/Image";
+        const MD5SUM: &'static str = "060021388200f6f0f447d0fcd9c64743";
+        const DEFINITION: &'static str = "# This message contains an uncompressed image\n# (0, 0) is at top-left corner of image\n#\n\nHeader header        # Header timestamp should be acquisition time of image\n                     # Header frame_id should be optical frame of camera\n                     # origin of frame should be optical center of camera\n                     # +x should point to the right in the image\n                     # +y should point down in the image\n                     # +z should point into to plane of the image\n                     # If the frame_id here and the frame_id of the CameraInfo\n                     # message associated with the image conflict\n                     # the behavior is undefined\n\nuint32 height         # image height, that is, number of rows\nuint32 width          # image width, that is, number of columns\n\n# The legal values for encoding are in file src/image_encodings.cpp\n# If you want to standardize a new string format, join\n# ros-users@lists.sourceforge.net and send an email proposing a new encoding.\n\nstring encoding       # Encoding of pixels -- channel meaning, ordering, size\n                      # taken from the list of strings in include/sensor_msgs/image_encodings.h\n\nuint8 is_bigendian    # is this data bigendian?\nuint32 step           # Full row length in bytes\nuint8[] data          # actual matrix data, size is (step * rows)\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/Imu`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Imu {
+        pub header: super::std_msgs::Header,
+        pub orientation: super::geometry_msgs::Quaternion,
+        /// Row major about x, y, z axes
+        pub orientation_covariance: [f64; 9],
+        pub angular_velocity: super::geometry_msgs::Vector3,
+        /// Row major about x, y, z axes
+        pub angular_velocity_covariance: [f64; 9],
+        pub linear_acceleration: super::geometry_msgs::Vector3,
+        /// Row major x, y z
+        pub linear_acceleration_covariance: [f64; 9],
+    }
+
+    impl ::serde_rosmsg::RosMessage for Imu {
+        const ROS_TYPE: &'static str = "sensor_msgs/Imu";
+        const MD5SUM: &'static str = "6a62c6daae103f4ff57a132d6f95cec2";
+        const DEFINITION: &'static str = "# This is a message to hold data from an IMU (Inertial Measurement Unit)\n#\n# Accelerations should be in m/s^2 (not in g's), and rotational velocity should be in rad/sec\n#\n# If the covariance of the measurement is known, it should be filled in (if all you know is the \n# variance of each measurement, e.g. from the datasheet, just put those along the diagonal)\n# A covariance matrix of all zeros will be interpreted as \"covariance unknown\", and to use the\n# data a covariance will have to be assumed or gotten from some other source\n#\n# If you have no estimate for one of the data elements (e.g. your IMU doesn't produce an orientation \n# estimate), please set element 0 of the associated covariance matrix to -1\n# If you are interpreting this message, please check for a value of -1 in the first element of each \n# covariance matrix, and disregard the associated estimate.\n\nHeader header\n\ngeometry_msgs/Quaternion orientation\nfloat64[9] orientation_covariance # Row major about x, y, z axes\n\ngeometry_msgs/Vector3 angular_velocity\nfloat64[9] angular_velocity_covariance # Row major about x, y, z axes\n\ngeometry_msgs/Vector3 linear_acceleration\nfloat64[9] linear_acceleration_covariance # Row major x, y z \n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `sensor_msgs/JointState`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct JointState {
+        pub header: super::std_msgs::Header,
+        pub name: ::std::vec::Vec<::std::string::String>,
+        pub position: ::std::vec::Vec<f64>,
+        pub velocity: ::std::vec::Vec<f64>,
+        pub effort: ::std::vec::Vec<f64>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for JointState {
+        const ROS_TYPE: &'static str = "sensor_msgs/JointState";
+        const MD5SUM: &'static str = "3066dcd76a6cfaef579bd0f34173e9fd";
+        const DEFINITION: &'static str = "# This is a message that holds data to describe the state of a set of torque controlled joints. \n#\n# The state of each joint (revolute or prismatic) is defined by:\n#  * the position of the joint (rad or m),\n#  * the velocity of the joint (rad/s or m/s) and \n#  * the effort that is applied in the joint (Nm or N).\n#\n# Each joint is uniquely identified by its name\n# The header specifies the time at which the joint states were recorded. All the joint states\n# in one message have to be recorded at the same time.\n#\n# This message consists of a multiple arrays, one for each part of the joint state. \n# The goal is to make each of the fields optional. When e.g. your joints have no\n# effort associated with them, you can leave the effort array empty. \n#\n# All arrays in this message should have the same size, or be empty.\n# This is the only way to uniquely associate the joint name with the correct\n# states.\n\n\nHeader header\n\nstring[] name\nfloat64[] position\nfloat64[] velocity\nfloat64[] effort\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/Joy`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Joy {
+        /// timestamp in the header is the time the data is received from the joystick
+        pub header: super::std_msgs::Header,
+        /// the axes measurements from a joystick
+        pub axes: ::std::vec::Vec<f32>,
+        /// the buttons measurements from a joystick
+        pub buttons: ::std::vec::Vec<i32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Joy {
+        const ROS_TYPE: &'static str = "sensor_msgs/Joy";
+        const MD5SUM: &'static str = "5a9ea5f83505693b71e785041e67a8bb";
+        const DEFINITION: &'static str = "# Reports the state of a joysticks axes and buttons.\nHeader header           # timestamp in the header is the time the data is received from the joystick\nfloat32[] axes          # the axes measurements from a joystick\nint32[] buttons         # the buttons measurements from a joystick \n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/JoyFeedback`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct JoyFeedback {
+        pub r#type: u8,
+        pub id: u8,
+        pub intensity: f32,
+    }
+
+    impl JoyFeedback {
+        pub const TYPE_LED: u8 = 0;
+        pub const TYPE_RUMBLE: u8 = 1;
+        pub const TYPE_BUZZER: u8 = 2;
+    }
+
+    impl ::serde_rosmsg::RosMessage for JoyFeedback {
+        const ROS_TYPE: &'static str = "sensor_msgs/JoyFeedback";
+        const MD5SUM: &'static str = "f4dcd73460360d98f36e55ee7f2e46f1";
+        const DEFINITION: &'static str = "# Declare of the type of feedback\nuint8 TYPE_LED    = 0\nuint8 TYPE_RUMBLE = 1\nuint8 TYPE_BUZZER = 2\n\nuint8 type\n\n# This will hold an id number for each type of each feedback.\n# Example, the first led would be id=0, the second would be id=1\nuint8 id\n\n# Intensity of the feedback, from 0.0 to 1.0, inclusive.  If device is\n# actually binary, driver should treat 0<=x<0.5 as off, 0.5<=x<=1 as on.\nfloat32 intensity\n\n";
+    }
+
+    /// Message type `sensor_msgs/JoyFeedbackArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct JoyFeedbackArray {
+        pub array: ::std::vec::Vec<super::sensor_msgs::JoyFeedback>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for JoyFeedbackArray {
+        const ROS_TYPE: &'static str = "sensor_msgs/JoyFeedbackArray";
+        const MD5SUM: &'static str = "cde5730a895b1fc4dee6f91b754b213d";
+        const DEFINITION: &'static str = "# This message publishes values for multiple feedback at once. \nJoyFeedback[] array\n\n================================================================================\nMSG: sensor_msgs/JoyFeedback\n# Declare of the type of feedback\nuint8 TYPE_LED    = 0\nuint8 TYPE_RUMBLE = 1\nuint8 TYPE_BUZZER = 2\n\nuint8 type\n\n# This will hold an id number for each type of each feedback.\n# Example, the first led would be id=0, the second would be id=1\nuint8 id\n\n# Intensity of the feedback, from 0.0 to 1.0, inclusive.  If device is\n# actually binary, driver should treat 0<=x<0.5 as off, 0.5<=x<=1 as on.\nfloat32 intensity\n\n";
+    }
+
+    /// Message type `sensor_msgs/LaserEcho`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct LaserEcho {
+        /// Multiple values of ranges or intensities.
+        pub echoes: ::std::vec::Vec<f32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for LaserEcho {
+        const ROS_TYPE: &'static str = "sensor_msgs/LaserEcho";
+        const MD5SUM: &'static str = "8bc5ae449b200fba4d552b4225586696";
+        const DEFINITION: &'static str = "# This message is a submessage of MultiEchoLaserScan and is not intended\n# to be used separately.\n\nfloat32[] echoes  # Multiple values of ranges or intensities.\n                  # Each array represents data from the same angle increment.\n";
+    }
+
+    /// Message type `sensor_msgs/LaserScan`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct LaserScan {
+        /// timestamp in the header is the acquisition time of
+        pub header: super::std_msgs::Header,
+        /// start angle of the scan [rad]
+        pub angle_min: f32,
+        /// end angle of the scan [rad]
+        pub angle_max: f32,
+        /// angular distance between measurements [rad]
+        pub angle_increment: f32,
+        /// time between measurements [seconds] - if your scanner
+        pub time_increment: f32,
+        /// time between scans [seconds]
+        pub scan_time: f32,
+        /// minimum range value [m]
+        pub range_min: f32,
+        /// maximum range value [m]
+        pub range_max: f32,
+        /// range data [m] (Note: values < range_min or > range_max should be discarded)
+        pub ranges: ::std::vec::Vec<f32>,
+        /// intensity data [device-specific units].  If your
+        pub intensities: ::std::vec::Vec<f32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for LaserScan {
+        const ROS_TYPE: &'static str = "sensor_msgs/LaserScan";
+        const MD5SUM: &'static str = "90c7ef2dc6895d81024acba2ac42f369";
+        const DEFINITION: &'static str = "# Single scan from a planar laser range-finder\n#\n# If you have another ranging device with different behavior (e.g. a sonar\n# array), please find or create a different message, since applications\n# will make fairly laser-specific assumptions about this data\n\nHeader header            # timestamp in the header is the acquisition time of \n                         # the first ray in the scan.\n                         #\n                         # in frame frame_id, angles are measured around \n                         # the positive Z axis (counterclockwise, if Z is up)\n                         # with zero angle being forward along the x axis\n                         \nfloat32 angle_min        # start angle of the scan [rad]\nfloat32 angle_max        # end angle of the scan [rad]\nfloat32 angle_increment  # angular distance between measurements [rad]\n\nfloat32 time_increment   # time between measurements [seconds] - if your scanner\n                         # is moving, this will be used in interpolating position\n                         # of 3d points\nfloat32 scan_time        # time between scans [seconds]\n\nfloat32 range_min        # minimum range value [m]\nfloat32 range_max        # maximum range value [m]\n\nfloat32[] ranges         # range data [m] (Note: values < range_min or > range_max should be discarded)\nfloat32[] intensities    # intensity data [device-specific units].  If your\n                         # device does not provide intensities, please leave\n                         # the array empty.\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/MagneticField`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct MagneticField {
+        /// timestamp is the time the
+        pub header: super::std_msgs::Header,
+        /// x, y, and z components of the
+        pub magnetic_field: super::geometry_msgs::Vector3,
+        /// Row major about x, y, z axes
+        pub magnetic_field_covariance: [f64; 9],
+    }
+
+    impl ::serde_rosmsg::RosMessage for MagneticField {
+        const ROS_TYPE: &'static str = "sensor_msgs/MagneticField";
+        const MD5SUM: &'static str = "2f3b0b43eed0c9501de0fa3ff89a45aa";
+        const DEFINITION: &'static str = " # Measurement of the Magnetic Field vector at a specific location.\n\n # If the covariance of the measurement is known, it should be filled in\n # (if all you know is the variance of each measurement, e.g. from the\n # datasheet, just put those along the diagonal)\n # A covariance matrix of all zeros will be interpreted as \"covariance unknown\",\n # and to use the data a covariance will have to be assumed or gotten from some\n # other source\n\n\n Header header                        # timestamp is the time the\n                                      # field was measured\n                                      # frame_id is the location and orientation\n                                      # of the field measurement\n\n geometry_msgs/Vector3 magnetic_field # x, y, and z components of the\n                                      # field vector in Tesla\n                                      # If your sensor does not output 3 axes,\n                                      # put NaNs in the components not reported.\n\n float64[9] magnetic_field_covariance # Row major about x, y, z axes\n                                      # 0 is interpreted as variance unknown\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z";
+    }
+
+    /// Message type `sensor_msgs/MultiDOFJointState`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct MultiDOFJointState {
+        pub header: super::std_msgs::Header,
+        pub joint_names: ::std::vec::Vec<::std::string::String>,
+        pub transforms: ::std::vec::Vec<super::geometry_msgs::Transform>,
+        pub twist: ::std::vec::Vec<super::geometry_msgs::Twist>,
+        pub wrench: ::std::vec::Vec<super::geometry_msgs::Wrench>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for MultiDOFJointState {
+        const ROS_TYPE: &'static str = "sensor_msgs/MultiDOFJointState";
+        const MD5SUM: &'static str = "690f272f0640d2631c305eeb8301e59d";
+        const DEFINITION: &'static str = "# Representation of state for joints with multiple degrees of freedom, \n# following the structure of JointState.\n#\n# It is assumed that a joint in a system corresponds to a transform that gets applied \n# along the kinematic chain. For example, a planar joint (as in URDF) is 3DOF (x, y, yaw)\n# and those 3DOF can be expressed as a transformation matrix, and that transformation\n# matrix can be converted back to (x, y, yaw)\n#\n# Each joint is uniquely identified by its name\n# The header specifies the time at which the joint states were recorded. All the joint states\n# in one message have to be recorded at the same time.\n#\n# This message consists of a multiple arrays, one for each part of the joint state. \n# The goal is to make each of the fields optional. When e.g. your joints have no\n# wrench associated with them, you can leave the wrench array empty. \n#\n# All arrays in this message should have the same size, or be empty.\n# This is the only way to uniquely associate the joint name with the correct\n# states.\n\nHeader header\n\nstring[] joint_names\ngeometry_msgs/Transform[] transforms\ngeometry_msgs/Twist[] twist\ngeometry_msgs/Wrench[] wrench\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Transform\n# This represents the transform between two coordinate frames in free space.\n\nVector3 translation\nQuaternion rotation\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n\n================================================================================\nMSG: geometry_msgs/Twist\n# This expresses velocity in free space broken into its linear and angular parts.\nVector3  linear\nVector3  angular\n\n================================================================================\nMSG: geometry_msgs/Wrench\n# This represents force in free space, separated into\n# its linear and angular parts.\nVector3  force\nVector3  torque\n";
+    }
+
+    /// Message type `sensor_msgs/MultiEchoLaserScan`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct MultiEchoLaserScan {
+        /// timestamp in the header is the acquisition time of
+        pub header: super::std_msgs::Header,
+        /// start angle of the scan [rad]
+        pub angle_min: f32,
+        /// end angle of the scan [rad]
+        pub angle_max: f32,
+        /// angular distance between measurements [rad]
+        pub angle_increment: f32,
+        /// time between measurements [seconds] - if your scanner
+        pub time_increment: f32,
+        /// time between scans [seconds]
+        pub scan_time: f32,
+        /// minimum range value [m]
+        pub range_min: f32,
+        /// maximum range value [m]
+        pub range_max: f32,
+        /// range data [m] (Note: NaNs, values < range_min or > range_max should be discarded)
+        pub ranges: ::std::vec::Vec<super::sensor_msgs::LaserEcho>,
+        /// intensity data [device-specific units].  If your
+        pub intensities: ::std::vec::Vec<super::sensor_msgs::LaserEcho>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for MultiEchoLaserScan {
+        const ROS_TYPE: &'static str = "sensor_msgs/MultiEchoLaserScan";
+        const MD5SUM: &'static str = "6fefb0c6da89d7c8abe4b339f5c2f8fb";
+        const DEFINITION: &'static str = "# Single scan from a multi-echo planar laser range-finder\n#\n# If you have another ranging device with different behavior (e.g. a sonar\n# array), please find or create a different message, since applications\n# will make fairly laser-specific assumptions about this data\n\nHeader header            # timestamp in the header is the acquisition time of \n                         # the first ray in the scan.\n                         #\n                         # in frame frame_id, angles are measured around \n                         # the positive Z axis (counterclockwise, if Z is up)\n                         # with zero angle being forward along the x axis\n                         \nfloat32 angle_min        # start angle of the scan [rad]\nfloat32 angle_max        # end angle of the scan [rad]\nfloat32 angle_increment  # angular distance between measurements [rad]\n\nfloat32 time_increment   # time between measurements [seconds] - if your scanner\n                         # is moving, this will be used in interpolating position\n                         # of 3d points\nfloat32 scan_time        # time between scans [seconds]\n\nfloat32 range_min        # minimum range value [m]\nfloat32 range_max        # maximum range value [m]\n\nLaserEcho[] ranges       # range data [m] (Note: NaNs, values < range_min or > range_max should be discarded)\n                         # +Inf measurements are out of range\n                         # -Inf measurements are too close to determine exact distance.\nLaserEcho[] intensities  # intensity data [device-specific units].  If your\n                         # device does not provide intensities, please leave\n                         # the array empty.\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: sensor_msgs/LaserEcho\n# This message is a submessage of MultiEchoLaserScan and is not intended\n# to be used separately.\n\nfloat32[] echoes  # Multiple values of ranges or intensities.\n                  # Each array represents data from the same angle increment.\n";
+    }
+
+    /// Message type `sensor_msgs/NavSatFix`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct NavSatFix {
+        pub header: super::std_msgs::Header,
+        pub status: super::sensor_msgs::NavSatStatus,
+        pub latitude: f64,
+        pub longitude: f64,
+        pub altitude: f64,
+        pub position_covariance: [f64; 9],
+        pub position_covariance_type: u8,
+    }
+
+    impl NavSatFix {
+        pub const COVARIANCE_TYPE_UNKNOWN: u8 = 0;
+        pub const COVARIANCE_TYPE_APPROXIMATED: u8 = 1;
+        pub const COVARIANCE_TYPE_DIAGONAL_KNOWN: u8 = 2;
+        pub const COVARIANCE_TYPE_KNOWN: u8 = 3;
+    }
+
+    impl ::serde_rosmsg::RosMessage for NavSatFix {
+        const ROS_TYPE: &'static str = "sensor_msgs/NavSatFix";
+        const MD5SUM: &'static str = "2d3a8cd499b9b4a0249fb98fd05cfa48";
+        const DEFINITION: &'static str = "# Navigation Satellite fix for any Global Navigation Satellite System\n#\n# Specified using the WGS 84 reference ellipsoid\n\n# header.stamp specifies the ROS time for this measurement (the\n#        corresponding satellite time may be reported using the\n#        sensor_msgs/TimeReference message).\n#\n# header.frame_id is the frame of reference reported by the satellite\n#        receiver, usually the location of the antenna.  This is a\n#        Euclidean frame relative to the vehicle, not a reference\n#        ellipsoid.\nHeader header\n\n# satellite fix status information\nNavSatStatus status\n\n# Latitude [degrees]. Positive is north of equator; negative is south.\nfloat64 latitude\n\n# Longitude [degrees]. Positive is east of prime meridian; negative is west.\nfloat64 longitude\n\n# Altitude [m]. Positive is above the WGS 84 ellipsoid\n# (quiet NaN if no altitude is available).\nfloat64 altitude\n\n# Position covariance [m^2] defined relative to a tangential plane\n# through the reported position. The components are East, North, and\n# Up (ENU), in row-major order.\n#\n# Beware: this coordinate system exhibits singularities at the poles.\n\nfloat64[9] position_covariance\n\n# If the covariance of the fix is known, fill it in completely. If the\n# GPS receiver provides the variance of each measurement, put them\n# along the diagonal. If only Dilution of Precision is available,\n# estimate an approximate covariance from that.\n\nuint8 COVARIANCE_TYPE_UNKNOWN = 0\nuint8 COVARIANCE_TYPE_APPROXIMATED = 1\nuint8 COVARIANCE_TYPE_DIAGONAL_KNOWN = 2\nuint8 COVARIANCE_TYPE_KNOWN = 3\n\nuint8 position_covariance_type\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: sensor_msgs/NavSatStatus\n# Navigation Satellite fix status for any Global Navigation Satellite System\n\n# Whether to output an augmented fix is determined by both the fix\n# type and the last time differential corrections were received.  A\n# fix is valid when status >= STATUS_FIX.\n\nint8 STATUS_NO_FIX =  -1        # unable to fix position\nint8 STATUS_FIX =      0        # unaugmented fix\nint8 STATUS_SBAS_FIX = 1        # with satellite-based augmentation\nint8 STATUS_GBAS_FIX = 2        # with ground-based augmentation\n\nint8 status\n\n# Bits defining which Global Navigation Satellite System signals were\n# used by the receiver.\n\nuint16 SERVICE_GPS =     1\nuint16 SERVICE_GLONASS = 2\nuint16 SERVICE_COMPASS = 4      # includes BeiDou.\nuint16 SERVICE_GALILEO = 8\n\nuint16 service\n";
+    }
+
+    /// Message type `sensor_msgs/NavSatStatus`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct NavSatStatus {
+        pub status: i8,
+        pub service: u16,
+    }
+
+    impl NavSatStatus {
+        /// unable to fix position
+        pub const STATUS_NO_FIX: i8 = -1;
+        /// unaugmented fix
+        pub const STATUS_FIX: i8 = 0;
+        /// with satellite-based augmentation
+        pub const STATUS_SBAS_FIX: i8 = 1;
+        /// with ground-based augmentation
+        pub const STATUS_GBAS_FIX: i8 = 2;
+        pub const SERVICE_GPS: u16 = 1;
+        pub const SERVICE_GLONASS: u16 = 2;
+        /// includes BeiDou.
+        pub const SERVICE_COMPASS: u16 = 4;
+        pub const SERVICE_GALILEO: u16 = 8;
+    }
+
+    impl ::serde_rosmsg::RosMessage for NavSatStatus {
+        const ROS_TYPE: &'static str = "sensor_msgs/NavSatStatus";
+        const MD5SUM: &'static str = "331cdbddfa4bc96ffc3b9ad98900a54c";
+        const DEFINITION: &'static str = "# Navigation Satellite fix status for any Global Navigation Satellite System\n\n# Whether to output an augmented fix is determined by both the fix\n# type and the last time differential corrections were received.  A\n# fix is valid when status >= STATUS_FIX.\n\nint8 STATUS_NO_FIX =  -1        # unable to fix position\nint8 STATUS_FIX =      0        # unaugmented fix\nint8 STATUS_SBAS_FIX = 1        # with satellite-based augmentation\nint8 STATUS_GBAS_FIX = 2        # with ground-based augmentation\n\nint8 status\n\n# Bits defining which Global Navigation Satellite System signals were\n# used by the receiver.\n\nuint16 SERVICE_GPS =     1\nuint16 SERVICE_GLONASS = 2\nuint16 SERVICE_COMPASS = 4      # includes BeiDou.\nuint16 SERVICE_GALILEO = 8\n\nuint16 service\n";
+    }
+
+    /// Message type `sensor_msgs/PointCloud`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PointCloud {
+        pub header: super::std_msgs::Header,
+        pub points: ::std::vec::Vec<super::geometry_msgs::Point32>,
+        pub channels: ::std::vec::Vec<super::sensor_msgs::ChannelFloat32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PointCloud {
+        const ROS_TYPE: &'static str = "sensor_msgs/PointCloud";
+        const MD5SUM: &'static str = "d8e9c3f5afbdd8a130fd1d2763945fca";
+        const DEFINITION: &'static str = "# This message holds a collection of 3d points, plus optional additional\n# information about each point.\n\n# Time of sensor data acquisition, coordinate frame ID.\nHeader header\n\n# Array of 3d points. Each Point32 should be interpreted as a 3d point\n# in the frame given in the header.\ngeometry_msgs/Point32[] points\n\n# Each channel should have the same number of elements as points array,\n# and the data in each channel should correspond 1:1 with each point.\n# Channel names in common practice are listed in ChannelFloat32.msg.\nChannelFloat32[] channels\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Point32\n# This contains the position of a point in free space(with 32 bits of precision).\n# It is recommeded to use Point wherever possible instead of Point32.  \n# \n# This recommendation is to promote interoperability.  \n#\n# This message is designed to take up less space when sending\n# lots of points at once, as in the case of a PointCloud.  \n\nfloat32 x\nfloat32 y\nfloat32 z\n================================================================================\nMSG: sensor_msgs/ChannelFloat32\n# This message is used by the PointCloud message to hold optional data\n# associated with each point in the cloud. The length of the values\n# array should be the same as the length of the points array in the\n# PointCloud, and each value should be associated with the corresponding\n# point.\n\n# Channel names in existing practice include:\n#   \"u\", \"v\" - row and column (respectively) in the left stereo image.\n#              This is opposite to usual conventions but remains for\n#              historical reasons. The newer PointCloud2 message has no\n#              such problem.\n#   \"rgb\" - For point clouds produced by color stereo cameras. uint8\n#           (R,G,B) values packed into the least significant 24 bits,\n#           in order.\n#   \"intensity\" - laser or pixel intensity.\n#   \"distance\"\n\n# The channel name should give semantics of the channel (e.g.\n# \"intensity\" instead of \"value\").\nstring name\n\n# The values array should be 1-1 with the elements of the associated\n# PointCloud.\nfloat32[] values\n";
+    }
+
+    /// Message type `sensor_msgs/PointCloud2`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PointCloud2 {
+        pub header: super::std_msgs::Header,
+        pub height: u32,
+        pub width: u32,
+        pub fields: ::std::vec::Vec<super::sensor_msgs::PointField>,
+        /// Is this data bigendian?
+        pub is_bigendian: bool,
+        /// Length of a point in bytes
+        pub point_step: u32,
+        /// Length of a row in bytes
+        pub row_step: u32,
+        /// Actual point data, size is (row_step*height)
+        pub data: ::std::vec::Vec<u8>,
+        /// True if there are no invalid points
+        pub is_dense: bool,
+    }
+
+    impl ::serde_rosmsg::RosMessage for PointCloud2 {
+        const ROS_TYPE: &'static str = "sensor_msgs/PointCloud2";
+        const MD5SUM: &'static str = "1158d486dd51d683ce2f1be655c3c181";
+        const DEFINITION: &'static str = "# This message holds a collection of N-dimensional points, which may\n# contain additional information such as normals, intensity, etc. The\n# point data is stored as a binary blob, its layout described by the\n# contents of the \"fields\" array.\n\n# The point cloud data may be organized 2d (image-like) or 1d\n# (unordered). Point clouds organized as 2d images may be produced by\n# camera depth sensors such as stereo or time-of-flight.\n\n# Time of sensor data acquisition, and the coordinate frame ID (for 3d\n# points).\nHeader header\n\n# 2D structure of the point cloud. If the cloud is unordered, height is\n# 1 and width is the length of the point cloud.\nuint32 height\nuint32 width\n\n# Describes the channels and their layout in the binary data blob.\nPointField[] fields\n\nbool    is_bigendian # Is this data bigendian?\nuint32  point_step   # Length of a point in bytes\nuint32  row_step     # Length of a row in bytes\nuint8[] data         # Actual point data, size is (row_step*height)\n\nbool is_dense        # True if there are no invalid points\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: sensor_msgs/PointField\n# This message holds the description of one point entry in the\n# PointCloud2 message format.\nuint8 INT8    = 1\nuint8 UINT8   = 2\nuint8 INT16   = 3\nuint8 UINT16  = 4\nuint8 INT32   = 5\nuint8 UINT32  = 6\nuint8 FLOAT32 = 7\nuint8 FLOAT64 = 8\n\nstring name      # Name of field\nuint32 offset    # Offset from start of point struct\nuint8  datatype  # Datatype enumeration, see above\nuint32 count     # How many elements in the field\n";
+    }
+
+    /// Message type `sensor_msgs/PointField`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct PointField {
+        /// Name of field
+        pub name: ::std::string::String,
+        /// Offset from start of point struct
+        pub offset: u32,
+        /// Datatype enumeration, see above
+        pub datatype: u8,
+        /// How many elements in the field
+        pub count: u32,
+    }
+
+    impl PointField {
+        pub const INT8: u8 = 1;
+        pub const UINT8: u8 = 2;
+        pub const INT16: u8 = 3;
+        pub const UINT16: u8 = 4;
+        pub const INT32: u8 = 5;
+        pub const UINT32: u8 = 6;
+        pub const FLOAT32: u8 = 7;
+        pub const FLOAT64: u8 = 8;
+    }
+
+    impl ::serde_rosmsg::RosMessage for PointField {
+        const ROS_TYPE: &'static str = "sensor_msgs/PointField";
+        const MD5SUM: &'static str = "268eacb2962780ceac86cbd17e328150";
+        const DEFINITION: &'static str = "# This message holds the description of one point entry in the\n# PointCloud2 message format.\nuint8 INT8    = 1\nuint8 UINT8   = 2\nuint8 INT16   = 3\nuint8 UINT16  = 4\nuint8 INT32   = 5\nuint8 UINT32  = 6\nuint8 FLOAT32 = 7\nuint8 FLOAT64 = 8\n\nstring name      # Name of field\nuint32 offset    # Offset from start of point struct\nuint8  datatype  # Datatype enumeration, see above\nuint32 count     # How many elements in the field\n";
+    }
+
+    /// Message type `sensor_msgs/Range`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Range {
+        /// timestamp in the header is the time the ranger
+        pub header: super::std_msgs::Header,
+        /// the type of radiation used by the sensor
+        pub radiation_type: u8,
+        /// the size of the arc that the distance reading is
+        pub field_of_view: f32,
+        /// minimum range value [m]
+        pub min_range: f32,
+        /// maximum range value [m]
+        pub max_range: f32,
+        /// range data [m]
+        pub range: f32,
+    }
+
+    impl Range {
+        pub const ULTRASOUND: u8 = 0;
+        pub const INFRARED: u8 = 1;
+    }
+
+    impl ::serde_rosmsg::RosMessage for Range {
+        const ROS_TYPE: &'static str = "sensor_msgs/Range";
+        const MD5SUM: &'static str = "c005c34273dc426c67a020a87bc24148";
+        const DEFINITION: &'static str = "# Single range reading from an active ranger that emits energy and reports\n# one range reading that is valid along an arc at the distance measured. \n# This message is  not appropriate for laser scanners. See the LaserScan\n# message if you are working with a laser scanner.\n\n# This message also can represent a fixed-distance (binary) ranger.  This\n# sensor will have min_range===max_range===distance of detection.\n# These sensors follow REP 117 and will output -Inf if the object is detected\n# and +Inf if the object is outside of the detection range.\n\nHeader header           # timestamp in the header is the time the ranger\n                        # returned the distance reading\n\n# Radiation type enums\n# If you want a value added to this list, send an email to the ros-users list\nuint8 ULTRASOUND=0\nuint8 INFRARED=1\n\nuint8 radiation_type    # the type of radiation used by the sensor\n                        # (sound, IR, etc) [enum]\n\nfloat32 field_of_view   # the size of the arc that the distance reading is\n                        # valid for [rad]\n                        # the object causing the range reading may have\n                        # been anywhere within -field_of_view/2 and\n                        # field_of_view/2 at the measured range. \n                        # 0 angle corresponds to the x-axis of the sensor.\n\nfloat32 min_range       # minimum range value [m]\nfloat32 max_range       # maximum range value [m]\n                        # Fixed distance rangers require min_range==max_range\n\nfloat32 range           # range data [m]\n                        # (Note: values < range_min or > range_max\n                        # should be discarded)\n                        # Fixed distance rangers only output -Inf or +Inf.\n                        # -Inf represents a detection within fixed distance.\n                        # (Detection too close to the sensor to quantify)\n                        # +Inf represents no detection within the fixed distance.\n                        # (Object out of range)\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/RegionOfInterest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct RegionOfInterest {
+        /// Leftmost pixel of the ROI
+        pub x_offset: u32,
+        /// Topmost pixel of the ROI
+        pub y_offset: u32,
+        /// Height of ROI
+        pub height: u32,
+        /// Width of ROI
+        pub width: u32,
+        pub do_rectify: bool,
+    }
+
+    impl ::serde_rosmsg::RosMessage for RegionOfInterest {
+        const ROS_TYPE: &'static str = "sensor_msgs/RegionOfInterest";
+        const MD5SUM: &'static str = "bdb633039d588fcccb441a4d43ccfe09";
+        const DEFINITION: &'static str = "# This message is used to specify a region of interest within an image.\n#\n# When used to specify the ROI setting of the camera when the image was\n# taken, the height and width fields should either match the height and\n# width fields for the associated image; or height = width = 0\n# indicates that the full resolution image was captured.\n\nuint32 x_offset  # Leftmost pixel of the ROI\n                 # (0 if the ROI includes the left edge of the image)\nuint32 y_offset  # Topmost pixel of the ROI\n                 # (0 if the ROI includes the top edge of the image)\nuint32 height    # Height of ROI\nuint32 width     # Width of ROI\n\n# True if a distinct rectified ROI should be calculated from the \"raw\"\n# ROI in this message. Typically this should be False if the full image\n# is captured (ROI not used), and True if a subwindow is captured (ROI\n# used).\nbool do_rectify\n";
+    }
+
+    /// Message type `sensor_msgs/RelativeHumidity`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct RelativeHumidity {
+        /// timestamp of the measurement
+        pub header: super::std_msgs::Header,
+        /// Expression of the relative humidity
+        pub relative_humidity: f64,
+        /// 0 is interpreted as variance unknown
+        pub variance: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for RelativeHumidity {
+        const ROS_TYPE: &'static str = "sensor_msgs/RelativeHumidity";
+        const MD5SUM: &'static str = "8730015b05955b7e992ce29a2678d90f";
+        const DEFINITION: &'static str = " # Single reading from a relative humidity sensor.  Defines the ratio of partial\n # pressure of water vapor to the saturated vapor pressure at a temperature.\n\n Header header             # timestamp of the measurement\n                           # frame_id is the location of the humidity sensor\n\n float64 relative_humidity # Expression of the relative humidity\n                           # from 0.0 to 1.0.\n                           # 0.0 is no partial pressure of water vapor\n                           # 1.0 represents partial pressure of saturation\n\n float64 variance          # 0 is interpreted as variance unknown\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/Temperature`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Temperature {
+        /// timestamp is the time the temperature was measured
+        pub header: super::std_msgs::Header,
+        /// Measurement of the Temperature in Degrees Celsius
+        pub temperature: f64,
+        /// 0 is interpreted as variance unknown
+        pub variance: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Temperature {
+        const ROS_TYPE: &'static str = "sensor_msgs/Temperature";
+        const MD5SUM: &'static str = "ff71b307acdbe7c871a5a6d7ed359100";
+        const DEFINITION: &'static str = " # Single temperature reading.\n\n Header header           # timestamp is the time the temperature was measured\n                         # frame_id is the location of the temperature reading\n\n float64 temperature     # Measurement of the Temperature in Degrees Celsius\n\n float64 variance        # 0 is interpreted as variance unknown\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/TimeReference`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct TimeReference {
+        /// stamp is system time for which measurement was valid
+        pub header: super::std_msgs::Header,
+        /// corresponding time from this external source
+        pub time_ref: ::serde_rosmsg::time::Time,
+        /// (optional) name of time source
+        pub source: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for TimeReference {
+        const ROS_TYPE: &'static str = "sensor_msgs/TimeReference";
+        const MD5SUM: &'static str = "fded64a0265108ba86c3d38fb11c0c16";
+        const DEFINITION: &'static str = "# Measurement from an external time source not actively synchronized with the system clock.\n\nHeader header    # stamp is system time for which measurement was valid\n                 # frame_id is not used \n\ntime   time_ref  # corresponding time from this external source\nstring source    # (optional) name of time source\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `sensor_msgs/SetCameraInfoRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct SetCameraInfoRequest {
+        /// The camera_info to store
+        pub camera_info: super::sensor_msgs::CameraInfo,
+    }
+
+    impl ::serde_rosmsg::RosMessage for SetCameraInfoRequest {
+        const ROS_TYPE: &'static str = "sensor_msgs/SetCameraInfoRequest";
+        const MD5SUM: &'static str = "ee34be01fdeee563d0d99cd594d5581d";
+        const DEFINITION: &'static str = "# This service requests that a camera stores the given CameraInfo \n# as that camera's calibration information.\n#\n# The width and height in the camera_info field should match what the\n# camera is currently outputting on its camera_info topic, and the camera\n# will assume that the region of the imager that is being referred to is\n# the region that the camera is currently capturing.\n\nsensor_msgs/CameraInfo camera_info # The camera_info to store\n\n================================================================================\nMSG: sensor_msgs/CameraInfo\n# This message defines meta information for a camera. It should be in a\n# camera namespace on topic \"camera_info\" and accompanied by up to five\n# image topics named:\n#\n#   image_raw - raw data from the camera driver, possibly Bayer encoded\n#   image            - monochrome, distorted\n#   image_color      - color, distorted\n#   image_rect       - monochrome, rectified\n#   image_rect_color - color, rectified\n#\n# The image_pipeline contains packages (image_proc, stereo_image_proc)\n# for producing the four processed image topics from image_raw and\n# camera_info. The meaning of the camera parameters are described in\n# detail at http://www.ros.org/wiki/image_pipeline/CameraInfo.\n\n#######################################################################\n#                     Image acquisition info                          #\n#######################################################################\n\n# Time of image acquisition, camera coordinate frame ID\nHeader header    # Header timestamp should be acquisition time of image\n                 # Header frame_id should be optical frame of camera\n                 # origin of frame should be optical center of camera\n                 # +x should point to the right in the image\n                 # +y should point down in the image\n                 # +z should point into the plane of the image\n\n\n#######################################################################\n#                      Calibration Parameters                         #\n#######################################################################\n# These are fixed during camera calibration. Their values will be the #\n# same in all messages until the camera is recalibrated. Note that    #\n# self-calibrating systems may \"recalibrate\" frequently.              #\n#######################################################################\n\n# The image dimensions with which the camera was calibrated. Normally\n# this will be the full camera resolution in pixels.\nuint32 height\nuint32 width\n\n# The distortion model used. Supported models are listed in\n# sensor_msgs/distortion_models.h. For most cameras, \"plumb_bob\" - a\n# simple model of radial and tangential distortion - is sufficient.\nstring distortion_model\n\n# The distortion parameters, size depending on the distortion model.\n# For \"plumb_bob\", the 5 parameters are: (k1, k2, t1, t2, k3).\nfloat64[] D\n\n# Intrinsic camera matrix for the raw (distorted) images.\nfloat64[9]  K # 3x3 row-major matrix\n\n# Rectification matrix (stereo cameras only)\nfloat64[9]  R # 3x3 row-major matrix\n\n# Projection/camera matrix\nfloat64[12] P # 3x4 row-major matrix\n\n\n#######################################################################\n#                      Operational Parameters                         #\n#######################################################################\n\n# Binning refers here to any camera setting which combines rectangular\n#  neighborhoods of pixels into larger \"super-pixels.\" It reduces the\n#  resolution of the output image to\n#  (width / binning_x) x (height / binning_y).\n# The default values binning_x = binning_y = 0 is considered the same\n#  as binning_x = binning_y = 1 (no subsampling).\nuint32 binning_x\nuint32 binning_y\n\n# Region of interest (subwindow of full camera resolution), given in\n#  full resolution (unbinned) image coordinates. A particular ROI\n#  always denotes the same window of pixels on the camera sensor,\n#  regardless of binning settings.\n# The default setting of roi (all values 0) is considered the same as\n#  full resolution (roi.width = width, roi.height = height).\nRegionOfInterest roi\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: sensor_msgs/RegionOfInterest\n# This message is used to specify a region of interest within an image.\n#\n# When used to specify the ROI setting of the camera when the image was\n# taken, the height and width fields should either match the height and\n# width fields for the associated image; or height = width = 0\n# indicates that the full resolution image was captured.\n\nuint32 x_offset  # Leftmost pixel of the ROI\n                 # (0 if the ROI includes the left edge of the image)\nuint32 y_offset  # Topmost pixel of the ROI\n                 # (0 if the ROI includes the top edge of the image)\nuint32 height    # Height of ROI\nuint32 width     # Width of ROI\n\n# True if a distinct rectified ROI should be calculated from the \"raw\"\n# ROI in this message. Typically this should be False if the full image\n# is captured (ROI not used), and True if a subwindow is captured (ROI\n# used).\nbool do_rectify\n";
+    }
+
+    /// Message type `sensor_msgs/SetCameraInfoResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct SetCameraInfoResponse {
+        /// True if the call succeeded
+        pub success: bool,
+        /// Used to give details about success
+        pub status_message: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for SetCameraInfoResponse {
+        const ROS_TYPE: &'static str = "sensor_msgs/SetCameraInfoResponse";
+        const MD5SUM: &'static str = "2ec6f3eff0161f4257b808b12bc830c2";
+        const DEFINITION: &'static str = "bool success          # True if the call succeeded\nstring status_message # Used to give details about success\n";
+    }
+
+    /// Service type `sensor_msgs/SetCameraInfo`, made of `SetCameraInfoRequest` and `SetCameraInfoResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct SetCameraInfo;
+
+    impl SetCameraInfo {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "sensor_msgs/SetCameraInfo";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "bef1df590ed75ed1f393692395e15482";
+    }
+}
+
+pub mod std_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `std_msgs/Bool`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Bool {
+        pub data: bool,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Bool {
+        const ROS_TYPE: &'static str = "std_msgs/Bool";
+        const MD5SUM: &'static str = "8b94c1b53db61fb6aed406028ad6332a";
+        const DEFINITION: &'static str = "bool data\n";
+    }
+
+    /// Message type `std_msgs/Byte`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Byte {
+        pub data: i8,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Byte {
+        const ROS_TYPE: &'static str = "std_msgs/Byte";
+        const MD5SUM: &'static str = "ad736a2e8818154c487bb80fe42ce43b";
+        const DEFINITION: &'static str = "byte data\n";
+    }
+
+    /// Message type `std_msgs/ByteMultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct ByteMultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<i8>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for ByteMultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/ByteMultiArray";
+        const MD5SUM: &'static str = "70ea476cbcfd65ac2f68f3cda1e891fe";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nbyte[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/Char`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Char {
+        pub data: u8,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Char {
+        const ROS_TYPE: &'static str = "std_msgs/Char";
+        const MD5SUM: &'static str = "1bf77f25acecdedba0e224b162199717";
+        const DEFINITION: &'static str = "char data\n";
+    }
+
+    /// Message type `std_msgs/ColorRGBA`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct ColorRGBA {
+        pub r: f32,
+        pub g: f32,
+        pub b: f32,
+        pub a: f32,
+    }
+
+    impl ::serde_rosmsg::RosMessage for ColorRGBA {
+        const ROS_TYPE: &'static str = "std_msgs/ColorRGBA";
+        const MD5SUM: &'static str = "a29a96539573343b1310c73607334b00";
+        const DEFINITION: &'static str = "float32 r\nfloat32 g\nfloat32 b\nfloat32 a\n";
+    }
+
+    /// Message type `std_msgs/Duration`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Duration {
+        pub data: ::serde_rosmsg::time::Duration,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Duration {
+        const ROS_TYPE: &'static str = "std_msgs/Duration";
+        const MD5SUM: &'static str = "3e286caf4241d664e55f3ad380e2ae46";
+        const DEFINITION: &'static str = "duration data\n";
+    }
+
+    /// Message type `std_msgs/Empty`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Empty {
+    }
+
+    impl ::serde_rosmsg::RosMessage for Empty {
+        const ROS_TYPE: &'static str = "std_msgs/Empty";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "";
+    }
+
+    /// Message type `std_msgs/Float32`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Float32 {
+        pub data: f32,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Float32 {
+        const ROS_TYPE: &'static str = "std_msgs/Float32";
+        const MD5SUM: &'static str = "73fcbf46b49191e672908e50842a83d4";
+        const DEFINITION: &'static str = "float32 data\n";
+    }
+
+    /// Message type `std_msgs/Float32MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Float32MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<f32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Float32MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/Float32MultiArray";
+        const MD5SUM: &'static str = "6a40e0ffa6a17a503ac3f8616991b1f6";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nfloat32[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/Float64`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Float64 {
+        pub data: f64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Float64 {
+        const ROS_TYPE: &'static str = "std_msgs/Float64";
+        const MD5SUM: &'static str = "fdb28210bfa9d7c91146260178d9a584";
+        const DEFINITION: &'static str = "float64 data\n";
+    }
+
+    /// Message type `std_msgs/Float64MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Float64MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<f64>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Float64MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/Float64MultiArray";
+        const MD5SUM: &'static str = "4b7d974086d4060e7db4613a7e6c3ba4";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nfloat64[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/Header`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Header {
+        pub seq: u32,
+        pub stamp: ::serde_rosmsg::time::Time,
+        pub frame_id: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Header {
+        const ROS_TYPE: &'static str = "std_msgs/Header";
+        const MD5SUM: &'static str = "2176decaecbce78abc3b96ef049fabed";
+        const DEFINITION: &'static str = "# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n";
+    }
+
+    /// Message type `std_msgs/Int16`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int16 {
+        pub data: i16,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Int16 {
+        const ROS_TYPE: &'static str = "std_msgs/Int16";
+        const MD5SUM: &'static str = "8524586e34fbd7cb1c08c5f5f1ca0e57";
+        const DEFINITION: &'static str = "int16 data\n";
+    }
+
+    /// Message type `std_msgs/Int16MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int16MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<i16>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Int16MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/Int16MultiArray";
+        const MD5SUM: &'static str = "d9338d7f523fcb692fae9d0a0e9f067c";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nint16[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/Int32`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int32 {
+        pub data: i32,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Int32 {
+        const ROS_TYPE: &'static str = "std_msgs/Int32";
+        const MD5SUM: &'static str = "da5909fbe378aeaf85e547e830cc1bb7";
+        const DEFINITION: &'static str = "int32 data\n";
+    }
+
+    /// Message type `std_msgs/Int32MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int32MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<i32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Int32MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/Int32MultiArray";
+        const MD5SUM: &'static str = "1d99f79f8b325b44fee908053e9c945b";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nint32[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/Int64`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int64 {
+        pub data: i64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Int64 {
+        const ROS_TYPE: &'static str = "std_msgs/Int64";
+        const MD5SUM: &'static str = "34add168574510e6e17f5d23ecc077ef";
+        const DEFINITION: &'static str = "int64 data\n";
+    }
+
+    /// Message type `std_msgs/Int64MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int64MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<i64>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Int64MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/Int64MultiArray";
+        const MD5SUM: &'static str = "54865aa6c65be0448113a2afc6a49270";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nint64[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/Int8`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int8 {
+        pub data: i8,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Int8 {
+        const ROS_TYPE: &'static str = "std_msgs/Int8";
+        const MD5SUM: &'static str = "27ffa0c9c4b8fb8492252bcad9e5c57b";
+        const DEFINITION: &'static str = "int8 data\n";
+    }
+
+    /// Message type `std_msgs/Int8MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int8MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<i8>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Int8MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/Int8MultiArray";
+        const MD5SUM: &'static str = "d7c1af35a1b4781bbe79e03dd94b7c13";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nint8[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/MultiArrayDimension`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct MultiArrayDimension {
+        /// label of given dimension
+        pub label: ::std::string::String,
+        /// size of given dimension (in type units)
+        pub size: u32,
+        /// stride of given dimension
+        pub stride: u32,
+    }
+
+    impl ::serde_rosmsg::RosMessage for MultiArrayDimension {
+        const ROS_TYPE: &'static str = "std_msgs/MultiArrayDimension";
+        const MD5SUM: &'static str = "4cd0c83a8683deae40ecdac60e53bfa8";
+        const DEFINITION: &'static str = "string label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/MultiArrayLayout`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct MultiArrayLayout {
+        /// Array of dimension properties
+        pub dim: ::std::vec::Vec<super::std_msgs::MultiArrayDimension>,
+        /// padding elements at front of data
+        pub data_offset: u32,
+    }
+
+    impl ::serde_rosmsg::RosMessage for MultiArrayLayout {
+        const ROS_TYPE: &'static str = "std_msgs/MultiArrayLayout";
+        const MD5SUM: &'static str = "0fed2a11c13e11c5571b4e2a995a91a3";
+        const DEFINITION: &'static str = "# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/String`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct String {
+        pub data: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for String {
+        const ROS_TYPE: &'static str = "std_msgs/String";
+        const MD5SUM: &'static str = "992ce8a1687cec8c8bd883ec73ca41d1";
+        const DEFINITION: &'static str = "string data\n";
+    }
+
+    /// Message type `std_msgs/Time`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Time {
+        pub data: ::serde_rosmsg::time::Time,
+    }
+
+    impl ::serde_rosmsg::RosMessage for Time {
+        const ROS_TYPE: &'static str = "std_msgs/Time";
+        const MD5SUM: &'static str = "cd7166c74c552c311fbcc2fe5a7bc289";
+        const DEFINITION: &'static str = "time data\n";
+    }
+
+    /// Message type `std_msgs/UInt16`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct UInt16 {
+        pub data: u16,
+    }
+
+    impl ::serde_rosmsg::RosMessage for UInt16 {
+        const ROS_TYPE: &'static str = "std_msgs/UInt16";
+        const MD5SUM: &'static str = "1df79edf208b629fe6b81923a544552d";
+        const DEFINITION: &'static str = "uint16 data\n";
+    }
+
+    /// Message type `std_msgs/UInt16MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct UInt16MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<u16>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for UInt16MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/UInt16MultiArray";
+        const MD5SUM: &'static str = "52f264f1c973c4b73790d384c6cb4484";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nuint16[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/UInt32`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct UInt32 {
+        pub data: u32,
+    }
+
+    impl ::serde_rosmsg::RosMessage for UInt32 {
+        const ROS_TYPE: &'static str = "std_msgs/UInt32";
+        const MD5SUM: &'static str = "304a39449588c7f8ce2df6e8001c5fce";
+        const DEFINITION: &'static str = "uint32 data\n";
+    }
+
+    /// Message type `std_msgs/UInt32MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct UInt32MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<u32>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for UInt32MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/UInt32MultiArray";
+        const MD5SUM: &'static str = "4d6a180abc9be191b96a7eda6c8a233d";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nuint32[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/UInt64`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct UInt64 {
+        pub data: u64,
+    }
+
+    impl ::serde_rosmsg::RosMessage for UInt64 {
+        const ROS_TYPE: &'static str = "std_msgs/UInt64";
+        const MD5SUM: &'static str = "1b2a79973e8bf53d7b53acb71299cb57";
+        const DEFINITION: &'static str = "uint64 data\n";
+    }
+
+    /// Message type `std_msgs/UInt64MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct UInt64MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<u64>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for UInt64MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/UInt64MultiArray";
+        const MD5SUM: &'static str = "6088f127afb1d6c72927aa1247e945af";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nuint64[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+
+    /// Message type `std_msgs/UInt8`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct UInt8 {
+        pub data: u8,
+    }
+
+    impl ::serde_rosmsg::RosMessage for UInt8 {
+        const ROS_TYPE: &'static str = "std_msgs/UInt8";
+        const MD5SUM: &'static str = "7c8164229e7d2c17eb95e9231617fdee";
+        const DEFINITION: &'static str = "uint8 data\n";
+    }
+
+    /// Message type `std_msgs/UInt8MultiArray`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct UInt8MultiArray {
+        /// specification of data layout
+        pub layout: super::std_msgs::MultiArrayLayout,
+        /// array of data
+        pub data: ::std::vec::Vec<u8>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for UInt8MultiArray {
+        const ROS_TYPE: &'static str = "std_msgs/UInt8MultiArray";
+        const MD5SUM: &'static str = "82373f1612381bb6ee473b5cd6f5d89c";
+        const DEFINITION: &'static str = "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nuint8[]            data          # array of data\n\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n";
+    }
+}
+
+pub mod tf2_msgs {
+    #![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+    /// Message type `tf2_msgs/TF2Error`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct TF2Error {
+        pub error: u8,
+        pub error_string: ::std::string::String,
+    }
+
+    impl TF2Error {
+        pub const NO_ERROR: u8 = 0;
+        pub const LOOKUP_ERROR: u8 = 1;
+        pub const CONNECTIVITY_ERROR: u8 = 2;
+        pub const EXTRAPOLATION_ERROR: u8 = 3;
+        pub const INVALID_ARGUMENT_ERROR: u8 = 4;
+        pub const TIMEOUT_ERROR: u8 = 5;
+        pub const TRANSFORM_ERROR: u8 = 6;
+    }
+
+    impl ::serde_rosmsg::RosMessage for TF2Error {
+        const ROS_TYPE: &'static str = "tf2_msgs/TF2Error";
+        const MD5SUM: &'static str = "bc6848fd6fd750c92e38575618a4917d";
+        const DEFINITION: &'static str = "uint8 NO_ERROR = 0\nuint8 LOOKUP_ERROR = 1\nuint8 CONNECTIVITY_ERROR = 2\nuint8 EXTRAPOLATION_ERROR = 3\nuint8 INVALID_ARGUMENT_ERROR = 4\nuint8 TIMEOUT_ERROR = 5\nuint8 TRANSFORM_ERROR = 6\n\nuint8 error\nstring error_string\n";
+    }
+
+    /// Message type `tf2_msgs/TFMessage`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct TFMessage {
+        pub transforms: ::std::vec::Vec<super::geometry_msgs::TransformStamped>,
+    }
+
+    impl ::serde_rosmsg::RosMessage for TFMessage {
+        const ROS_TYPE: &'static str = "tf2_msgs/TFMessage";
+        const MD5SUM: &'static str = "94810edda583a504dfda3829e70d7eec";
+        const DEFINITION: &'static str = "geometry_msgs/TransformStamped[] transforms\n\n================================================================================\nMSG: geometry_msgs/TransformStamped\n# This expresses a transform from coordinate frame header.frame_id\n# to the coordinate frame child_frame_id\n#\n# This message is mostly used by the \n# <a href=\"http://wiki.ros.org/tf\">tf</a> package. \n# See its documentation for more information.\n\nHeader header\nstring child_frame_id # the frame id of the child frame\nTransform transform\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\n# 0: no frame\n# 1: global frame\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Transform\n# This represents the transform between two coordinate frames in free space.\n\nVector3 translation\nQuaternion rotation\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n";
+    }
+
+    /// Message type `tf2_msgs/FrameGraphRequest`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct FrameGraphRequest {
+    }
+
+    impl ::serde_rosmsg::RosMessage for FrameGraphRequest {
+        const ROS_TYPE: &'static str = "tf2_msgs/FrameGraphRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "";
+    }
+
+    /// Message type `tf2_msgs/FrameGraphResponse`.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct FrameGraphResponse {
+        pub frame_yaml: ::std::string::String,
+    }
+
+    impl ::serde_rosmsg::RosMessage for FrameGraphResponse {
+        const ROS_TYPE: &'static str = "tf2_msgs/FrameGraphResponse";
+        const MD5SUM: &'static str = "437ea58e9463815a0d511c7326b686b0";
+        const DEFINITION: &'static str = "string frame_yaml\n";
+    }
+
+    /// Service type `tf2_msgs/FrameGraph`, made of `FrameGraphRequest` and `FrameGraphResponse`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct FrameGraph;
+
+    impl FrameGraph {
+        /// Full name of the service type.
+        pub const ROS_TYPE: &'static str = "tf2_msgs/FrameGraph";
+        /// MD5 sum of the service type.
+        pub const MD5SUM: &'static str = "437ea58e9463815a0d511c7326b686b0";
+    }
+}
@@ -0,0 +1,125 @@
+//! Common ROS message packages, enabled with the `msgs` feature.
+//!
+//! The modules are generated with `codegen` from the definitions of the
+//! `std_msgs`, `geometry_msgs`, `sensor_msgs`, `nav_msgs`, `actionlib_msgs`,
+//! `diagnostic_msgs` and `tf2_msgs` packages, which are bundled with the
+//! crate.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde_rosmsg::{from_slice, to_vec, RosMessage};
+//! use serde_rosmsg::msgs::geometry_msgs::{Point, PoseStamped};
+//!
+//! let mut pose = PoseStamped::default();
+//! pose.header.seq = 7;
+//! pose.header.frame_id = "map".into();
+//! pose.pose.position = Point { x: 1.0, y: 2.0, z: 3.0 };
+//! pose.pose.orientation.w = 1.0;
+//!
+//! let data = to_vec(&pose).unwrap();
+//! assert_eq!(from_slice::<PoseStamped>(&data).unwrap(), pose);
+//! assert_eq!(PoseStamped::MD5SUM, "d3812c3cbc69362b77dc0b19b345f8f5");
+//! ```
+
+include!("generated.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::geometry_msgs::{Point, Pose, PoseArray, PoseWithCovariance, Quaternion};
+    use super::std_msgs;
+    use {from_slice, header_from_slice, to_vec, RosMessage};
+
+    fn pose() -> Pose {
+        Pose {
+            position: Point {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            orientation: Quaternion {
+                x: 4.0,
+                y: 5.0,
+                z: 6.0,
+                w: 7.0,
+            },
+        }
+    }
+
+    fn check_headers<T: RosMessage>(request: &[u8], response: &[u8]) {
+        let header = header_from_slice::<T>(request).unwrap();
+        assert_eq!(T::DEFINITION, header["message_definition"]);
+        let header = header_from_slice::<T>(response).unwrap();
+        assert_eq!(T::DEFINITION, header["message_definition"]);
+    }
+
+    #[test]
+    fn matches_string() {
+        let data = include_bytes!("../datatests/string_msg.bin");
+        let message = std_msgs::String { data: "Hello, World!".into() };
+        assert_eq!(message, from_slice(data).unwrap());
+        assert_eq!(&data[..], &to_vec(&message).unwrap()[..]);
+        check_headers::<std_msgs::String>(include_bytes!("../datatests/string_req.bin"),
+                                          include_bytes!("../datatests/string_res.bin"));
+    }
+
+    #[test]
+    fn matches_pose() {
+        let data = include_bytes!("../datatests/pose_msg.bin");
+        assert_eq!(pose(), from_slice(data).unwrap());
+        assert_eq!(&data[..], &to_vec(&pose()).unwrap()[..]);
+        check_headers::<Pose>(include_bytes!("../datatests/pose_req.bin"),
+                              include_bytes!("../datatests/pose_res.bin"));
+    }
+
+    #[test]
+    fn matches_pose_with_covariance() {
+        let data = include_bytes!("../datatests/pose_with_covariance_msg.bin");
+        let mut message = PoseWithCovariance {
+            pose: pose(),
+            covariance: [0.0; 36],
+        };
+        for (index, value) in message.covariance.iter_mut().enumerate() {
+            *value = index as f64 + 8.0;
+        }
+        assert_eq!(message, from_slice(data).unwrap());
+        assert_eq!(&data[..], &to_vec(&message).unwrap()[..]);
+        check_headers::<PoseWithCovariance>(
+            include_bytes!("../datatests/pose_with_covariance_req.bin"),
+            include_bytes!("../datatests/pose_with_covariance_res.bin"));
+    }
+
+    #[test]
+    fn matches_pose_array() {
+        let data = include_bytes!("../datatests/pose_array_msg.bin");
+        let message = PoseArray {
+            header: std_msgs::Header {
+                seq: 1,
+                frame_id: "ABC".into(),
+                ..Default::default()
+            },
+            poses: vec![pose(); 5],
+        };
+        assert_eq!(message, from_slice(data).unwrap());
+        assert_eq!(&data[..], &to_vec(&message).unwrap()[..]);
+        check_headers::<PoseArray>(include_bytes!("../datatests/pose_array_req.bin"),
+                                   include_bytes!("../datatests/pose_array_res.bin"));
+    }
+
+    #[test]
+    fn has_constants_and_services() {
+        use super::{actionlib_msgs, diagnostic_msgs, nav_msgs, sensor_msgs, tf2_msgs};
+        assert_eq!(3, actionlib_msgs::GoalStatus::SUCCEEDED);
+        assert_eq!(2, diagnostic_msgs::DiagnosticStatus::ERROR);
+        assert_eq!(-1, sensor_msgs::NavSatStatus::STATUS_NO_FIX);
+        assert_eq!(7, sensor_msgs::PointField::FLOAT32);
+        assert_eq!(1, sensor_msgs::JoyFeedback::TYPE_RUMBLE);
+        assert_eq!("6fefb0c6da89d7c8abe4b339f5c2f8fb", sensor_msgs::MultiEchoLaserScan::MD5SUM);
+        assert_eq!("690f272f0640d2631c305eeb8301e59d", sensor_msgs::MultiDOFJointState::MD5SUM);
+        assert_eq!("bef1df590ed75ed1f393692395e15482", sensor_msgs::SetCameraInfo::MD5SUM);
+        assert_eq!("94810edda583a504dfda3829e70d7eec", tf2_msgs::TFMessage::MD5SUM);
+        assert_eq!("cd5e73d190d741a2f92e81eda573aca7", nav_msgs::Odometry::MD5SUM);
+        assert_eq!("6cdd0a18e0aff5b0a3ca2326a89b54ff", nav_msgs::GetMap::MD5SUM);
+        assert_eq!("nav_msgs/GetMapResponse", nav_msgs::GetMapResponse::ROS_TYPE);
+    }
+}
@@ -0,0 +1,137 @@
+//! Sources of ROSMSG binary data for the deserializer.
+//!
+//! The deserializer is generic over the `Read` trait defined here, which
+//! allows it to borrow strings and byte arrays straight from an in-memory
+//! buffer, while still supporting arbitrary IO streams.
+
+use super::error::{ErrorKind, Result, ResultExt};
+use std::io;
+
+/// A byte source that the ROSMSG deserializer can consume.
+///
+/// This trait is implemented by `IoRead`, which wraps any `io::Read`, and
+/// `SliceRead`, which borrows data from a byte slice for the `'de` lifetime.
+pub trait Read<'de> {
+    /// Fill the whole buffer with the next bytes of the source.
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()>;
+
+    /// Read the next `len` bytes of the source.
+    ///
+    /// Sources backed by memory that outlives `'de` return borrowed data,
+    /// while other sources copy the data into a new buffer.
+    fn read_bytes(&mut self, len: usize) -> Result<Reference<'de>>;
+
+    /// Discard the next `len` bytes of the source.
+    ///
+    /// The default implementation reads the bytes with `read_bytes`.
+    fn skip(&mut self, len: usize) -> Result<()> {
+        self.read_bytes(len).map(|_| ())
+    }
+}
+
+/// Bytes returned by a `Read` source.
+#[derive(Debug, PartialEq)]
+pub enum Reference<'de> {
+    /// Data borrowed from the underlying buffer.
+    Borrowed(&'de [u8]),
+    /// Data copied out of the underlying stream.
+    Copied(Vec<u8>),
+}
+
+/// ROSMSG source reading from an `io::Read` stream.
+pub struct IoRead<R> {
+    reader: R,
+}
+
+impl<R> IoRead<R>
+where
+    R: io::Read,
+{
+    /// Create a ROSMSG source from an IO stream.
+    pub fn new(reader: R) -> Self {
+        IoRead { reader }
+    }
+
+    /// Unwrap the `Reader` from the `IoRead`.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<'de, R> Read<'de> for IoRead<R>
+where
+    R: io::Read,
+{
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.reader
+            .read_exact(buf)
+            .chain_err(|| ErrorKind::EndOfBuffer)
+    }
+
+    #[inline]
+    fn read_bytes(&mut self, len: usize) -> Result<Reference<'de>> {
+        let mut buffer = vec![0; len];
+        self.read_exact(&mut buffer)?;
+        Ok(Reference::Copied(buffer))
+    }
+
+    #[inline]
+    fn skip(&mut self, len: usize) -> Result<()> {
+        let mut data = io::Read::take(&mut self.reader, len as u64);
+        let skipped = io::copy(&mut data, &mut io::sink())?;
+        if skipped < len as u64 {
+            bail!(ErrorKind::EndOfBuffer);
+        }
+        Ok(())
+    }
+}
+
+/// ROSMSG source borrowing data from a byte slice.
+pub struct SliceRead<'de> {
+    slice: &'de [u8],
+}
+
+impl<'de> SliceRead<'de> {
+    /// Create a ROSMSG source from a byte slice.
+    pub fn new(slice: &'de [u8]) -> Self {
+        SliceRead { slice }
+    }
+
+    /// Get the part of the slice that has not been read yet.
+    pub fn remaining(&self) -> &'de [u8] {
+        self.slice
+    }
+}
+
+impl<'de> Read<'de> for SliceRead<'de> {
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        if buf.len() > self.slice.len() {
+            bail!(ErrorKind::EndOfBuffer);
+        }
+        let (data, rest) = self.slice.split_at(buf.len());
+        buf.copy_from_slice(data);
+        self.slice = rest;
+        Ok(())
+    }
+
+    #[inline]
+    fn read_bytes(&mut self, len: usize) -> Result<Reference<'de>> {
+        if len > self.slice.len() {
+            bail!(ErrorKind::EndOfBuffer);
+        }
+        let (data, rest) = self.slice.split_at(len);
+        self.slice = rest;
+        Ok(Reference::Borrowed(data))
+    }
+
+    #[inline]
+    fn skip(&mut self, len: usize) -> Result<()> {
+        if len > self.slice.len() {
+            bail!(ErrorKind::EndOfBuffer);
+        }
+        self.slice = &self.slice[len..];
+        Ok(())
+    }
+}
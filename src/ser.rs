@@ -3,14 +3,22 @@
 //! Data types supported by ROSMSG are supported as well. This results in the
 //! lack of support for:
 //!
-//! * Enums of any type, including `Option`
+//! * Enums of any type, including `Option`, unless enabled through `Config`
+//! * Enums with variants that contain data
 //! * `char`, so use one character `String`s instead
 //! * Maps that can't be boiled down to `<String, String>`
 
 use byteorder::{LittleEndian, WriteBytesExt};
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
 use serde::ser::{self, Impossible};
+use super::config::{Config, EnumEncoding, OptionEncoding};
 use super::error::{Error, ErrorKind, Result};
 use std::io;
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
 
 /// A structure for serializing Rust values into ROSMSG binary data.
 ///
@@ -20,6 +28,7 @@ use std::io;
 /// Prefer using `to_writer` and `to_vec`.
 pub struct Serializer<W> {
     writer: W,
+    config: Config,
 }
 
 impl<W> Serializer<W>
@@ -44,7 +53,35 @@ impl<W> Serializer<W>
     /// # }
     /// ```
     pub fn new(writer: W) -> Self {
-        Serializer { writer: writer }
+        Serializer {
+            writer,
+            config: Config::default(),
+        }
+    }
+
+    /// Set the configuration used for encoding types that ROSMSG lacks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate serde_rosmsg;
+    /// # use serde_rosmsg::ser::Serializer;
+    /// # use serde_rosmsg::config::{Config, OptionEncoding};
+    /// # extern crate serde;
+    /// # fn main() {
+    /// use serde::ser::Serialize;
+    ///
+    /// let config = Config::new().option_encoding(OptionEncoding::Array);
+    /// let mut cursor = std::io::Cursor::new(Vec::new());
+    /// Some(7u8).serialize(
+    ///         &mut Serializer::new(&mut cursor).with_config(config)).unwrap();
+    ///
+    /// assert_eq!(cursor.into_inner(), b"\x01\0\0\0\x07");
+    /// # }
+    /// ```
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
     }
 
     /// Unwrap the `Writer` from the `Serializer`.
@@ -151,12 +188,21 @@ impl<'a, W> ser::Serializer for &'a mut Serializer<W>
 
     #[inline]
     fn serialize_none(self) -> SerializerResult {
-        bail!(ErrorKind::UnsupportedEnumType)
+        match self.config.get_option_encoding() {
+            OptionEncoding::Unsupported => bail!(ErrorKind::UnsupportedEnumType),
+            OptionEncoding::Array => self.serialize_u32(0),
+        }
     }
 
     #[inline]
-    fn serialize_some<T: ?Sized + ser::Serialize>(self, _value: &T) -> SerializerResult {
-        bail!(ErrorKind::UnsupportedEnumType)
+    fn serialize_some<T: ?Sized + ser::Serialize>(self, value: &T) -> SerializerResult {
+        match self.config.get_option_encoding() {
+            OptionEncoding::Unsupported => bail!(ErrorKind::UnsupportedEnumType),
+            OptionEncoding::Array => {
+                self.serialize_u32(1)?;
+                value.serialize(self)
+            }
+        }
     }
 
     #[inline]
@@ -172,10 +218,18 @@ impl<'a, W> ser::Serializer for &'a mut Serializer<W>
     #[inline]
     fn serialize_unit_variant(self,
                               _name: &'static str,
-                              _variant_index: u32,
+                              variant_index: u32,
                               _variant: &'static str)
                               -> SerializerResult {
-        bail!(ErrorKind::UnsupportedEnumType)
+        let index = variant_index as i64;
+        match self.config.get_enum_encoding() {
+            EnumEncoding::Unsupported => bail!(ErrorKind::UnsupportedEnumType),
+            EnumEncoding::U8 if index <= u8::MAX as i64 => self.serialize_u8(index as u8),
+            EnumEncoding::I8 if index <= i8::MAX as i64 => self.serialize_i8(index as i8),
+            EnumEncoding::U16 if index <= u16::MAX as i64 => self.serialize_u16(index as u16),
+            EnumEncoding::I32 if index <= i32::MAX as i64 => self.serialize_i32(index as i32),
+            _ => bail!(ErrorKind::BadEnumValue(index)),
+        }
     }
 
     #[inline]
@@ -365,7 +419,7 @@ impl<'a, W> ser::SerializeMap for CompoundMap<'a, W>
     {
         self.item = Vec::<u8>::new();
         let mut buffer = Vec::<u8>::new();
-        key.serialize(&mut Serializer::new(&mut buffer))?;
+        key.serialize(&mut Serializer::new(&mut buffer).with_config(self.ser.config))?;
         self.item.extend(buffer.into_iter().skip(4));
         self.item.push(b'=');
         Ok(())
@@ -377,7 +431,7 @@ impl<'a, W> ser::SerializeMap for CompoundMap<'a, W>
     {
         use serde::Serializer as SerializerTrait;
         let mut buffer = Vec::<u8>::new();
-        value.serialize(&mut Serializer::new(&mut buffer))?;
+        value.serialize(&mut Serializer::new(&mut buffer).with_config(self.ser.config))?;
         self.item.extend(buffer.into_iter().skip(4));
         self.ser.serialize_bytes(&self.item)
     }
@@ -395,6 +449,70 @@ impl ser::Error for Error {
     }
 }
 
+/// Writer that discards all data, only keeping track of its length.
+struct SizeCounter {
+    length: usize,
+}
+
+impl io::Write for SizeCounter {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.length += buf.len();
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Calculate the length of the given data structure `T` serialized as ROSMSG.
+///
+/// The returned length includes the 4-byte size prefix, so it matches the
+/// length of the data produced by `to_vec` and `to_writer`.
+///
+/// Calculation can fail if `T`'s implementation of `Serialize` decides to
+/// fail, or if the structure contains unsupported elements. It also fails if
+/// the serialized data would not fit into the 32-bit size prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_rosmsg::ser::{serialized_size, to_vec};
+/// let data = String::from("Hello, World!");
+/// assert_eq!(serialized_size(&data).unwrap(), 21);
+/// assert_eq!(to_vec(&data).unwrap().len(), 21);
+/// ```
+pub fn serialized_size<T>(value: &T) -> Result<u32>
+    where T: ser::Serialize
+{
+    Config::default().serialized_size(value)
+}
+
+/// Calculate the length of the given data structure `T` serialized as ROSMSG,
+/// without the 4-byte size prefix.
+///
+/// This is the value written into the size prefix by `to_vec` and
+/// `to_writer`.
+///
+/// Calculation can fail if `T`'s implementation of `Serialize` decides to
+/// fail, or if the structure contains unsupported elements. It also fails if
+/// the serialized data would not fit into the 32-bit size prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_rosmsg::ser::serialized_body_size;
+/// assert_eq!(serialized_body_size(&String::from("Hello, World!")).unwrap(), 17);
+/// assert_eq!(serialized_body_size(&(1u8, 2u32)).unwrap(), 5);
+/// ```
+pub fn serialized_body_size<T>(value: &T) -> Result<u32>
+    where T: ser::Serialize
+{
+    Config::default().serialized_body_size(value)
+}
+
 /// Serialize the given data structure `T` as ROSMSG into the IO stream.
 ///
 /// Serialization can fail if `T`'s implementation of `Serialize` decides to
@@ -402,6 +520,9 @@ impl ser::Error for Error {
 ///
 /// Finally, it can also fail due to writer failure.
 ///
+/// The value is traversed twice: once to calculate the size prefix, and once
+/// to write the data directly into `writer`, without buffering it.
+///
 /// # Examples
 ///
 /// ```rust
@@ -415,12 +536,7 @@ pub fn to_writer<W, T>(writer: &mut W, value: &T) -> Result<()>
     where W: io::Write,
           T: ser::Serialize
 {
-    let mut buffer = Vec::new();
-    value.serialize(&mut Serializer::new(&mut buffer))?;
-    writer
-        .write_u32::<LittleEndian>(buffer.len() as u32)
-        .and_then(|_| writer.write_all(&buffer))
-        .map_err(|v| v.into())
+    Config::default().to_writer(writer, value)
 }
 
 /// Serialize the given data structure `T` as a ROSMSG byte vector.
@@ -438,9 +554,163 @@ pub fn to_writer<W, T>(writer: &mut W, value: &T) -> Result<()>
 pub fn to_vec<T>(value: &T) -> Result<Vec<u8>>
     where T: ser::Serialize
 {
-    let mut writer = Vec::with_capacity(128);
-    to_writer(&mut writer, value)?;
-    Ok(writer)
+    Config::default().to_vec(value)
+}
+
+/// Serialize the given data structure `T` as ROSMSG into an asynchronous
+/// stream.
+///
+/// Serialization can fail if `T`'s implementation of `Serialize` decides to
+/// fail. It can also fail if the structure contains unsupported elements.
+///
+/// Finally, it can also fail due to writer failure.
+///
+/// The value is serialized into a buffer right away, and the returned future
+/// writes that buffer into `writer`, yielding whenever the stream is not
+/// ready to accept more data. It is available with the `async` feature
+/// enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate serde_rosmsg;
+/// # use futures::executor::block_on;
+/// # use futures::io::Cursor;
+/// # use serde_rosmsg::ser::to_async_writer;
+/// # fn main() {
+/// let mut cursor = Cursor::new(Vec::new());
+/// block_on(to_async_writer(&mut cursor, &String::from("Hello, World!"))).unwrap();
+/// assert_eq!(cursor.into_inner(), b"\x11\0\0\0\x0d\0\0\0Hello, World!");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub fn to_async_writer<'a, W, T>(writer: &'a mut W, value: &T) -> WriteMessage<'a, W>
+    where W: AsyncWrite + Unpin + ?Sized,
+          T: ser::Serialize
+{
+    Config::default().to_async_writer(writer, value)
+}
+
+/// Future returned by `to_async_writer`.
+#[cfg(feature = "async")]
+#[must_use = "futures do nothing unless polled"]
+pub struct WriteMessage<'a, W: ?Sized + 'a> {
+    writer: &'a mut W,
+    data: Vec<u8>,
+    written: usize,
+    error: Option<Error>,
+}
+
+#[cfg(feature = "async")]
+impl<'a, W> Future for WriteMessage<'a, W>
+    where W: AsyncWrite + Unpin + ?Sized
+{
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        let this = &mut *self;
+        if let Some(error) = this.error.take() {
+            return Poll::Ready(Err(error));
+        }
+        while this.written < this.data.len() {
+            match Pin::new(&mut *this.writer).poll_write(cx, &this.data[this.written..]) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(0)) => {
+                    let error = io::Error::from(io::ErrorKind::WriteZero);
+                    return Poll::Ready(Err(error.into()));
+                }
+                Poll::Ready(Ok(len)) => this.written += len,
+                Poll::Ready(Err(ref error)) if error.kind() == io::ErrorKind::Interrupted => {}
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error.into())),
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Config {
+    /// Calculate the length of `T` serialized as ROSMSG with this
+    /// configuration, including the 4-byte size prefix.
+    ///
+    /// Behaves like `ser::serialized_size`.
+    pub fn serialized_size<T>(&self, value: &T) -> Result<u32>
+        where T: ser::Serialize
+    {
+        match self.serialized_body_size(value)?.checked_add(4) {
+            Some(length) => Ok(length),
+            None => bail!(ErrorKind::MessageTooLarge),
+        }
+    }
+
+    /// Calculate the length of `T` serialized as ROSMSG with this
+    /// configuration, without the 4-byte size prefix.
+    ///
+    /// Behaves like `ser::serialized_body_size`.
+    pub fn serialized_body_size<T>(&self, value: &T) -> Result<u32>
+        where T: ser::Serialize
+    {
+        let mut serializer = Serializer::new(SizeCounter { length: 0 }).with_config(*self);
+        value.serialize(&mut serializer)?;
+        let length = serializer.into_inner().length;
+        if length > u32::MAX as usize {
+            bail!(ErrorKind::MessageTooLarge);
+        }
+        Ok(length as u32)
+    }
+
+    /// Serialize `T` as ROSMSG into the IO stream with this configuration.
+    ///
+    /// Behaves like `ser::to_writer`.
+    pub fn to_writer<W, T>(&self, writer: &mut W, value: &T) -> Result<()>
+        where W: io::Write,
+              T: ser::Serialize
+    {
+        let length = self.serialized_body_size(value)?;
+        self.write_with_length(writer, length, value)
+    }
+
+    /// Serialize `T` as a ROSMSG byte vector with this configuration.
+    ///
+    /// Behaves like `ser::to_vec`.
+    pub fn to_vec<T>(&self, value: &T) -> Result<Vec<u8>>
+        where T: ser::Serialize
+    {
+        let length = self.serialized_body_size(value)?;
+        let mut writer = Vec::with_capacity(length as usize + 4);
+        self.write_with_length(&mut writer, length, value)?;
+        Ok(writer)
+    }
+
+    /// Write the size prefix, followed by `T` serialized as ROSMSG.
+    fn write_with_length<W, T>(&self, writer: &mut W, length: u32, value: &T) -> Result<()>
+        where W: io::Write,
+              T: ser::Serialize
+    {
+        writer.write_u32::<LittleEndian>(length)?;
+        value.serialize(&mut Serializer::new(writer).with_config(*self))
+    }
+
+    /// Serialize `T` as ROSMSG into an asynchronous stream with this
+    /// configuration.
+    ///
+    /// Behaves like `ser::to_async_writer`.
+    #[cfg(feature = "async")]
+    pub fn to_async_writer<'a, W, T>(&self, writer: &'a mut W, value: &T) -> WriteMessage<'a, W>
+        where W: AsyncWrite + Unpin + ?Sized,
+              T: ser::Serialize
+    {
+        let (data, error) = match self.to_vec(value) {
+            Ok(data) => (data, None),
+            Err(error) => (Vec::new(), Some(error)),
+        };
+        WriteMessage {
+            writer,
+            data,
+            written: 0,
+            error,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -597,6 +867,118 @@ mod tests {
                    to_vec(&v).unwrap());
     }
 
+    #[test]
+    fn writes_directly_into_writer() {
+        let mut cursor = io::Cursor::new(vec![0xFF, 0xFF]);
+        cursor.set_position(2);
+        to_writer(&mut cursor, &(7u8, "ABC", vec![1u16, 2])).unwrap();
+        assert_eq!(vec![0xFF, 0xFF, 16, 0, 0, 0, 7, 3, 0, 0, 0, 65, 66, 67, 2, 0, 0, 0, 1, 0,
+                        2, 0],
+                   cursor.into_inner());
+    }
+
+    #[test]
+    fn traverses_values_twice() {
+        struct Counted(::std::cell::Cell<usize>);
+
+        impl ser::Serialize for Counted {
+            fn serialize<S: ser::Serializer>(&self,
+                                             serializer: S)
+                                             -> ::std::result::Result<S::Ok, S::Error> {
+                self.0.set(self.0.get() + 1);
+                serializer.serialize_u8(7)
+            }
+        }
+
+        let value = Counted(Default::default());
+        assert_eq!(vec![1, 0, 0, 0, 7], to_vec(&value).unwrap());
+        assert_eq!(2, value.0.get());
+        to_writer(&mut Vec::new(), &value).unwrap();
+        assert_eq!(4, value.0.get());
+    }
+
+    #[test]
+    fn counts_body_size() {
+        assert_eq!(0, serialized_body_size(&()).unwrap());
+        assert_eq!(8, serialized_body_size(&1u64).unwrap());
+        assert_eq!(17, serialized_body_size(&"Hello, World!").unwrap());
+        assert_eq!(12, serialized_body_size(&vec![7i16, 1025, 33, 57]).unwrap());
+        let mut data = HashMap::<String, String>::new();
+        data.insert(String::from("abc"), String::from("123"));
+        assert_eq!(11, serialized_body_size(&data).unwrap());
+    }
+
+    #[test]
+    fn counts_size_of_complex_struct() {
+        let v = TestStructBig {
+            a: vec![TestStructPart {
+                        a: String::from("ABC"),
+                        b: true,
+                    },
+                    TestStructPart {
+                        a: String::from("1!!!!"),
+                        b: false,
+                    }],
+            b: String::from("EEe"),
+        };
+        assert_eq!(to_vec(&v).unwrap().len() as u32, serialized_size(&v).unwrap());
+        assert_eq!(serialized_size(&v).unwrap() - 4,
+                   serialized_body_size(&v).unwrap());
+    }
+
+    #[test]
+    fn rejects_option_by_default() {
+        to_vec(&Some(5u8)).unwrap_err();
+        to_vec(&None::<u8>).unwrap_err();
+    }
+
+    #[test]
+    fn writes_option_as_array() {
+        let config = Config::new().option_encoding(OptionEncoding::Array);
+        assert_eq!(vec![4, 0, 0, 0, 0, 0, 0, 0], config.to_vec(&None::<u16>).unwrap());
+        assert_eq!(vec![6, 0, 0, 0, 1, 0, 0, 0, 0x34, 0xA2],
+                   config.to_vec(&Some(0xA234u16)).unwrap());
+        assert_eq!(vec![15, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 65, 66, 67, 0, 0, 0, 0],
+                   config.to_vec(&(Some("ABC"), None::<bool>)).unwrap());
+        assert_eq!(19, config.serialized_size(&(Some("ABC"), None::<bool>)).unwrap());
+    }
+
+    #[derive(Serialize)]
+    enum TestEnum {
+        A,
+        B,
+        C,
+    }
+
+    #[derive(Serialize)]
+    enum TestDataEnum {
+        A(u8),
+    }
+
+    #[test]
+    fn rejects_enum_by_default() {
+        to_vec(&TestEnum::A).unwrap_err();
+    }
+
+    #[test]
+    fn writes_enum_as_integer() {
+        let config = Config::new().enum_encoding(EnumEncoding::U8);
+        assert_eq!(vec![1, 0, 0, 0, 2], config.to_vec(&TestEnum::C).unwrap());
+        let config = Config::new().enum_encoding(EnumEncoding::I8);
+        assert_eq!(vec![1, 0, 0, 0, 1], config.to_vec(&TestEnum::B).unwrap());
+        let config = Config::new().enum_encoding(EnumEncoding::U16);
+        assert_eq!(vec![2, 0, 0, 0, 2, 0], config.to_vec(&TestEnum::C).unwrap());
+        let config = Config::new().enum_encoding(EnumEncoding::I32);
+        assert_eq!(vec![8, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0],
+                   config.to_vec(&(TestEnum::A, TestEnum::C)).unwrap());
+    }
+
+    #[test]
+    fn rejects_enum_with_data() {
+        let config = Config::new().enum_encoding(EnumEncoding::U8);
+        config.to_vec(&TestDataEnum::A(5)).unwrap_err();
+    }
+
     #[test]
     fn writes_empty_string_string_map() {
         let data = HashMap::<String, String>::new();
@@ -622,4 +1004,63 @@ mod tests {
                 vec![21, 0, 0, 0, 6, 0, 0, 0, 65, 65, 65, 61, 66, 48, 7, 0, 0, 0, 97, 98, 99,
                      61, 49, 50, 51] == answer);
     }
+
+    /// Writer accepting up to two bytes at a time, and nothing every other
+    /// poll.
+    #[cfg(feature = "async")]
+    struct SlowWriter {
+        data: Vec<u8>,
+        ready: bool,
+    }
+
+    #[cfg(feature = "async")]
+    impl AsyncWrite for SlowWriter {
+        fn poll_write(mut self: Pin<&mut Self>,
+                      cx: &mut Context,
+                      buf: &[u8])
+                      -> Poll<io::Result<usize>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            let len = ::std::cmp::min(buf.len(), 2);
+            self.data.extend_from_slice(&buf[..len]);
+            Poll::Ready(Ok(len))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn writes_async_streams() {
+        use futures::executor::block_on;
+        let mut writer = SlowWriter {
+            data: Vec::new(),
+            ready: false,
+        };
+        block_on(to_async_writer(&mut writer, &0xCD012345u32)).unwrap();
+        block_on(to_async_writer(&mut writer, &(1u8, 2u8, 3u8))).unwrap();
+        assert_eq!(vec![4, 0, 0, 0, 0x45, 0x23, 1, 0xCD, 3, 0, 0, 0, 1, 2, 3],
+                   writer.data);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn reports_async_serialization_errors() {
+        use futures::executor::block_on;
+        let mut writer = SlowWriter {
+            data: Vec::new(),
+            ready: false,
+        };
+        block_on(to_async_writer(&mut writer, &'a')).unwrap_err();
+        assert!(writer.data.is_empty());
+    }
 }
@@ -0,0 +1,240 @@
+//! Framing of TCPROS service calls.
+//!
+//! After exchanging connection headers, a service client sends requests as
+//! regular length-prefixed messages, and the server answers each of them
+//! with a response prefixed by a single `ok` byte. If the call succeeded,
+//! `ok` is non-zero and is followed by the response message. Otherwise `ok`
+//! is zero and is followed by an error message, as a length-prefixed string.
+//!
+//! Failed calls are reported by the decoding functions as
+//! `ErrorKind::ServiceError`, which carries the error message.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde_rosmsg::error::ErrorKind;
+//! # use serde_rosmsg::service::*;
+//! let request = encode_service_request(&(2i64, 3i64)).unwrap();
+//! let (a, b): (i64, i64) = decode_service_request(&request).unwrap();
+//!
+//! let response = encode_service_response(Ok(&(a + b))).unwrap();
+//! assert_eq!(response, b"\x01\x08\0\0\0\x05\0\0\0\0\0\0\0");
+//! assert_eq!(decode_service_response::<i64>(&response).unwrap(), 5);
+//!
+//! let response = encode_service_response::<i64>(Err("overflow")).unwrap();
+//! assert_eq!(response, b"\0\x08\0\0\0overflow");
+//! match *decode_service_response::<i64>(&response).unwrap_err().kind() {
+//!     ErrorKind::ServiceError(ref message) => assert_eq!(message, "overflow"),
+//!     ref error => panic!("Unexpected error: {:?}", error),
+//! }
+//! ```
+
+use super::de::{from_reader, from_slice};
+use super::error::{ErrorKind, Result};
+use super::ser::{to_vec, to_writer};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
+use serde::de::{Deserialize, DeserializeOwned};
+use serde::Serialize;
+use std::io::{self, Read};
+use std::result;
+
+/// Serialize a service request.
+///
+/// Requests are framed like any other message.
+pub fn encode_service_request<T: Serialize>(request: &T) -> Result<Vec<u8>> {
+    to_vec(request)
+}
+
+/// Deserialize a service request.
+///
+/// Requests are framed like any other message.
+pub fn decode_service_request<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T> {
+    from_slice(bytes)
+}
+
+/// Serialize a service response, either the response message of a successful
+/// call or the error message of a failed one.
+pub fn encode_service_response<T: Serialize>(response: result::Result<&T, &str>)
+                                             -> Result<Vec<u8>> {
+    let mut writer = Vec::new();
+    service_response_to_writer(&mut writer, response)?;
+    Ok(writer)
+}
+
+/// Serialize a service response into the IO stream.
+///
+/// Behaves like `encode_service_response`.
+pub fn service_response_to_writer<W, T>(writer: &mut W,
+                                        response: result::Result<&T, &str>)
+                                        -> Result<()>
+    where W: io::Write,
+          T: Serialize
+{
+    match response {
+        Ok(message) => {
+            writer.write_u8(1)?;
+            to_writer(writer, message)
+        }
+        Err(error) => {
+            if error.len() > u32::MAX as usize {
+                bail!(ErrorKind::MessageTooLarge);
+            }
+            writer.write_u8(0)?;
+            writer.write_u32::<LittleEndian>(error.len() as u32)?;
+            writer.write_all(error.as_bytes())?;
+            Ok(())
+        }
+    }
+}
+
+/// Deserialize a service response.
+///
+/// Responses of failed calls result in an `ErrorKind::ServiceError`.
+pub fn decode_service_response<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T> {
+    let (ok, bytes) = match bytes.split_first() {
+        Some((&ok, bytes)) => (ok, bytes),
+        None => bail!(ErrorKind::EndOfBuffer),
+    };
+    if ok != 0 {
+        return from_slice(bytes);
+    }
+    if bytes.len() < 4 {
+        bail!(ErrorKind::EndOfBuffer);
+    }
+    let (length, error) = bytes.split_at(4);
+    let length = LittleEndian::read_u32(length) as usize;
+    if error.len() < length {
+        bail!(ErrorKind::EndOfBuffer);
+    }
+    if error.len() > length {
+        bail!(ErrorKind::Underflow);
+    }
+    service_error(error.to_vec())
+}
+
+/// Deserialize a service response from the IO stream.
+///
+/// Exactly one response is read, so further responses sent over a persistent
+/// connection can be read from the same stream afterwards.
+///
+/// Behaves like `decode_service_response`.
+pub fn service_response_from_reader<R, T>(mut reader: R) -> Result<T>
+    where R: io::Read,
+          T: DeserializeOwned
+{
+    if reader.read_u8()? != 0 {
+        return from_reader(reader);
+    }
+    let length = reader.read_u32::<LittleEndian>()?;
+    let mut error = Vec::new();
+    reader.take(u64::from(length)).read_to_end(&mut error)?;
+    if error.len() < length as usize {
+        bail!(ErrorKind::EndOfBuffer);
+    }
+    service_error(error)
+}
+
+/// Report the error message of a failed service call.
+fn service_error<T>(error: Vec<u8>) -> Result<T> {
+    match String::from_utf8(error) {
+        Ok(error) => bail!(ErrorKind::ServiceError(error)),
+        Err(_) => bail!(ErrorKind::BadStringData),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct AddTwoIntsRequest {
+        a: i64,
+        b: i64,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct AddTwoIntsResponse {
+        sum: i64,
+    }
+
+    fn expect_service_error<T: ::std::fmt::Debug>(result: Result<T>, expected: &str) {
+        match *result.unwrap_err().kind() {
+            ErrorKind::ServiceError(ref error) => assert_eq!(expected, error),
+            ref error => panic!("Service error expected, got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn frames_requests() {
+        let request = AddTwoIntsRequest { a: 7, b: -2 };
+        let data = encode_service_request(&request).unwrap();
+        assert_eq!(b"\x10\0\0\0\x07\0\0\0\0\0\0\0\xfe\xff\xff\xff\xff\xff\xff\xff".to_vec(),
+                   data);
+        assert_eq!(request, decode_service_request(&data).unwrap());
+    }
+
+    #[test]
+    fn frames_successful_responses() {
+        let response = AddTwoIntsResponse { sum: 5 };
+        let data = encode_service_response(Ok(&response)).unwrap();
+        assert_eq!(b"\x01\x08\0\0\0\x05\0\0\0\0\0\0\0".to_vec(), data);
+        assert_eq!(response, decode_service_response(&data).unwrap());
+        let data = b"\x02\x08\0\0\0\x05\0\0\0\0\0\0\0";
+        assert_eq!(response, decode_service_response(data).unwrap());
+    }
+
+    #[test]
+    fn frames_failed_responses() {
+        let data = encode_service_response::<AddTwoIntsResponse>(Err("Sum is too big"))
+            .unwrap();
+        assert_eq!(b"\0\x0e\0\0\0Sum is too big".to_vec(), data);
+        expect_service_error(decode_service_response::<AddTwoIntsResponse>(&data),
+                             "Sum is too big");
+        let data = encode_service_response::<AddTwoIntsResponse>(Err("")).unwrap();
+        assert_eq!(b"\0\0\0\0\0".to_vec(), data);
+        expect_service_error(decode_service_response::<AddTwoIntsResponse>(&data), "");
+    }
+
+    #[test]
+    fn rejects_bad_responses() {
+        let cases: [(&[u8], ErrorKind); 5] = [(b"", ErrorKind::EndOfBuffer),
+                                              (b"\0\x03\0", ErrorKind::EndOfBuffer),
+                                              (b"\0\x03\0\0\0ab", ErrorKind::EndOfBuffer),
+                                              (b"\0\x01\0\0\0ab", ErrorKind::Underflow),
+                                              (b"\0\x01\0\0\0\xff", ErrorKind::BadStringData)];
+        for &(data, ref expected) in &cases {
+            let error = decode_service_response::<AddTwoIntsResponse>(data).unwrap_err();
+            assert_eq!(expected.to_string(), error.kind().to_string());
+        }
+    }
+
+    #[test]
+    fn reads_responses_from_persistent_connections() {
+        let mut data = Vec::new();
+        service_response_to_writer(&mut data, Ok(&AddTwoIntsResponse { sum: 3 })).unwrap();
+        service_response_to_writer::<_, AddTwoIntsResponse>(&mut data, Err("Overflow"))
+            .unwrap();
+        service_response_to_writer(&mut data, Ok(&AddTwoIntsResponse { sum: -1 })).unwrap();
+        let mut cursor = Cursor::new(data);
+        assert_eq!(AddTwoIntsResponse { sum: 3 },
+                   service_response_from_reader(&mut cursor).unwrap());
+        expect_service_error(service_response_from_reader::<_, AddTwoIntsResponse>(&mut cursor),
+                             "Overflow");
+        assert_eq!(AddTwoIntsResponse { sum: -1 },
+                   service_response_from_reader(&mut cursor).unwrap());
+        let error = service_response_from_reader::<_, AddTwoIntsResponse>(&mut cursor)
+            .unwrap_err();
+        match *error.kind() {
+            ErrorKind::Io(ref error) if error.kind() == io::ErrorKind::UnexpectedEof => {}
+            ref error => panic!("Unexpected EOF error expected, got: {:?}", error),
+        }
+        let mut cursor = Cursor::new(b"\0\x03\0\0\0ab".to_vec());
+        match *service_response_from_reader::<_, AddTwoIntsResponse>(&mut cursor)
+            .unwrap_err()
+            .kind() {
+            ErrorKind::EndOfBuffer => {}
+            ref error => panic!("End of buffer error expected, got: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,449 @@
+//! The built-in `time` and `duration` types of ROS messages.
+//!
+//! Both types are encoded as two 32-bit integers, seconds followed by
+//! nanoseconds. Values created through the constructors and arithmetic are
+//! normalized, so the nanoseconds are always in the range `0..1_000_000_000`,
+//! even for negative durations, matching the normalization done by `roscpp`.
+//!
+//! Arithmetic operators panic on overflow, like the ones of `std::time`,
+//! while the `checked_*` methods return `None` instead.
+//!
+//! Conversions from and to `std::time::SystemTime` and `std::time::Duration`
+//! are provided through `From` and `TryFrom`. With the `chrono` feature
+//! enabled, there are also conversions from and to `chrono::DateTime<Utc>`
+//! and `chrono::TimeDelta`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde_rosmsg::{from_slice, to_vec};
+//! # use serde_rosmsg::time::{Duration, Time};
+//! let stamp = Time::new(1487460134, 332000000);
+//! let data = to_vec(&stamp).unwrap();
+//! assert_eq!(data.len(), 12);
+//! assert_eq!(from_slice::<Time>(&data).unwrap(), stamp);
+//!
+//! let timeout = Duration::new(0, -500000000);
+//! assert_eq!(timeout, Duration { sec: -1, nsec: 500000000 });
+//! assert_eq!(to_vec(&timeout).unwrap(), b"\x08\0\0\0\xff\xff\xff\xff\0\x65\xcd\x1d");
+//!
+//! assert_eq!(stamp + timeout, Time::new(1487460133, 832000000));
+//! assert_eq!(stamp - (stamp + timeout), Duration::new(0, 500000000));
+//! ```
+
+use super::error::{Error, ErrorKind};
+use std::convert::TryFrom;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::time::{self, SystemTime, UNIX_EPOCH};
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+/// A point in time, the `time` type of ROS messages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize,
+         Deserialize)]
+pub struct Time {
+    /// Seconds since the Unix epoch.
+    pub sec: u32,
+    /// Nanoseconds since the last full second.
+    pub nsec: u32,
+}
+
+impl Time {
+    /// Create a time from seconds and nanoseconds, moving whole seconds out
+    /// of the nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seconds overflow.
+    pub fn new(sec: u32, nsec: u32) -> Self {
+        Self::checked_new(sec, nsec).expect("overflow when creating time")
+    }
+
+    /// Create a time from seconds and nanoseconds, or `None` if the seconds
+    /// overflow.
+    pub fn checked_new(sec: u32, nsec: u32) -> Option<Self> {
+        Self::from_nanos(i64::from(sec) * NANOS_PER_SEC + i64::from(nsec))
+    }
+
+    /// Create a time from nanoseconds since the Unix epoch, or `None` if it
+    /// is out of range.
+    pub fn from_nanos(nanos: i64) -> Option<Self> {
+        if nanos < 0 {
+            return None;
+        }
+        let sec = u32::try_from(nanos / NANOS_PER_SEC).ok()?;
+        Some(Time {
+            sec,
+            nsec: (nanos % NANOS_PER_SEC) as u32,
+        })
+    }
+
+    /// Nanoseconds since the Unix epoch.
+    pub fn as_nanos(self) -> i64 {
+        i64::from(self.sec) * NANOS_PER_SEC + i64::from(self.nsec)
+    }
+
+    /// The current system time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is out of range, before 1970 or after 2106.
+    pub fn now() -> Self {
+        Self::try_from(SystemTime::now()).expect("system time out of range")
+    }
+
+    /// Add a duration, or return `None` if the result is out of range.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        Self::from_nanos(self.as_nanos() + duration.as_nanos())
+    }
+
+    /// Subtract a duration, or return `None` if the result is out of range.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        Self::from_nanos(self.as_nanos() - duration.as_nanos())
+    }
+
+    /// Get the duration since an earlier time, which is negative if the
+    /// other time is actually later, or `None` if it is out of range.
+    pub fn checked_duration_since(self, earlier: Time) -> Option<Duration> {
+        Duration::from_nanos(self.as_nanos() - earlier.as_nanos())
+    }
+}
+
+impl Add<Duration> for Time {
+    type Output = Time;
+
+    fn add(self, duration: Duration) -> Time {
+        self.checked_add(duration).expect("overflow when adding duration to time")
+    }
+}
+
+impl AddAssign<Duration> for Time {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for Time {
+    type Output = Time;
+
+    fn sub(self, duration: Duration) -> Time {
+        self.checked_sub(duration).expect("overflow when subtracting duration from time")
+    }
+}
+
+impl SubAssign<Duration> for Time {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub<Time> for Time {
+    type Output = Duration;
+
+    fn sub(self, earlier: Time) -> Duration {
+        self.checked_duration_since(earlier).expect("overflow when subtracting times")
+    }
+}
+
+impl TryFrom<SystemTime> for Time {
+    type Error = Error;
+
+    fn try_from(value: SystemTime) -> Result<Self, Error> {
+        let since_epoch = value.duration_since(UNIX_EPOCH)
+            .map_err(|_| ErrorKind::TimeOutOfRange)?;
+        let sec = u32::try_from(since_epoch.as_secs()).map_err(|_| ErrorKind::TimeOutOfRange)?;
+        Ok(Time {
+            sec,
+            nsec: since_epoch.subsec_nanos(),
+        })
+    }
+}
+
+impl From<Time> for SystemTime {
+    fn from(value: Time) -> Self {
+        UNIX_EPOCH + time::Duration::from_nanos(value.as_nanos() as u64)
+    }
+}
+
+/// A span of time, the `duration` type of ROS messages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize,
+         Deserialize)]
+pub struct Duration {
+    /// Whole seconds of the duration.
+    pub sec: i32,
+    /// Nanoseconds added to the seconds.
+    pub nsec: i32,
+}
+
+impl Duration {
+    /// Create a duration from seconds and nanoseconds, moving whole seconds
+    /// out of the nanoseconds and making the nanoseconds positive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seconds overflow.
+    pub fn new(sec: i32, nsec: i32) -> Self {
+        Self::checked_new(sec, nsec).expect("overflow when creating duration")
+    }
+
+    /// Create a duration from seconds and nanoseconds, or `None` if the
+    /// seconds overflow.
+    pub fn checked_new(sec: i32, nsec: i32) -> Option<Self> {
+        Self::from_nanos(i64::from(sec) * NANOS_PER_SEC + i64::from(nsec))
+    }
+
+    /// Create a duration from nanoseconds, or `None` if it is out of range.
+    pub fn from_nanos(nanos: i64) -> Option<Self> {
+        let sec = i32::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?;
+        Some(Duration {
+            sec,
+            nsec: nanos.rem_euclid(NANOS_PER_SEC) as i32,
+        })
+    }
+
+    /// Total nanoseconds of the duration.
+    pub fn as_nanos(self) -> i64 {
+        i64::from(self.sec) * NANOS_PER_SEC + i64::from(self.nsec)
+    }
+
+    /// Check if the duration is shorter than zero.
+    pub fn is_negative(self) -> bool {
+        self.as_nanos() < 0
+    }
+
+    /// Add a duration, or return `None` if the result is out of range.
+    pub fn checked_add(self, other: Duration) -> Option<Self> {
+        Self::from_nanos(self.as_nanos() + other.as_nanos())
+    }
+
+    /// Subtract a duration, or return `None` if the result is out of range.
+    pub fn checked_sub(self, other: Duration) -> Option<Self> {
+        Self::from_nanos(self.as_nanos() - other.as_nanos())
+    }
+
+    /// Negate the duration, or return `None` if the result is out of range.
+    pub fn checked_neg(self) -> Option<Self> {
+        Self::from_nanos(-self.as_nanos())
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        self.checked_add(other).expect("overflow when adding durations")
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        self.checked_sub(other).expect("overflow when subtracting durations")
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        self.checked_neg().expect("overflow when negating duration")
+    }
+}
+
+impl TryFrom<time::Duration> for Duration {
+    type Error = Error;
+
+    fn try_from(value: time::Duration) -> Result<Self, Error> {
+        let sec = i32::try_from(value.as_secs()).map_err(|_| ErrorKind::TimeOutOfRange)?;
+        Ok(Duration {
+            sec,
+            nsec: value.subsec_nanos() as i32,
+        })
+    }
+}
+
+impl TryFrom<Duration> for time::Duration {
+    type Error = Error;
+
+    fn try_from(value: Duration) -> Result<Self, Error> {
+        let nanos = u64::try_from(value.as_nanos()).map_err(|_| ErrorKind::TimeOutOfRange)?;
+        Ok(time::Duration::from_nanos(nanos))
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_conversions {
+    use super::{Duration, Time};
+    use chrono::{DateTime, TimeDelta, Utc};
+    use error::{Error, ErrorKind};
+    use std::convert::TryFrom;
+
+    impl TryFrom<DateTime<Utc>> for Time {
+        type Error = Error;
+
+        fn try_from(value: DateTime<Utc>) -> Result<Self, Error> {
+            let nanos = value.timestamp()
+                .checked_mul(super::NANOS_PER_SEC)
+                .and_then(|nanos| nanos.checked_add(i64::from(value.timestamp_subsec_nanos())));
+            nanos.and_then(Time::from_nanos).ok_or_else(|| ErrorKind::TimeOutOfRange.into())
+        }
+    }
+
+    impl From<Time> for DateTime<Utc> {
+        fn from(value: Time) -> Self {
+            DateTime::from_timestamp_nanos(value.as_nanos())
+        }
+    }
+
+    impl TryFrom<TimeDelta> for Duration {
+        type Error = Error;
+
+        fn try_from(value: TimeDelta) -> Result<Self, Error> {
+            value.num_nanoseconds()
+                .and_then(Duration::from_nanos)
+                .ok_or_else(|| ErrorKind::TimeOutOfRange.into())
+        }
+    }
+
+    impl From<Duration> for TimeDelta {
+        fn from(value: Duration) -> Self {
+            TimeDelta::nanoseconds(value.as_nanos())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {from_slice, to_vec};
+
+    #[test]
+    fn encodes_seconds_and_nanoseconds() {
+        let value = (Time::new(1, 2), Duration::new(0, -500_000_000));
+        let data = to_vec(&value).unwrap();
+        assert_eq!(b"\x10\0\0\0\x01\0\0\0\x02\0\0\0\xff\xff\xff\xff\0\x65\xcd\x1d".to_vec(),
+                   data);
+        assert_eq!(value, from_slice(&data).unwrap());
+    }
+
+    #[test]
+    fn normalizes_nanoseconds() {
+        assert_eq!(Time { sec: 3, nsec: 5 }, Time::new(1, 2_000_000_005));
+        assert_eq!(Time { sec: 4, nsec: 294967295 }, Time::new(0, u32::MAX));
+        assert_eq!(None, Time::checked_new(u32::MAX, 1_000_000_000));
+        assert_eq!(Duration { sec: 2, nsec: 5 }, Duration::new(0, 2_000_000_005));
+        assert_eq!(Duration { sec: -1, nsec: 999_999_999 }, Duration::new(0, -1));
+        assert_eq!(Duration { sec: -3, nsec: 0 }, Duration::new(-1, -2_000_000_000));
+        assert_eq!(Duration { sec: -1, nsec: 0 }, Duration::new(-2, 1_000_000_000));
+        assert_eq!(None, Duration::checked_new(i32::MIN, -1));
+        assert_eq!(None, Duration::checked_new(i32::MAX, 1_000_000_000));
+    }
+
+    #[test]
+    fn converts_nanoseconds() {
+        assert_eq!(Some(Time::new(2, 3)), Time::from_nanos(2_000_000_003));
+        assert_eq!(None, Time::from_nanos(-1));
+        assert_eq!(None, Time::from_nanos((i64::from(u32::MAX) + 1) * NANOS_PER_SEC));
+        assert_eq!(2_000_000_003, Time::new(2, 3).as_nanos());
+        assert_eq!(Some(Duration::new(-3, 999_999_997)), Duration::from_nanos(-2_000_000_003));
+        assert_eq!(-2_000_000_003, Duration::new(-3, 999_999_997).as_nanos());
+        assert!(Duration::new(0, -1).is_negative());
+        assert!(!Duration::new(0, 0).is_negative());
+    }
+
+    #[test]
+    fn adds_and_subtracts() {
+        let time = Time::new(10, 900_000_000);
+        let duration = Duration::new(1, 200_000_000);
+        assert_eq!(Time::new(12, 100_000_000), time + duration);
+        assert_eq!(Time::new(9, 700_000_000), time - duration);
+        assert_eq!(Time::new(9, 700_000_000), time + -duration);
+        assert_eq!(Duration::new(-1, -200_000_000), (time - duration) - time);
+        assert_eq!(Duration::new(2, 400_000_000), duration + duration);
+        assert_eq!(Duration::new(0, 0), duration - duration);
+        let mut value = time;
+        value += duration;
+        value -= duration;
+        assert_eq!(time, value);
+        let mut value = duration;
+        value += duration;
+        value -= Duration::new(0, 400_000_000);
+        assert_eq!(Duration::new(2, 0), value);
+    }
+
+    #[test]
+    fn reports_overflows() {
+        let max_time = Time::new(u32::MAX, 999_999_999);
+        let max_duration = Duration::new(i32::MAX, 999_999_999);
+        let min_duration = Duration::new(i32::MIN, 0);
+        assert_eq!(None, max_time.checked_add(Duration::new(0, 1)));
+        assert_eq!(None, Time::new(0, 0).checked_sub(Duration::new(0, 1)));
+        assert_eq!(None, max_time.checked_duration_since(Time::new(0, 0)));
+        assert_eq!(Some(Duration::new(-1, 0)),
+                   Time::new(1, 0).checked_duration_since(Time::new(2, 0)));
+        assert_eq!(None, max_duration.checked_add(Duration::new(0, 1)));
+        assert_eq!(None, min_duration.checked_sub(Duration::new(0, 1)));
+        assert_eq!(None, min_duration.checked_neg());
+        assert_eq!(Some(Duration::new(i32::MIN, 1)), max_duration.checked_neg());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding duration to time")]
+    fn panics_on_overflowing_operators() {
+        let _ = Time::new(u32::MAX, 0) + Duration::new(1, 0);
+    }
+
+    #[test]
+    fn converts_system_time() {
+        let system = UNIX_EPOCH + time::Duration::new(1487460134, 332000000);
+        let time = Time::try_from(system).unwrap();
+        assert_eq!(Time::new(1487460134, 332000000), time);
+        assert_eq!(system, SystemTime::from(time));
+        let before_epoch = UNIX_EPOCH - time::Duration::new(1, 0);
+        match *Time::try_from(before_epoch).unwrap_err().kind() {
+            ErrorKind::TimeOutOfRange => {}
+            ref kind => panic!("Out of range error expected, got: {:?}", kind),
+        }
+        let after_2106 = UNIX_EPOCH + time::Duration::new(1 << 32, 0);
+        assert!(Time::try_from(after_2106).is_err());
+        assert!(Time::now() > time);
+    }
+
+    #[test]
+    fn converts_std_duration() {
+        let duration = Duration::try_from(time::Duration::new(5, 7)).unwrap();
+        assert_eq!(Duration::new(5, 7), duration);
+        assert_eq!(time::Duration::new(5, 7), time::Duration::try_from(duration).unwrap());
+        assert!(Duration::try_from(time::Duration::new(1 << 31, 0)).is_err());
+        assert!(time::Duration::try_from(Duration::new(0, -1)).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_chrono_types() {
+        use chrono::{DateTime, TimeDelta, Utc};
+        let date = DateTime::from_timestamp(1487460134, 332000000).unwrap();
+        let time = Time::try_from(date).unwrap();
+        assert_eq!(Time::new(1487460134, 332000000), time);
+        assert_eq!(date, DateTime::<Utc>::from(time));
+        assert!(Time::try_from(DateTime::from_timestamp(-1, 0).unwrap()).is_err());
+        let delta = TimeDelta::milliseconds(-1500);
+        let duration = Duration::try_from(delta).unwrap();
+        assert_eq!(Duration::new(-2, 500_000_000), duration);
+        assert_eq!(delta, TimeDelta::from(duration));
+        assert!(Duration::try_from(TimeDelta::seconds(1 << 31)).is_err());
+    }
+}